@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Parsing should reject bad input with an error, never panic
+fuzz_target!(|text: &str| {
+    let _ = text.parse::<{{crate_name}}::Puzzle>();
+});
@@ -3,7 +3,9 @@ use {{crate_name}}::{init_tracing, part1::process};
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     init_tracing()?;
-    let puzzle = common::read_input!("part1.txt").parse()?;
+    let puzzle = common::input::Resolver::from_args(env!("CARGO_MANIFEST_DIR"))
+        .read("part1.txt")?
+        .parse()?;
     let output = process(puzzle);
     println!("{output}");
     Ok(())
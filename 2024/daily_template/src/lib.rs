@@ -1,7 +1,7 @@
 pub mod part1;
 pub mod part2;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Puzzle {}
 
 impl std::str::FromStr for Puzzle {
@@ -12,6 +12,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = {{ project-name | remove: "day-" | plus: 0 }};
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part2.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
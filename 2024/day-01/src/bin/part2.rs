@@ -3,7 +3,7 @@ use day_01::{init_tracing, part2::process};
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     init_tracing()?;
-    let input = common::read_input!("part2.txt");
+    let input = common::input::Resolver::from_args(env!("CARGO_MANIFEST_DIR")).read("part2.txt")?;
     let output = process(&input)?;
     println!("{output}");
     Ok(())
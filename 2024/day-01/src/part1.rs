@@ -21,21 +21,25 @@ pub fn process(input: &str) -> Result<String> {
     right.sort_unstable();
     left.into_iter()
         .zip(right)
-        .map(|(l, r)| if l > r { l - r } else { r - l })
+        .map(|(l, r)| l.abs_diff(r))
         .sum::<u32>()
         .to_string()
         .pipe(Ok)
 }
 
+common::solution!(Part1: String => String, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, "11");
+        assert_eq!(output, common::expected!(Part::One, "example.txt"));
         Ok(())
     }
 
@@ -43,7 +47,7 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, "1938424");
+        assert_eq!(output, common::expected!(Part::One, "part1.txt"));
         Ok(())
     }
 }
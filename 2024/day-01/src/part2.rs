@@ -24,15 +24,19 @@ pub fn process(input: &str) -> Result<String> {
         .pipe(Ok)
 }
 
+common::solution!(Part2: String => String, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, "31");
+        assert_eq!(output, common::expected!(Part::Two, "example.txt"));
         Ok(())
     }
 
@@ -40,7 +44,7 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, "22014209");
+        assert_eq!(output, common::expected!(Part::Two, "part1.txt"));
         Ok(())
     }
 }
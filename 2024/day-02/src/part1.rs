@@ -30,15 +30,19 @@ fn safe(line: &str) -> Result<bool> {
     Ok(monotonic && (1..=3).contains(&diff))
 }
 
+common::solution!(Part1: String => String, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, "2");
+        assert_eq!(output, common::expected!(Part::One, "example.txt"));
         Ok(())
     }
 
@@ -46,7 +50,7 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, ("606"));
+        assert_eq!(output, common::expected!(Part::One, "part1.txt"));
         Ok(())
     }
 }
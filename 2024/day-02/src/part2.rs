@@ -75,15 +75,19 @@ fn safe_line(line: &str) -> Result<bool> {
     bad_indexs.is_empty().pipe(Ok)
 }
 
+common::solution!(Part2: String => String, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, "4");
+        assert_eq!(output, common::expected!(Part::Two, "example.txt"));
         Ok(())
     }
 
@@ -91,7 +95,7 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part2.txt");
         let output = process(&input)?;
-        assert_eq!(output, "644");
+        assert_eq!(output, common::expected!(Part::Two, "part2.txt"));
         Ok(())
     }
 }
@@ -9,10 +9,7 @@ pub fn process(mut input: &str) -> Result<u32> {
                 break (l, r).pipe(Some);
             }
             Err("") => break None,
-            Err(rest) => {
-                input = rest;
-                continue;
-            }
+            Err(rest) => input = rest,
         }
     })
     .map(|(l, r)| l * r)
@@ -42,8 +39,12 @@ fn parse_num(s: &str) -> Result<(u32, &str), &str> {
     }
 }
 
+common::solution!(Part1: String => u32, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
@@ -58,7 +59,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, 173_731_097);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
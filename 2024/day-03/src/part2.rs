@@ -12,8 +12,12 @@ pub fn process(mut input: &str) -> Result<String> {
     sum.to_string().pipe(Ok)
 }
 
+common::solution!(Part2: String => String, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
@@ -28,7 +32,7 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part2.txt");
         let output = process(&input)?;
-        assert_eq!(output, "93729253");
+        assert_eq!(output, common::expected!(Part::Two, "part2.txt"));
         Ok(())
     }
 }
@@ -23,3 +23,19 @@ fn part2() -> color_eyre::Result<()> {
         .pipe_deref(part1::process)?;
     Ok(())
 }
+
+#[divan::bench]
+fn part1_patterns() -> color_eyre::Result<()> {
+    common::read_input!("part1.txt")
+        .pipe(divan::black_box)
+        .pipe_deref(part1::process_patterns)?;
+    Ok(())
+}
+
+#[divan::bench]
+fn part2_patterns() -> color_eyre::Result<()> {
+    common::read_input!("part2.txt")
+        .pipe(divan::black_box)
+        .pipe_deref(part2::process_patterns)?;
+    Ok(())
+}
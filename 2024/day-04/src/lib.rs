@@ -1,6 +1,47 @@
+use common::grid::{Grid, GridView};
+
 pub mod part1;
 pub mod part2;
 
+/// A pattern to search for, where `.` matches any letter
+fn pattern(s: &str) -> Grid<Option<u8>> {
+    s.parse::<Grid<u8>>()
+        .expect("patterns to be valid grids")
+        .map(|b| (b != b'.').then_some(b))
+}
+
+/// The number of places `pattern` appears in `grid` in any orientation
+fn count_matches(grid: &Grid<u8>, pattern: &Grid<Option<u8>>) -> usize {
+    pattern
+        .symmetries()
+        .map(|pattern| {
+            grid.windows(pattern.size())
+                .filter(|window| matches(*window, &pattern))
+                .count()
+        })
+        .sum()
+}
+
+fn matches(window: GridView<'_, u8>, pattern: &Grid<Option<u8>>) -> bool {
+    window
+        .iter()
+        .zip(pattern)
+        .all(|(letter, expected)| expected.is_none_or(|e| e == *letter))
+}
+
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part2.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -1,80 +1,59 @@
-use color_eyre::eyre::{ensure, Result};
+use color_eyre::eyre::Result;
+use common::grid::{Grid, Ray};
 use itertools::Itertools;
+use tap::Pipe;
+
+use crate::{count_matches, pattern};
 
 pub fn process(input: &str) -> Result<usize> {
-    ensure!(input.is_ascii(), "Non ascii input not allowed");
-    let lines = input.lines().collect_vec();
-    ensure!(
-        lines[0].len() == lines.len(),
-        "Non square inputs not allowed"
-    );
-    let width = lines[0].len();
-    let vertical = (0..width)
-        .map(|x| lines.iter().map(|line| &line[x..=x]).collect::<String>())
-        .collect_vec();
-    let down_right = (0..width)
-        .map(|x| {
-            lines
-                .iter()
-                .enumerate()
-                .take(width - x)
-                .map(|(y, line)| &line[(y + x)..=(y + x)])
-                .collect::<String>()
-        })
-        .rev()
-        .chain((1..width).map(|y| {
-            (0..(width - y))
-                .map(|x| (x, x + y))
-                .map(|(x, y)| &lines[y][x..=x])
-                .collect()
-        }))
-        .collect_vec();
-    let down_left = (0..width)
-        .map(|x| {
-            lines
-                .iter()
-                .enumerate()
-                .take(width - x)
-                .map(|(y, line)| {
-                    let idx = width - y - x - 1;
-                    &line[idx..=idx]
-                })
-                .collect::<String>()
-        })
-        .rev()
-        .chain((1..width).map(|y| {
-            (0..(width - y))
-                .map(|x| (width - x - 1, x + y))
-                .map(|(x, y)| &lines[y][x..=x])
-                .collect()
-        }))
-        .collect_vec();
-    let h_sum = lines.iter().map(|line| count_in_line(line)).sum::<usize>();
-    let v_sum = vertical
-        .iter()
-        .map(|line| count_in_line(line))
-        .sum::<usize>();
-    let d_sum = down_right
+    let grid: Grid<u8> = input.parse()?;
+    grid.rows()
+        .chain(grid.columns())
+        .chain(grid.diagonals())
+        .chain(grid.anti_diagonals())
+        .map(count_in_line)
+        .sum::<usize>()
+        .pipe(Ok)
+}
+
+/// Searches for every orientation of a horizontal and a diagonal pattern
+pub fn process_patterns(input: &str) -> Result<usize> {
+    let grid: Grid<u8> = input.parse()?;
+    [pattern("XMAS"), pattern("X...\n.M..\n..A.\n...S")]
         .iter()
-        .chain(&down_left)
-        .map(|line| count_in_line(line))
-        .sum::<usize>();
-    Ok(v_sum + h_sum + d_sum)
+        .map(|pattern| count_matches(&grid, pattern))
+        .sum::<usize>()
+        .pipe(Ok)
 }
 
-fn count_in_line(line: &str) -> usize {
-    line.split("XMAS").count() + line.rsplit("SAMX").count() - 2
+fn count_in_line(line: Ray<'_, u8>) -> usize {
+    line.map(|(_, letter)| *letter)
+        .tuple_windows::<(_, _, _, _)>()
+        .filter(|&window| matches!(&window.into(), b"XMAS" | b"SAMX"))
+        .count()
 }
 
+common::solution!(Part1: String => usize, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 18);
+        let expected = common::expected!(Part::One, "example.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(process_patterns(&input)?.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rectangular() -> Result<()> {
+        assert_eq!(process("XMASAMX\nM......\nA......\nS......")?, 3);
         Ok(())
     }
 
@@ -82,7 +61,9 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, 2_397);
+        let expected = common::expected!(Part::One, "part1.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(process_patterns(&input)?.to_string(), expected);
         Ok(())
     }
 }
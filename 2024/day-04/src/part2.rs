@@ -1,7 +1,10 @@
 use color_eyre::eyre::{ensure, Result};
+use common::grid::Grid;
 use itertools::Itertools;
 use tap::prelude::*;
 
+use crate::{count_matches, pattern};
+
 pub fn process(input: &str) -> Result<usize> {
     let lines = input.lines().collect_vec();
     let width = lines[0].len();
@@ -24,28 +27,36 @@ pub fn process(input: &str) -> Result<usize> {
                 ),
             )
         })
-        .filter(|(a, b)| match (a.as_str(), b.as_str()) {
-            #[expect(clippy::unnested_or_patterns)]
-            ("MAS", "MAS") | ("SAM", "SAM") | ("MAS", "SAM") | ("SAM", "MAS") => true,
-            _ => false,
-        })
+        .filter(|(a, b)| matches!((a.as_str(), b.as_str()), ("MAS" | "SAM", "MAS" | "SAM")))
         .count()
         .pipe(Ok)
 }
 
+/// Searches for every orientation of the X-MAS pattern
+pub fn process_patterns(input: &str) -> Result<usize> {
+    let grid: Grid<u8> = input.parse()?;
+    count_matches(&grid, &pattern("M.S\n.A.\nM.S")).pipe(Ok)
+}
+
 fn get_char<'a>(lines: &'a [&'a str], (x, y): (usize, usize)) -> &'a str {
     &lines[y][x..=x]
 }
 
+common::solution!(Part2: String => usize, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 9);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(process_patterns(&input)?.to_string(), expected);
         Ok(())
     }
 
@@ -53,7 +64,9 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part2.txt");
         let output = process(&input)?;
-        assert_eq!(output, 1824);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(process_patterns(&input)?.to_string(), expected);
         Ok(())
     }
 }
@@ -45,15 +45,22 @@ fn middle_page(pages: &[u32]) -> u32 {
     pages[pages.len() / 2]
 }
 
+common::solution!(Part1: String => u32, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 143);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example.txt")
+        );
         Ok(())
     }
 
@@ -61,7 +68,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, 7307);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
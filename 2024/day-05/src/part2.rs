@@ -64,15 +64,22 @@ fn middle_page(pages: &[u32]) -> u32 {
     pages[pages.len() / 2]
 }
 
+common::solution!(Part2: String => u32, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 123);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example.txt")
+        );
         Ok(())
     }
 
@@ -3,7 +3,7 @@ use day_06::{init_tracing, part1::process};
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     init_tracing()?;
-    let input = common::read_input!("part1.txt");
+    let input = common::input::Resolver::from_args(env!("CARGO_MANIFEST_DIR")).read("part1.txt")?;
     let output = process(&input)?;
     println!("{output}");
     Ok(())
@@ -29,15 +29,22 @@ pub fn process(input: &str) -> Result<usize> {
         .pipe(Ok)
 }
 
+common::solution!(Part1: String => usize, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 41);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example.txt")
+        );
         Ok(())
     }
 
@@ -45,7 +52,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, 4939);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
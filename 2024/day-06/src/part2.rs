@@ -48,15 +48,22 @@ pub fn process(input: &str) -> Result<usize> {
     Ok(res)
 }
 
+common::solution!(Part2: String => usize, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 6);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example.txt")
+        );
         Ok(())
     }
 
@@ -64,7 +71,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part2.txt");
         let output = process(&input)?;
-        assert_eq!(output, 1434);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "part2.txt")
+        );
         Ok(())
     }
 }
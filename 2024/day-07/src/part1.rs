@@ -49,15 +49,22 @@ fn can_match(first: u64, rest: &[u64], expected: u64) -> bool {
     }
 }
 
+common::solution!(Part1: String => u64, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 3749);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example.txt")
+        );
         Ok(())
     }
 
@@ -65,7 +72,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, 5_837_374_519_342);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
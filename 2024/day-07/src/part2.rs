@@ -53,8 +53,12 @@ const fn concat(left: u64, right: u64) -> u64 {
     (10u64).pow(right.ilog(10) + 1) * left + right
 }
 
+common::solution!(Part2: String => u64, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
@@ -66,7 +70,10 @@ mod tests {
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 11_387);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example.txt")
+        );
         Ok(())
     }
 
@@ -74,7 +81,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part2.txt");
         let output = process(&input)?;
-        assert_eq!(output, 492_383_931_650_959);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "part2.txt")
+        );
         Ok(())
     }
 }
@@ -23,3 +23,11 @@ fn part2() -> color_eyre::Result<()> {
         .pipe_deref(part2::process)?;
     Ok(())
 }
+
+#[divan::bench]
+fn part2_rays() -> color_eyre::Result<()> {
+    common::read_input!("part2.txt")
+        .pipe(divan::black_box)
+        .pipe_deref(part2::process_rays)?;
+    Ok(())
+}
@@ -27,15 +27,22 @@ fn antinodes(a: IVec2, b: IVec2) -> (IVec2, IVec2) {
     (a + delta, b - delta)
 }
 
+common::solution!(Part1: String => usize, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 14);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example.txt")
+        );
         Ok(())
     }
 
@@ -43,7 +50,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt");
         let output = process(&input)?;
-        assert_eq!(output, 369);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
@@ -1,4 +1,5 @@
 use color_eyre::eyre::Result;
+use common::grid::Grid;
 use glam::IVec2;
 use itertools::Itertools as _;
 use tap::prelude::*;
@@ -20,6 +21,21 @@ pub fn process(input: &str) -> Result<usize> {
         .pipe(Ok)
 }
 
+/// Walks outwards from each pair of antennas until leaving the map, rather than guessing
+/// how far the antinodes can reach
+pub fn process_rays(input: &str) -> Result<usize> {
+    let (_, antenas) = crate::parse(input)?;
+    let map: Grid<char> = input.parse()?;
+    antenas
+        .values()
+        .flat_map(|positions| positions.iter().tuple_combinations())
+        .flat_map(|(&a, &b)| map.ray(a, a - b).chain(map.ray(b, b - a)))
+        .map(|(pos, _)| pos)
+        .unique()
+        .count()
+        .pipe(Ok)
+}
+
 fn antinodes(a: IVec2, b: IVec2) -> impl Iterator<Item = IVec2> {
     debug_assert_ne!(a, b);
     let delta = a - b;
@@ -29,15 +45,21 @@ fn antinodes(a: IVec2, b: IVec2) -> impl Iterator<Item = IVec2> {
     (-50..51).map(move |n| n * delta + a)
 }
 
+common::solution!(Part2: String => usize, |puzzle| Ok(process(&puzzle)?));
+
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
+
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt");
         let output = process(&input)?;
-        assert_eq!(output, 34);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(process_rays(&input)?.to_string(), expected);
         Ok(())
     }
 
@@ -45,7 +67,9 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part2.txt");
         let output = process(&input)?;
-        assert_eq!(output, 1_169);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(process_rays(&input)?.to_string(), expected);
         Ok(())
     }
 }
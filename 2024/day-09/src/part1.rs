@@ -16,9 +16,12 @@ pub fn process(mut puzzle: Puzzle) -> usize {
     puzzle.checksum()
 }
 
+common::solution!(Part1: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::Result;
+    use common::runner::Part;
 
     use super::*;
 
@@ -26,7 +29,10 @@ mod tests {
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 1928);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example.txt")
+        );
         Ok(())
     }
 
@@ -34,7 +40,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part1.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 6_201_130_364_722);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
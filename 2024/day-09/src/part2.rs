@@ -216,9 +216,13 @@ impl Puzzle {
     }
 }
 
+common::solution!(Part2: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::Result;
+    use common::check::{self, DiskMap, DiskMaps};
+    use common::runner::Part;
 
     use super::*;
 
@@ -226,7 +230,10 @@ mod tests {
     fn test_example() -> Result<()> {
         let input = common::read_input!("example.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 2858);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example.txt")
+        );
         Ok(())
     }
 
@@ -234,7 +241,8 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input = common::read_input!("part2.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 6_221_662_795_602);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 
@@ -242,7 +250,7 @@ mod tests {
     // fn test_heap() -> Result<()> {
     //     let input = common::read_input!("part2.txt").parse()?;
     //     let output = process_heap(input);
-    //     assert_eq!(output, 6_221_662_795_602);
+    //     assert_eq!(output.to_string(), expected);
     //     Ok(())
     // }
 
@@ -250,7 +258,26 @@ mod tests {
     fn test_heap() -> Result<()> {
         let input = common::read_input!("part2.txt").parse()?;
         let output = process_vecs(input);
-        assert_eq!(output, 6_221_662_795_602);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "part2.txt")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn variants_agree() -> Result<()> {
+        fn puzzle(map: &DiskMap) -> Puzzle {
+            map.to_string().parse().expect("disk maps to parse")
+        }
+        check::agree(
+            &DiskMaps(1..=40),
+            &[
+                ("process", |map| process(puzzle(map))),
+                ("heap", |map| process_heap(puzzle(map))),
+                ("vecs", |map| process_vecs(puzzle(map))),
+            ],
+        )?;
         Ok(())
     }
 }
@@ -35,6 +35,15 @@ fn part1_no_visited() -> color_eyre::Result<()> {
     Ok(())
 }
 
+#[divan::bench]
+fn part1_flood_fill() -> color_eyre::Result<()> {
+    common::read_input!("part1.txt")
+        .parse::<Puzzle>()?
+        .pipe(divan::black_box)
+        .pipe(part1::process_flood_fill);
+    Ok(())
+}
+
 #[divan::bench]
 fn part2() -> color_eyre::Result<()> {
     common::read_input!("part2.txt")
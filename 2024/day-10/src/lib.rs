@@ -1,4 +1,5 @@
 use color_eyre::eyre::{ensure, OptionExt};
+use common::grid::Grid;
 use glam::IVec2;
 use itertools::Itertools;
 use tap::{Pipe, TryConv};
@@ -32,6 +33,10 @@ impl Puzzle {
         self.heights.get(idx).copied()
     }
 
+    fn grid(&self) -> Grid<u8> {
+        Grid::from_row_major_ordered(self.heights.iter().copied(), self.size())
+    }
+
     fn iter(&self) -> impl Iterator<Item = (IVec2, u8)> + '_ {
         let size = self.size();
         (0..size.y).cartesian_product(0..size.x).map(|(y, x)| {
@@ -65,6 +70,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -34,6 +34,21 @@ pub fn process_no_visited(puzzle: Puzzle) -> usize {
         .sum()
 }
 
+#[allow(clippy::needless_pass_by_value)]
+#[must_use]
+pub fn process_flood_fill(puzzle: Puzzle) -> usize {
+    let grid = puzzle.grid();
+    grid.positions()
+        .filter(|&pos| grid[pos] == 0)
+        .map(|trailhead| {
+            grid.flood_fill(trailhead, |from, to| *to == from + 1)
+                .iter()
+                .filter(|&pos| grid[pos] == 9)
+                .count()
+        })
+        .sum()
+}
+
 fn score(trailhead: IVec2, map: &Puzzle) -> usize {
     let (mut visited, mut fringe) = (
         once(trailhead).collect::<HashSet<_>>(),
@@ -90,9 +105,12 @@ fn score_dfs(trailhead: IVec2, map: &Puzzle) -> usize {
     seen.len()
 }
 
+common::solution!(Part1: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::Result;
+    use common::runner::Part;
     use tap::prelude::*;
 
     use super::*;
@@ -102,10 +120,13 @@ mod tests {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let bfs = input.clone().pipe(process);
         let dfs = input.clone().pipe(process_dfs);
-        let no_visited = process_no_visited(input);
-        assert_eq!(bfs, 36);
-        assert_eq!(dfs, 36);
-        assert_eq!(no_visited, 36);
+        let no_visited = input.clone().pipe(process_no_visited);
+        let flood_fill = process_flood_fill(input);
+        let expected = common::expected!(Part::One, "example.txt");
+        assert_eq!(bfs.to_string(), expected);
+        assert_eq!(dfs.to_string(), expected);
+        assert_eq!(no_visited.to_string(), expected);
+        assert_eq!(flood_fill.to_string(), expected);
         Ok(())
     }
 
@@ -114,10 +135,13 @@ mod tests {
         let input: Puzzle = common::read_input!("example2.txt").parse()?;
         let bfs = input.clone().pipe(process);
         let dfs = input.clone().pipe(process_dfs);
-        let no_visited = process_no_visited(input);
-        assert_eq!(bfs, 2);
-        assert_eq!(dfs, 2);
-        assert_eq!(no_visited, 2);
+        let no_visited = input.clone().pipe(process_no_visited);
+        let flood_fill = process_flood_fill(input);
+        let expected = common::expected!(Part::One, "example2.txt");
+        assert_eq!(bfs.to_string(), expected);
+        assert_eq!(dfs.to_string(), expected);
+        assert_eq!(no_visited.to_string(), expected);
+        assert_eq!(flood_fill.to_string(), expected);
         Ok(())
     }
 
@@ -126,10 +150,13 @@ mod tests {
         let input: Puzzle = common::read_input!("example3.txt").parse()?;
         let bfs = input.clone().pipe(process);
         let dfs = input.clone().pipe(process_dfs);
-        let no_visited = process_no_visited(input);
-        assert_eq!(bfs, 4);
-        assert_eq!(dfs, 4);
-        assert_eq!(no_visited, 4);
+        let no_visited = input.clone().pipe(process_no_visited);
+        let flood_fill = process_flood_fill(input);
+        let expected = common::expected!(Part::One, "example3.txt");
+        assert_eq!(bfs.to_string(), expected);
+        assert_eq!(dfs.to_string(), expected);
+        assert_eq!(no_visited.to_string(), expected);
+        assert_eq!(flood_fill.to_string(), expected);
         Ok(())
     }
 
@@ -138,10 +165,13 @@ mod tests {
         let input: Puzzle = common::read_input!("part1.txt").parse()?;
         let bfs = input.clone().pipe(process);
         let dfs = input.clone().pipe(process_dfs);
-        let no_visited = process_no_visited(input);
-        assert_eq!(bfs, 746);
-        assert_eq!(dfs, 746);
-        assert_eq!(no_visited, 746);
+        let no_visited = input.clone().pipe(process_no_visited);
+        let flood_fill = process_flood_fill(input);
+        let expected = common::expected!(Part::One, "part1.txt");
+        assert_eq!(bfs.to_string(), expected);
+        assert_eq!(dfs.to_string(), expected);
+        assert_eq!(no_visited.to_string(), expected);
+        assert_eq!(flood_fill.to_string(), expected);
         Ok(())
     }
 }
@@ -64,9 +64,12 @@ fn rank_loop(trailhead: IVec2, map: &Puzzle) -> usize {
     seen
 }
 
+common::solution!(Part2: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use tap::Pipe;
 
     use super::*;
@@ -76,8 +79,9 @@ mod tests {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let recursive = input.clone().pipe(process);
         let looping = process_loop(input);
-        assert_eq!(recursive, 81);
-        assert_eq!(looping, 81);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(recursive.to_string(), expected);
+        assert_eq!(looping.to_string(), expected);
         Ok(())
     }
 
@@ -86,8 +90,9 @@ mod tests {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
         let recursive = input.clone().pipe(process);
         let looping = process_loop(input);
-        assert_eq!(recursive, 1541);
-        assert_eq!(looping, 1541);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(recursive.to_string(), expected);
+        assert_eq!(looping.to_string(), expected);
         Ok(())
     }
 }
@@ -81,3 +81,24 @@ fn depth_first_math<const N: u8>() -> color_eyre::Result<()> {
     let _ = puzzle.depth_first_math(N);
     Ok(())
 }
+
+#[divan::bench(consts = [
+    1,
+    10,
+    15,
+    20,
+    25,
+    32,
+    40,
+    50,
+    60,
+    70,
+    75
+])]
+fn depth_first_par<const N: u8>() -> color_eyre::Result<()> {
+    let puzzle = common::read_input!("part1.txt")
+        .parse::<Puzzle>()?
+        .pipe(divan::black_box);
+    let _ = puzzle.depth_first_par(N);
+    Ok(())
+}
@@ -1,5 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Add};
 
+use common::par::{self, Memo};
 use either::Either;
 use itertools::Itertools as _;
 use tap::prelude::*;
@@ -96,6 +97,30 @@ impl Puzzle {
         })
     }
 
+    #[must_use]
+    pub fn depth_first_par(self, steps: u8) -> usize {
+        fn stones_after(initial: u64, steps: u8, memo: &Memo<(u64, u8), usize>) -> usize {
+            if steps == 0 {
+                return 1;
+            }
+            memo.get_or_insert_with((initial, steps), || match initial {
+                0 => stones_after(1, steps - 1, memo),
+                n if n.ilog(10) % 2 == 1 => {
+                    let (a, b) = crate::split_digits(n);
+                    stones_after(a, steps - 1, memo) + stones_after(b, steps - 1, memo)
+                }
+                n => stones_after(n * 2024, steps - 1, memo),
+            })
+        }
+
+        par::memo_map_reduce(
+            self.stones,
+            |stone, memo| stones_after(stone, steps, memo),
+            || 0,
+            Add::add,
+        )
+    }
+
     fn depth_first_impl(self, steps: u8, mut stones_after: impl FnMut(u64, u8) -> usize) -> usize {
         self.stones
             .into_iter()
@@ -176,6 +201,36 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+common::solution!(Part1: Puzzle => usize, |puzzle| Ok(puzzle.breadth_first(25)));
+
+common::solution!(Part2: Puzzle => usize, |puzzle| Ok(puzzle.breadth_first(75)));
+
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part2.txt"];
+
+    const VARIANTS: &'static [common::bench::Variant] = common::variants![
+        One "simulate_vec": str::parse::<Puzzle> => |p: Puzzle| p.simulate_vec(25);
+        One "simulate_no_alloc": str::parse::<Puzzle> => |p: Puzzle| p.simulate_no_alloc(25);
+        One "depth_first_str": str::parse::<Puzzle> => |p: Puzzle| p.depth_first_str(25);
+        One "depth_first_math": str::parse::<Puzzle> => |p: Puzzle| p.depth_first_math(25);
+        One "depth_first_par": str::parse::<Puzzle> => |p: Puzzle| p.depth_first_par(25);
+        One "breadth_first": str::parse::<Puzzle> => |p: Puzzle| p.breadth_first(25);
+        Two "depth_first_str": str::parse::<Puzzle> => |p: Puzzle| p.depth_first_str(75);
+        Two "depth_first_math": str::parse::<Puzzle> => |p: Puzzle| p.depth_first_math(75);
+        Two "depth_first_par": str::parse::<Puzzle> => |p: Puzzle| p.depth_first_par(75);
+        Two "breadth_first": str::parse::<Puzzle> => |p: Puzzle| p.breadth_first(75);
+    ];
+
+    type Part1 = Part1;
+    type Part2 = Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -198,15 +253,18 @@ pub fn init_tracing() -> color_eyre::Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use color_eyre::eyre::Result;
+    use common::runner::Part;
+
+    use super::*;
 
     #[test]
     fn test_simulate_vec() -> Result<()> {
         let example: Puzzle = "125 17".parse()?;
         let actual: Puzzle = common::read_input!("part1.txt").parse()?;
+        let part1 = common::expected!(Part::One, "part1.txt");
         assert_eq!(example.simulate_vec(25), 55_312);
-        assert_eq!(actual.simulate_vec(25), 218_079);
+        assert_eq!(actual.simulate_vec(25).to_string(), part1);
         Ok(())
     }
 
@@ -214,8 +272,9 @@ mod tests {
     fn test_simulate_no_alloc() -> Result<()> {
         let example: Puzzle = "125 17".parse()?;
         let actual: Puzzle = common::read_input!("part1.txt").parse()?;
+        let part1 = common::expected!(Part::One, "part1.txt");
         assert_eq!(example.simulate_no_alloc(25), 55_312);
-        assert_eq!(actual.simulate_no_alloc(25), 218_079);
+        assert_eq!(actual.simulate_no_alloc(25).to_string(), part1);
         Ok(())
     }
 
@@ -223,10 +282,14 @@ mod tests {
     fn test_depth_first_str() -> Result<()> {
         let example: Puzzle = "125 17".parse()?;
         let actual: Puzzle = common::read_input!("part1.txt").parse()?;
+        let (part1, part2) = (
+            common::expected!(Part::One, "part1.txt"),
+            common::expected!(Part::Two, "part1.txt"),
+        );
         assert_eq!(example.clone().depth_first_str(25), 55_312);
-        assert_eq!(actual.clone().depth_first_str(25), 218_079);
+        assert_eq!(actual.clone().depth_first_str(25).to_string(), part1);
         assert_eq!(example.depth_first_str(75), 65_601_038_650_482);
-        assert_eq!(actual.depth_first_str(75), 259_755_538_429_618);
+        assert_eq!(actual.depth_first_str(75).to_string(), part2);
         Ok(())
     }
 
@@ -234,10 +297,29 @@ mod tests {
     fn test_depth_first_math() -> Result<()> {
         let example: Puzzle = "125 17".parse()?;
         let actual: Puzzle = common::read_input!("part1.txt").parse()?;
+        let (part1, part2) = (
+            common::expected!(Part::One, "part1.txt"),
+            common::expected!(Part::Two, "part1.txt"),
+        );
         assert_eq!(example.clone().depth_first_math(25), 55_312);
-        assert_eq!(actual.clone().depth_first_math(25), 218_079);
+        assert_eq!(actual.clone().depth_first_math(25).to_string(), part1);
         assert_eq!(example.depth_first_math(75), 65_601_038_650_482);
-        assert_eq!(actual.depth_first_math(75), 259_755_538_429_618);
+        assert_eq!(actual.depth_first_math(75).to_string(), part2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_depth_first_par() -> Result<()> {
+        let example: Puzzle = "125 17".parse()?;
+        let actual: Puzzle = common::read_input!("part1.txt").parse()?;
+        let (part1, part2) = (
+            common::expected!(Part::One, "part1.txt"),
+            common::expected!(Part::Two, "part1.txt"),
+        );
+        assert_eq!(example.clone().depth_first_par(25), 55_312);
+        assert_eq!(actual.clone().depth_first_par(25).to_string(), part1);
+        assert_eq!(example.depth_first_par(75), 65_601_038_650_482);
+        assert_eq!(actual.depth_first_par(75).to_string(), part2);
         Ok(())
     }
 
@@ -245,10 +327,14 @@ mod tests {
     fn test_breadth_first() -> Result<()> {
         let example: Puzzle = "125 17".parse()?;
         let actual: Puzzle = common::read_input!("part1.txt").parse()?;
+        let (part1, part2) = (
+            common::expected!(Part::One, "part1.txt"),
+            common::expected!(Part::Two, "part1.txt"),
+        );
         assert_eq!(example.clone().breadth_first(25), 55_312);
-        assert_eq!(actual.clone().breadth_first(25), 218_079);
+        assert_eq!(actual.clone().breadth_first(25).to_string(), part1);
         assert_eq!(example.breadth_first(75), 65_601_038_650_482);
-        assert_eq!(actual.breadth_first(75), 259_755_538_429_618);
+        assert_eq!(actual.breadth_first(75).to_string(), part2);
         Ok(())
     }
 }
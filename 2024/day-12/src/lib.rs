@@ -1,9 +1,4 @@
-use std::collections::HashSet;
-
-use glam::IVec2;
-use itertools::Itertools;
-
-use common::grid::Grid;
+use common::grid::{Grid, Region};
 
 pub mod part1;
 pub mod part2;
@@ -13,79 +8,9 @@ pub struct Puzzle {
     plots: Grid<u8>,
 }
 
-#[derive(Debug)]
-pub struct Region {
-    perimiter: usize,
-    area: usize,
-    corners: usize,
-}
-
 impl Puzzle {
     fn regions(&self) -> impl Iterator<Item = Region> + '_ {
-        let mut seen = HashSet::new();
-        let mut positions = self.plots.positions();
-        std::iter::from_fn(move || loop {
-            let pos = positions.next()?;
-            if seen.contains(&pos) {
-                continue;
-            }
-            let (region, plots) = {
-                let plant = self.plots.get(pos).copied()?;
-                let (mut region, mut plots, mut plot_stack) = (
-                    Region {
-                        perimiter: 4,
-                        area: 1,
-                        corners: self.corners(pos, plant),
-                    },
-                    HashSet::new(),
-                    Vec::new(),
-                );
-                plot_stack.push(pos);
-                plots.insert(pos);
-                while let Some(pos) = plot_stack.pop() {
-                    for neighbor in [
-                        pos + IVec2::X,
-                        pos + IVec2::Y,
-                        pos - IVec2::X,
-                        pos - IVec2::Y,
-                    ]
-                    .into_iter()
-                    .filter(|pos| self.plots.get(*pos).copied() == Some(plant))
-                    {
-                        region.perimiter -= 1;
-                        if plots.contains(&neighbor) {
-                            continue;
-                        }
-                        region.area += 1;
-                        region.perimiter += 4;
-                        region.corners += self.corners(neighbor, plant);
-                        plot_stack.push(neighbor);
-                        plots.insert(neighbor);
-                    }
-                }
-                (region, plots)
-            };
-            seen.extend(plots.iter().copied());
-            break Some(region);
-        })
-    }
-
-    fn corners(&self, pos: IVec2, plant: u8) -> usize {
-        [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X]
-            .into_iter()
-            .circular_tuple_windows()
-            .map(|(a, b)| {
-                (
-                    self.plots.get(pos + a).copied(),
-                    self.plots.get(pos + b).copied(),
-                    self.plots.get(pos + a + b).copied(),
-                )
-            })
-            .filter(|(left, right, mid)| {
-                (*left != Some(plant) && *right != Some(plant))
-                    || (*left == Some(plant) && *right == Some(plant) && *mid != Some(plant))
-            })
-            .count()
+        self.plots.regions()
     }
 }
 
@@ -97,6 +22,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -5,13 +5,16 @@ use crate::Puzzle;
 pub fn process(puzzle: Puzzle) -> usize {
     puzzle
         .regions()
-        .map(|region| region.area * region.perimiter)
+        .map(|region| region.area() * region.perimeter())
         .sum()
 }
 
+common::solution!(Part1: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
 
     use super::*;
 
@@ -19,7 +22,10 @@ mod tests {
     fn test_example() -> Result<()> {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 1930);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example.txt")
+        );
         Ok(())
     }
 
@@ -27,7 +33,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part1.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 1_433_460);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
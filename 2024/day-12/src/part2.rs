@@ -5,13 +5,16 @@ use crate::Puzzle;
 pub fn process(puzzle: Puzzle) -> usize {
     puzzle
         .regions()
-        .map(|region| region.area * region.corners)
+        .map(|region| region.area() * region.corners())
         .sum()
 }
 
+common::solution!(Part2: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
 
     use super::*;
 
@@ -19,7 +22,10 @@ mod tests {
     fn test_example() -> Result<()> {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 1_206);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example.txt")
+        );
         Ok(())
     }
 
@@ -27,7 +33,10 @@ mod tests {
     fn test_example_2() -> Result<()> {
         let input: Puzzle = common::read_input!("example2.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 368);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example2.txt")
+        );
         Ok(())
     }
 
@@ -35,7 +44,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 855_082);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "part2.txt")
+        );
         Ok(())
     }
 }
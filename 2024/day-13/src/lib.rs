@@ -1,4 +1,5 @@
-use color_eyre::eyre::{OptionExt, Result};
+use color_eyre::eyre::OptionExt;
+use common::parse::{self, Span};
 use glam::{DVec2, I64Vec2};
 use itertools::Itertools;
 use tap::prelude::*;
@@ -74,31 +75,21 @@ impl std::str::FromStr for Puzzle {
     type Err = color_eyre::Report;
 
     fn from_str(s: &str) -> color_eyre::Result<Self> {
-        fn parse_button(s: &str) -> Result<I64Vec2> {
-            let (x, y) = s
-                .trim()
-                .split(|c: char| !c.is_ascii_digit())
-                .filter(|s| !s.is_empty())
-                .collect_tuple()
-                .ok_or_eyre("Failed to parse line")?;
-            I64Vec2 {
-                x: x.parse()?,
-                y: y.parse()?,
-            }
-            .pipe(Ok)
+        fn parse_line(line: Span, label: &str) -> Result<I64Vec2, parse::Error> {
+            line.section(label)?.int_array().map(I64Vec2::from_array)
         }
 
         Self {
-            machines: s
-                .lines()
-                .map(str::trim)
-                .filter(|s| !s.is_empty())
-                .tuples()
-                .map(|(a, b, p)| {
+            machines: parse::blocks(s)
+                .map(|block| {
+                    let (a, b, prize) = block
+                        .lines()
+                        .collect_tuple()
+                        .ok_or_eyre("Expected three lines per machine")?;
                     Machine {
-                        a: parse_button(a)?,
-                        b: parse_button(b)?,
-                        prize: parse_button(p)?,
+                        a: parse_line(a, "Button A")?,
+                        b: parse_line(b, "Button B")?,
+                        prize: parse_line(prize, "Prize")?,
                     }
                     .pipe(Ok::<_, color_eyre::Report>)
                 })
@@ -108,6 +99,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
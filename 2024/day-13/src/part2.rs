@@ -24,9 +24,12 @@ pub fn process_int(puzzle: Puzzle) -> i64 {
         .sum()
 }
 
+common::solution!(Part2: Puzzle => i64, process_mat);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use tap::prelude::*;
 
     use super::*;
@@ -36,8 +39,9 @@ mod tests {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let output_mat = input.clone().pipe(process_mat);
         let output_int = process_int(input);
-        assert_eq!(output_mat, 875_318_608_908);
-        assert_eq!(output_int, 875_318_608_908);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(output_mat.to_string(), expected);
+        assert_eq!(output_int.to_string(), expected);
         Ok(())
     }
 
@@ -46,8 +50,9 @@ mod tests {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
         let output_mat = input.clone().pipe(process_mat);
         let output_int = process_int(input);
-        assert_eq!(output_mat, 99_968_222_587_852);
-        assert_eq!(output_int, 99_968_222_587_852);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(output_mat.to_string(), expected);
+        assert_eq!(output_int.to_string(), expected);
         Ok(())
     }
 }
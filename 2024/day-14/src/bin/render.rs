@@ -3,11 +3,14 @@ use glam::IVec2;
 
 use day_14::{init_tracing, Puzzle};
 
+/// Prints the christmas tree to the terminal, also saving it as an image if given a path
 fn main() -> Result<()> {
     init_tracing()?;
     let puzzle: Puzzle = common::read_input!("part1.txt").parse()?;
-    puzzle
-        .render(IVec2 { x: 101, y: 103 }, 6668)
-        .save("test.png")?;
+    let (size, t) = (IVec2 { x: 101, y: 103 }, 6668);
+    if let Some(path) = std::env::args().nth(1) {
+        puzzle.clone().render(size, t).save(path)?;
+    }
+    println!("{}", puzzle.render_text(size, t));
     Ok(())
 }
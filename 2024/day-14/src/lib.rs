@@ -1,9 +1,8 @@
 use std::cmp::Ordering;
 
-use color_eyre::eyre::OptionExt;
+use common::{grid::Grid, parse};
 use glam::IVec2;
 use image::{ImageBuffer, Rgb};
-// use image::{ImageBuffer, Rgb};
 use itertools::Itertools;
 use tap::{Pipe, TryConv};
 
@@ -38,21 +37,43 @@ impl Puzzle {
             .sum()
     }
 
-    #[must_use]
-    pub fn render(mut self, size: IVec2, t: u16) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
+    /// The number of robots on each tile after `t` seconds
+    fn grid_after(mut self, size: IVec2, t: u16) -> Grid<u8> {
         for _ in 0..t {
             self.robots.iter_mut().for_each(|r| r.tick(size));
         }
-        let mut grid = common::grid::Grid::from_value(0u8, size);
+        let mut grid = Grid::from_value(0u8, size);
         for pos in self.robots.iter().map(|r| r.position) {
             *grid.get_mut(pos).expect("all robots to be in grid") += 1;
         }
-        grid.pixels(|b| match *b {
+        grid
+    }
+
+    #[must_use]
+    pub fn render(self, size: IVec2, t: u16) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
+        self.grid_after(size, t).pixels(|b| match *b {
             0 => [0u8, 0, 0].pipe(Rgb),
             1 => [255, 255, 255].pipe(Rgb),
             _ => [255, 0, 0].pipe(Rgb),
         })
     }
+
+    /// The robots after `t` seconds as text, with `#` marking occupied tiles
+    #[must_use]
+    pub fn render_text(self, size: IVec2, t: u16) -> String {
+        self.grid_after(size, t)
+            .render_with(|_, &b| if b == 0 { '.' } else { '#' })
+    }
+}
+
+/// The size of the area the robots patrol, which is smaller in the examples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds(pub IVec2);
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self(IVec2::new(101, 103))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -87,31 +108,14 @@ impl std::str::FromStr for Puzzle {
 
     fn from_str(s: &str) -> color_eyre::Result<Self> {
         Self {
-            robots: s
-                .lines()
-                .map(str::trim)
-                .filter(|s| !s.is_empty())
+            robots: parse::lines(s)
                 .map(|line| {
-                    let (p, v) = line.split_once(' ')?;
-                    (
-                        p.strip_prefix("p=")?.split_once(',')?,
-                        v.strip_prefix("v=")?.split_once(',')?,
-                    )
-                        .pipe(Some)
-                })
-                .map(|opt| {
-                    let ((px, py), (vx, vy)) = opt.ok_or_eyre("Incorrect line")?;
+                    let (p, v) = line.split_once(" ")?;
                     Robot {
-                        position: IVec2 {
-                            x: px.parse()?,
-                            y: py.parse()?,
-                        },
-                        velocity: IVec2 {
-                            x: vx.parse()?,
-                            y: vy.parse()?,
-                        },
+                        position: p.section("p")?.ivec2()?,
+                        velocity: v.section("v")?.ivec2()?,
                     }
-                    .pipe(Ok::<_, color_eyre::Report>)
+                    .pipe(Ok::<_, parse::Error>)
                 })
                 .try_collect()?,
         }
@@ -119,6 +123,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -27,21 +27,41 @@ pub fn process(mut puzzle: Puzzle, size: IVec2) -> usize {
         .expect("The iter to be non empty")
 }
 
+/// Part 1 as a `Solution`, for the runner
+pub struct Part1;
+
+impl common::Solution for Part1 {
+    type Input = Puzzle;
+    type Params = crate::Bounds;
+    type Answer = usize;
+
+    fn prepare(text: &str, _: &crate::Bounds) -> Result<Self::Input, common::solution::Error> {
+        Ok(text.parse()?)
+    }
+
+    fn solve(
+        puzzle: Self::Input,
+        bounds: &crate::Bounds,
+    ) -> Result<Self::Answer, common::solution::Error> {
+        Ok(process(puzzle, bounds.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
+    use common::Solution;
 
     use super::*;
+    use crate::Bounds;
 
     #[test]
-    fn test_example() -> Result<()> {
-        let input: Puzzle = std::fs::read_to_string(
-            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
-                .join("inputs")
-                .join("example.txt"),
-        )?
-        .parse()?;
-        let output = process(input, IVec2 { x: 11, y: 7 });
+    fn test_example() -> Result<(), common::solution::Error> {
+        let output = Part1::run(
+            &common::read_input!("example.txt"),
+            &Bounds(IVec2 { x: 11, y: 7 }),
+        )?;
         assert_eq!(output, 12);
         Ok(())
     }
@@ -50,7 +70,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part1.txt").parse()?;
         let output = process(input, IVec2 { x: 101, y: 103 });
-        assert_eq!(output, 230_461_440);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
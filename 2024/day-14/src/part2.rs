@@ -73,13 +73,11 @@ pub fn process_var(mut puzzle: Puzzle, size: IVec2) -> usize {
         index
     };
     let inv = mod_inverse(size.x, size.y).expect("103 and 101 to have a mod inverse");
-    let m = (size.x * size.y)
-        .try_conv::<usize>()
-        .expect("size to be positive");
+    // the y offset can be before the x offset, so this needs to wrap rather than just truncate
     (min.x + inv * (min.y - min.x) * size.x)
+        .rem_euclid(size.x * size.y)
         .try_conv::<usize>()
         .expect("answer to be <= 103 * 101")
-        % m
 }
 
 fn all_unique(puzzle: &Puzzle) -> bool {
@@ -98,9 +96,31 @@ pub fn grid_unique(puzzle: &Puzzle, size: IVec2) -> bool {
     true
 }
 
+/// Part 2 as a `Solution`, for the runner
+pub struct Part2;
+
+impl common::Solution for Part2 {
+    type Input = Puzzle;
+    type Params = crate::Bounds;
+    type Answer = usize;
+
+    fn prepare(text: &str, _: &crate::Bounds) -> Result<Self::Input, common::solution::Error> {
+        Ok(text.parse()?)
+    }
+
+    fn solve(
+        puzzle: Self::Input,
+        bounds: &crate::Bounds,
+    ) -> Result<Self::Answer, common::solution::Error> {
+        Ok(process(puzzle, bounds.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::check::{self, Skies, Sky};
+    use common::runner::Part;
 
     use super::*;
 
@@ -111,9 +131,52 @@ mod tests {
         let out_hash = process(input.clone(), size);
         let out_grid = process_grid_unique(input.clone(), size);
         let out_var = process_var(input, size);
-        assert_eq!(out_hash, 6668);
-        assert_eq!(out_grid, 6668);
-        assert_eq!(out_var, 6668);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(out_hash.to_string(), expected);
+        assert_eq!(out_grid.to_string(), expected);
+        assert_eq!(out_var.to_string(), expected);
+        Ok(())
+    }
+
+    fn puzzle(sky: &Sky) -> Puzzle {
+        sky.to_string().parse().expect("skies to parse")
+    }
+
+    #[test]
+    fn unique_variants_agree() -> Result<()> {
+        let skies = Skies {
+            size: IVec2::new(11, 7),
+            robots: 1..=9,
+            picture: IVec2::new(3, 3),
+        };
+        check::agree(
+            &skies,
+            &[
+                ("process", |sky| process(puzzle(sky), sky.size)),
+                ("grid_unique", |sky| {
+                    process_grid_unique(puzzle(sky), sky.size)
+                }),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The variance only stands out when there are enough robots crowded into the picture, which
+    /// also makes it unlikely they'll all be on different tiles at any other time
+    #[test]
+    fn variance_finds_pictures() -> Result<()> {
+        let skies = Skies {
+            size: IVec2::new(31, 37),
+            robots: 200..=225,
+            picture: IVec2::new(15, 15),
+        };
+        check::Config::new(20).agree(
+            &skies,
+            &[
+                ("process", |sky| process(puzzle(sky), sky.size)),
+                ("var", |sky| process_var(puzzle(sky), sky.size)),
+            ],
+        )?;
         Ok(())
     }
 }
@@ -1,9 +1,12 @@
-use color_eyre::eyre::{bail, OptionExt, Result};
+use color_eyre::eyre::{bail, OptionExt};
 use glam::IVec2;
 use itertools::Itertools;
 use tap::prelude::*;
 
-use common::grid::Grid;
+use common::{
+    bitset::BitGrid,
+    grid::{Colour, Grid},
+};
 
 pub mod part1;
 pub mod part2;
@@ -47,81 +50,53 @@ impl Puzzle {
         }
     }
 
-    #[allow(unused)]
-    fn print_grid(&self) -> Result<()> {
-        let width = self.map.size().x.try_conv::<usize>()?;
-        for chunk in &self.map.iter().copied().chunks(width) {
-            let s = chunk
-                .map(u32::from)
-                .filter_map(char::from_u32)
-                .collect::<String>();
-            println!("{s}");
+    /// Dumps the map to the trace log, with the robot highlighted
+    fn trace_map(&self) {
+        if tracing::enabled!(tracing::Level::TRACE) {
+            let mut bot = BitGrid::new(self.map.size());
+            bot.insert(self.bot);
+            let map = self
+                .map
+                .render_overlay(&[(&bot, Colour::Red)], |_, b| char::from(*b));
+            tracing::trace!("\n{map}");
         }
-        Ok(())
     }
 
-    fn can_move(&self, pos: IVec2, dir: IVec2) -> bool {
-        let thing = *self.map.get(pos).expect("pos outside of map");
-        match self.map.get(pos + dir).copied() {
-            _ if thing == b'.' => false,
-            Some(b'.') => true,
-            Some(b'#') => false,
-            Some(b'O') => self.can_move(pos + dir, dir),
-            Some(b'[' | b']') if dir.y == 0 => self.can_move(pos + dir, dir),
-            Some(b'[') if dir.x == 0 => {
-                self.can_move(pos + dir, dir) && self.can_move(pos + dir + IVec2::X, dir)
-            }
-            Some(b']') if dir.x == 0 => {
-                self.can_move(pos + dir, dir) && self.can_move(pos + dir - IVec2::X, dir)
+    /// Moves the robot one step in `dir`, along with every box it pushes, unless any of them
+    /// would hit a wall
+    fn push(&mut self, dir: IVec2) {
+        debug_assert_eq!((dir.abs().max_element(), dir.abs().min_element()), (1, 0));
+        match self.map[self.bot + dir] {
+            b'#' => return,
+            b'.' => (),
+            _ => {
+                let boxes = self.map.flood(self.bot + dir, |pos| {
+                    let other_half = match self.map[pos] {
+                        b'[' if dir.x == 0 => Some(pos + IVec2::X),
+                        b']' if dir.x == 0 => Some(pos - IVec2::X),
+                        _ => None,
+                    };
+                    let behind = pos + dir;
+                    other_half
+                        .into_iter()
+                        .chain(matches!(self.map[behind], b'O' | b'[' | b']').then_some(behind))
+                });
+                if boxes.iter().any(|pos| self.map[pos + dir] == b'#') {
+                    return;
+                }
+                // the furthest along move first, so nothing is overwritten before it has moved
+                for pos in boxes.iter().sorted_by_key(|pos| -pos.dot(dir)) {
+                    self.shift(pos, dir);
+                }
             }
-            Some(b'@') => panic!("Attempted to move into robot"),
-            Some(b) => unreachable!("{:?}", b.conv::<u32>().pipe(char::from_u32)),
-            None => panic!("Attempted to move outside of map"),
-        }
-    }
-
-    fn move_unchecked(&mut self, pos: IVec2, dir: IVec2) {
-        let thing = *self.map.get(pos).expect("pos outside of map");
-        if thing == b'@' {
-            self.bot = pos + dir;
         }
-        *self.map.get_mut(pos + dir).expect("known inside") = thing;
-        *self.map.get_mut(pos).expect("known inside") = b'.';
+        self.shift(self.bot, dir);
+        self.bot += dir;
     }
 
-    fn step(&mut self, pos: IVec2, dir: IVec2) -> bool {
-        debug_assert_eq!((dir.abs().max_element(), dir.abs().min_element()), (1, 0));
-        match self.map.get(pos + dir).copied() {
-            _ if !self.can_move(pos, dir) => false,
-            Some(b'.') => {
-                self.move_unchecked(pos, dir);
-                true
-            }
-            Some(b'O') => {
-                assert!(self.step(pos + dir, dir));
-                self.move_unchecked(pos, dir);
-                true
-            }
-            Some(b'#') => false,
-            Some(b'@') => panic!("Attempted to move into robot"),
-            Some(b'[' | b']') if dir.y == 0 => {
-                assert!(self.step(pos + dir, dir));
-                self.move_unchecked(pos, dir);
-                true
-            }
-            Some(b'[') => {
-                assert!(self.step(pos + dir, dir) && self.step(pos + dir + IVec2::X, dir));
-                self.move_unchecked(pos, dir);
-                true
-            }
-            Some(b']') => {
-                assert!(self.step(pos + dir, dir) && self.step(pos + dir - IVec2::X, dir));
-                self.move_unchecked(pos, dir);
-                true
-            }
-            Some(_) => unreachable!(),
-            None => panic!("Attempted to move outside of map"),
-        }
+    fn shift(&mut self, pos: IVec2, dir: IVec2) {
+        self.map[pos + dir] = self.map[pos];
+        self.map[pos] = b'.';
     }
 
     fn sum_coords(&self) -> i32 {
@@ -168,6 +143,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
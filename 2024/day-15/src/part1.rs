@@ -4,14 +4,18 @@ use crate::Puzzle;
 #[allow(clippy::needless_pass_by_value)]
 pub fn process(mut puzzle: Puzzle) -> i32 {
     for dir in puzzle.moves.clone() {
-        puzzle.step(puzzle.bot, dir);
+        puzzle.push(dir);
+        puzzle.trace_map();
     }
     puzzle.sum_coords()
 }
 
+common::solution!(Part1: Puzzle => i32, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
 
     use super::*;
 
@@ -34,14 +38,20 @@ mod tests {
     fn test_example2() -> Result<()> {
         let input: Puzzle = common::read_input!("example2.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 2028);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example2.txt")
+        );
         Ok(())
     }
     #[test]
     fn test_example() -> Result<()> {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 10_092);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "example.txt")
+        );
         Ok(())
     }
 
@@ -49,7 +59,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part1.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 1_426_855);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
@@ -8,17 +8,42 @@ pub fn process(puzzle: Puzzle) -> i32 {
     puzzle.wide().pipe(crate::part1::process)
 }
 
+common::solution!(Part2: Puzzle => i32, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
 
     use super::*;
 
+    #[test]
+    fn test_small() -> Result<()> {
+        let input: Puzzle = r"
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+"
+        .trim()
+        .parse()?;
+        assert_eq!(process(input), 618);
+        Ok(())
+    }
+
     #[test]
     fn test_example2() -> Result<()> {
         let input: Puzzle = common::read_input!("example3.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 618);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example3.txt")
+        );
         Ok(())
     }
 
@@ -26,7 +51,10 @@ mod tests {
     fn test_example() -> Result<()> {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 9021);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "example.txt")
+        );
         Ok(())
     }
 
@@ -34,7 +62,10 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, 1_404_917);
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "part2.txt")
+        );
         Ok(())
     }
 }
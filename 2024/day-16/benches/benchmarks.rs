@@ -48,6 +48,17 @@ fn part1_astar(bencher: divan::Bencher) {
         });
 }
 
+#[divan::bench]
+fn part1_search(bencher: divan::Bencher) {
+    bencher
+        .with_inputs(|| common::read_input!("part1.txt").parse::<Puzzle>())
+        .bench_values(|res| {
+            res.expect("parsing to suceed")
+                .pipe(divan::black_box)
+                .pipe(part1::search)
+        });
+}
+
 #[divan::bench]
 fn part2_bfs(bencher: divan::Bencher) {
     bencher
@@ -84,3 +95,14 @@ fn part2_astar(bencher: divan::Bencher) {
                 .pipe(part2::astar)
         });
 }
+
+#[divan::bench]
+fn part2_search(bencher: divan::Bencher) {
+    bencher
+        .with_inputs(|| common::read_input!("part2.txt").parse::<Puzzle>())
+        .bench_values(|res| {
+            res.expect("parsing to suceed")
+                .pipe(divan::black_box)
+                .pipe(part2::search)
+        });
+}
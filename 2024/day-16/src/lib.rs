@@ -1,7 +1,11 @@
-use std::collections::BinaryHeap;
+use std::cmp::Reverse;
 
 use color_eyre::eyre::OptionExt;
-use common::grid::Grid;
+use common::{
+    grid::Grid,
+    min_heap::MinHeap,
+    search::{self, Search},
+};
 use glam::IVec2;
 use tap::{Pipe, TryConv};
 
@@ -41,20 +45,9 @@ impl Puzzle {
     fn dijkstras(&self) -> Costs {
         let mut costs = Costs::new(self.map.size());
         assert!(costs.replace_if_lt(self.start, D_0, 0));
-        let mut stack = BinaryHeap::<Entry>::new();
-        stack.push(Entry {
-            position: self.start,
-            direction: D_0,
-            cost: 0,
-            estimated_cost: 0,
-        });
-        while let Some(Entry {
-            position: pos,
-            direction: dir,
-            cost,
-            ..
-        }) = stack.pop()
-        {
+        let mut queue = MinHeap::new();
+        queue.push((self.start, D_0), 0);
+        while let Some(((pos, dir), cost)) = queue.pop() {
             for (new_p, new_d, new_c) in [
                 (pos + dir, dir, cost + 1),
                 (pos, dir.perp(), cost + 1000),
@@ -64,12 +57,7 @@ impl Puzzle {
             .filter(|(p, _, _)| self.map[*p] != b'#')
             {
                 if costs.replace_if_lt(new_p, new_d, new_c) {
-                    stack.push(Entry {
-                        position: new_p,
-                        direction: new_d,
-                        cost: new_c,
-                        estimated_cost: new_c,
-                    });
+                    queue.push((new_p, new_d), new_c);
                 }
             }
         }
@@ -79,20 +67,10 @@ impl Puzzle {
     fn astar(&self) -> Costs {
         let mut costs = Costs::new(self.map.size());
         assert!(costs.replace_if_lt(self.start, D_0, 0));
-        let mut stack = BinaryHeap::<Entry>::new();
-        stack.push(Entry {
-            position: self.start,
-            direction: D_0,
-            cost: 0,
-            estimated_cost: 0,
-        });
-        while let Some(Entry {
-            position: pos,
-            direction: dir,
-            cost,
-            ..
-        }) = stack.pop()
-        {
+        let mut queue = MinHeap::new();
+        queue.push((self.start, D_0), (0, Reverse(0)));
+        while let Some(((pos, dir), _)) = queue.pop() {
+            let cost = costs.get(pos, dir).expect("queued states to have a cost");
             for (new_p, new_d, new_c) in [
                 (pos + dir, dir, cost + 1),
                 (pos, dir.perp(), cost + 1000),
@@ -109,52 +87,30 @@ impl Puzzle {
                 .expect("the conversion to succeed");
 
                 if costs.replace_if_lt(new_p, new_d, new_c) {
-                    stack.push(Entry {
-                        position: new_p,
-                        direction: new_d,
-                        cost: new_c,
-                        estimated_cost: new_c + heuristic,
-                    });
+                    // Among equal estimates, expand the costlier state first, as it is
+                    // likely closer to the goal
+                    queue.push((new_p, new_d), (new_c + heuristic, Reverse(new_c)));
                 }
             }
         }
         costs
     }
-}
-
-pub struct Entry {
-    position: IVec2,
-    direction: IVec2,
-    cost: u32,
-    estimated_cost: u32,
-}
-
-impl std::cmp::PartialEq for Entry {
-    fn eq(&self, other: &Self) -> bool {
-        self.estimated_cost.eq(&other.estimated_cost) && self.cost.eq(&other.cost)
-    }
-}
-
-impl std::cmp::Eq for Entry {}
-
-impl std::cmp::Ord for Entry {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        use std::cmp::Ordering;
-        let o = match self.estimated_cost.cmp(&other.estimated_cost) {
-            Ordering::Equal => return self.cost.cmp(&other.cost),
-            o => o,
-        };
-        match o {
-            Ordering::Less => Ordering::Greater,
-            Ordering::Equal => Ordering::Equal,
-            Ordering::Greater => Ordering::Less,
-        }
-    }
-}
 
-impl std::cmp::PartialOrd for Entry {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+    fn search(&self) -> Search<(IVec2, IVec2), u32> {
+        search::astar(
+            [(self.start, D_0)],
+            |&(pos, dir)| {
+                [
+                    ((pos + dir, dir), 1),
+                    ((pos, dir.perp()), 1000),
+                    ((pos, -dir.perp()), 1000),
+                ]
+                .into_iter()
+                .filter(|((p, _), _)| self.map[*p] != b'#')
+            },
+            |&(pos, _)| (self.end - pos).abs().element_sum().unsigned_abs(),
+            |&(pos, _)| pos == self.end,
+        )
     }
 }
 
@@ -244,6 +200,19 @@ impl std::str::FromStr for Puzzle {
 const DIRECTIONS: [IVec2; 4] = [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y];
 const D_0: IVec2 = IVec2::X;
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -39,9 +39,22 @@ pub fn astar(puzzle: Puzzle) -> u32 {
         .expect("a solution to be found")
 }
 
+#[must_use]
+#[allow(clippy::needless_pass_by_value)]
+pub fn search(puzzle: Puzzle) -> u32 {
+    puzzle
+        .search()
+        .cheapest(crate::DIRECTIONS.map(|d| (puzzle.end, d)))
+        .expect("a solution to be found")
+        .1
+}
+
+common::solution!(Part1: Puzzle => u32, bfs);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use tap::prelude::*;
 
     use super::*;
@@ -51,10 +64,13 @@ mod tests {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let b = input.clone().pipe(bfs);
         let d = input.clone().pipe(dijkstras);
-        let a = input.pipe(astar);
-        assert_eq!(b, 7036);
-        assert_eq!(d, 7036);
-        assert_eq!(a, 7036);
+        let a = input.clone().pipe(astar);
+        let s = input.pipe(search);
+        let expected = common::expected!(Part::One, "example.txt");
+        assert_eq!(b.to_string(), expected);
+        assert_eq!(d.to_string(), expected);
+        assert_eq!(a.to_string(), expected);
+        assert_eq!(s.to_string(), expected);
         Ok(())
     }
 
@@ -63,10 +79,13 @@ mod tests {
         let input: Puzzle = common::read_input!("part1.txt").parse()?;
         let b = input.clone().pipe(bfs);
         let d = input.clone().pipe(dijkstras);
-        let a = input.pipe(astar);
-        assert_eq!(b, 65436);
-        assert_eq!(d, 65436);
-        assert_eq!(a, 65436);
+        let a = input.clone().pipe(astar);
+        let s = input.pipe(search);
+        let expected = common::expected!(Part::One, "part1.txt");
+        assert_eq!(b.to_string(), expected);
+        assert_eq!(d.to_string(), expected);
+        assert_eq!(a.to_string(), expected);
+        assert_eq!(s.to_string(), expected);
         Ok(())
     }
 }
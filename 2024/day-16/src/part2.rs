@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use itertools::Itertools;
+
 use crate::Puzzle;
 
 fn count_visited(costs: &crate::Costs, puzzle: &Puzzle) -> usize {
@@ -61,9 +63,27 @@ pub fn astar(puzzle: Puzzle) -> usize {
     count_visited(&costs, &puzzle)
 }
 
+#[must_use]
+#[allow(clippy::needless_pass_by_value)]
+pub fn search(puzzle: Puzzle) -> usize {
+    let search = puzzle.search();
+    let (ends, _) = search
+        .cheapest(crate::DIRECTIONS.map(|d| (puzzle.end, d)))
+        .expect("a solution to be found");
+    search
+        .on_shortest_paths(ends)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .unique()
+        .count()
+}
+
+common::solution!(Part2: Puzzle => usize, bfs);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use tap::prelude::*;
 
     use super::*;
@@ -73,10 +93,13 @@ mod tests {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let b = input.clone().pipe(bfs);
         let d = input.clone().pipe(dijkstras);
-        let a = input.pipe(astar);
-        assert_eq!(b, 45);
-        assert_eq!(d, 45);
-        assert_eq!(a, 45);
+        let a = input.clone().pipe(astar);
+        let s = input.pipe(search);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(b.to_string(), expected);
+        assert_eq!(d.to_string(), expected);
+        assert_eq!(a.to_string(), expected);
+        assert_eq!(s.to_string(), expected);
         Ok(())
     }
 
@@ -85,10 +108,13 @@ mod tests {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
         let b = input.clone().pipe(bfs);
         let d = input.clone().pipe(dijkstras);
-        let a = input.pipe(astar);
-        assert_eq!(b, 489);
-        assert_eq!(d, 489);
-        assert_eq!(a, 489);
+        let a = input.clone().pipe(astar);
+        let s = input.pipe(search);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(b.to_string(), expected);
+        assert_eq!(d.to_string(), expected);
+        assert_eq!(a.to_string(), expected);
+        assert_eq!(s.to_string(), expected);
         Ok(())
     }
 }
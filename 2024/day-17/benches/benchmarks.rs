@@ -40,3 +40,50 @@ fn part2(bencher: divan::Bencher) {
                 .pipe(part2::process)
         });
 }
+
+/// The first value of A to run the program with, as a brute force search for part 2 would, long
+/// enough to output as many digits as the real answer
+const SEARCH_FROM: u64 = 8u64.pow(15);
+
+/// How many values of A to run the program with
+const SEARCH: u64 = 1 << 10;
+
+#[divan::bench]
+fn search_cpu(bencher: divan::Bencher) {
+    bencher
+        .with_inputs(|| common::read_input!("part2.txt").parse::<Puzzle>())
+        .bench_values(|res| {
+            let puzzle = res.expect("parsing to suceed");
+            (SEARCH_FROM..SEARCH_FROM + SEARCH)
+                .map(|a| {
+                    let registers = Registers {
+                        a: divan::black_box(a),
+                        ..puzzle.registers()
+                    };
+                    Cpu::new(puzzle.program(), registers).count()
+                })
+                .sum::<usize>()
+        });
+}
+
+#[divan::bench]
+fn search_compiled(bencher: divan::Bencher) {
+    bencher
+        .with_inputs(|| {
+            let puzzle = common::read_input!("part2.txt").parse::<Puzzle>()?;
+            let compiled = puzzle.compile()?;
+            Ok::<_, color_eyre::Report>((puzzle, compiled))
+        })
+        .bench_values(|res| {
+            let (puzzle, compiled) = res.expect("parsing and compiling to suceed");
+            (SEARCH_FROM..SEARCH_FROM + SEARCH)
+                .map(|a| {
+                    let registers = Registers {
+                        a: divan::black_box(a),
+                        ..puzzle.registers()
+                    };
+                    compiled.run(registers).count()
+                })
+                .sum::<usize>()
+        });
+}
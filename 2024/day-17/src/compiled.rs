@@ -0,0 +1,206 @@
+use color_eyre::eyre::{bail, eyre};
+
+use crate::{dv, instruction, Combo, Instruction, Registers};
+
+/// What to run after an instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flow {
+    Next,
+    Out(u8),
+    /// To the instruction at this index, rather than an instruction pointer
+    Jump(usize),
+}
+
+type Op = Box<dyn Fn(&mut Registers) -> Flow + Send + Sync>;
+
+/// A program lowered to a closure per instruction, with every opcode and operand decoded ahead of
+/// time, for running it over and over with different registers
+pub struct Compiled {
+    ops: Vec<Op>,
+}
+
+impl std::fmt::Debug for Compiled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Compiled")
+            .field("ops", &self.ops.len())
+            .finish()
+    }
+}
+
+impl Compiled {
+    /// Compiles `program`, which has to jump only to even instruction pointers, and never use the
+    /// reserved combo operand
+    pub fn compile(program: &[u8]) -> color_eyre::Result<Self> {
+        let instructions = instruction::decode(program)?;
+        for (ip, instruction) in instructions.iter().enumerate().map(|(i, &x)| (i * 2, x)) {
+            if let Instruction::Jnz(target) = instruction {
+                if target % 2 != 0 {
+                    bail!("`{instruction}` at {ip} jumps between instructions");
+                }
+            }
+        }
+        let ops = instructions
+            .into_iter()
+            .enumerate()
+            .map(|(i, instruction)| {
+                lower(instruction)
+                    .ok_or_else(|| eyre!("Invalid operand in `{instruction}` at {}", i * 2))
+            })
+            .collect::<color_eyre::Result<_>>()?;
+        Ok(Self { ops })
+    }
+
+    /// The program's output, run from the start with `registers`
+    #[must_use]
+    pub fn run(&self, registers: Registers) -> Outputs<'_> {
+        Outputs {
+            ops: &self.ops,
+            registers,
+            pc: 0,
+        }
+    }
+}
+
+/// The output of a [`Compiled`] program, which runs as far as its next `out` each time
+pub struct Outputs<'c> {
+    ops: &'c [Op],
+    registers: Registers,
+    /// The index of the next instruction
+    pc: usize,
+}
+
+impl Outputs<'_> {
+    /// The registers as they are so far
+    #[must_use]
+    pub const fn registers(&self) -> Registers {
+        self.registers
+    }
+}
+
+impl Iterator for Outputs<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.ops.get(self.pc)?(&mut self.registers) {
+                Flow::Next => self.pc += 1,
+                Flow::Out(x) => {
+                    self.pc += 1;
+                    break Some(x);
+                }
+                Flow::Jump(pc) => self.pc = pc,
+            }
+        }
+    }
+}
+
+/// The instruction as a closure, or `None` if it has an invalid combo operand
+fn lower(instruction: Instruction) -> Option<Op> {
+    Some(match instruction {
+        Instruction::Adv(x) => {
+            let x = combo(x)?;
+            Box::new(move |r| {
+                r.a = dv(r.a, x(r));
+                Flow::Next
+            })
+        }
+        Instruction::Bxl(x) => Box::new(move |r| {
+            r.b ^= u64::from(x);
+            Flow::Next
+        }),
+        Instruction::Bst(x) => {
+            let x = combo(x)?;
+            Box::new(move |r| {
+                r.b = x(r) % 8;
+                Flow::Next
+            })
+        }
+        Instruction::Jnz(x) => {
+            let target = usize::from(x) / 2;
+            Box::new(move |r| {
+                if r.a == 0 {
+                    Flow::Next
+                } else {
+                    Flow::Jump(target)
+                }
+            })
+        }
+        Instruction::Bxc(_) => Box::new(|r| {
+            r.b ^= r.c;
+            Flow::Next
+        }),
+        Instruction::Out(x) => {
+            let x = combo(x)?;
+            Box::new(move |r| Flow::Out((x(r) % 8) as u8))
+        }
+        Instruction::Bdv(x) => {
+            let x = combo(x)?;
+            Box::new(move |r| {
+                r.b = dv(r.a, x(r));
+                Flow::Next
+            })
+        }
+        Instruction::Cdv(x) => {
+            let x = combo(x)?;
+            Box::new(move |r| {
+                r.c = dv(r.a, x(r));
+                Flow::Next
+            })
+        }
+    })
+}
+
+/// Reads a combo operand, with literals baked in
+fn combo(x: Combo) -> Option<fn(&Registers) -> u64> {
+    Some(match x {
+        Combo::Literal(0) => |_| 0,
+        Combo::Literal(1) => |_| 1,
+        Combo::Literal(2) => |_| 2,
+        Combo::Literal(3) => |_| 3,
+        Combo::A => |r| r.a,
+        Combo::B => |r| r.b,
+        Combo::C => |r| r.c,
+        Combo::Literal(_) | Combo::Reserved => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{Cpu, Puzzle};
+
+    #[test]
+    fn test_matches_cpu() -> color_eyre::Result<()> {
+        let programs = [
+            "0,1,5,4,3,0",
+            "0,3,5,4,3,0",
+            "2,4,1,1,7,5,1,5,4,0,5,5,0,3,3,0",
+            "2,4,0,3,1,3,7,5,4,7,1,4,5,5,3,0",
+            "1,7,6,5,2,6,5,6",
+        ];
+        for program in programs {
+            let puzzle: Puzzle =
+                format!("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {program}")
+                    .parse()?;
+            let compiled = puzzle.compile()?;
+            for a in (0..200).chain([117_440, 729, 2024, u64::MAX]) {
+                let registers = Registers { a, b: 9, c: 43_690 };
+                assert_eq!(
+                    compiled.run(registers).collect_vec(),
+                    Cpu::new(puzzle.program(), registers).collect_vec(),
+                    "{program} with {registers}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects() {
+        assert!(Compiled::compile(&[0, 3, 5, 4, 3, 1]).is_err());
+        assert!(Compiled::compile(&[0, 7, 3, 0]).is_err());
+        assert!(Compiled::compile(&[0, 3, 5]).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+use std::{collections::BTreeSet, fmt};
+
+use crate::{Cpu, Instruction};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a={:o} b={:o} c={:o}", self.a, self.b, self.c)
+    }
+}
+
+/// One instruction the debugger has run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    /// Where the instruction was
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+    pub out: Option<u8>,
+}
+
+impl fmt::Display for Trace {
+    /// Such as `  2: bxl 3  a=27 b=5 c=0 -> a=27 b=6 c=0`, with registers in octal
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>3}: {:<6} {} -> {}",
+            self.ip,
+            self.instruction.to_string(),
+            self.before,
+            self.after
+        )?;
+        if let Some(out) = self.out {
+            write!(f, ", out {out}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Why the debugger stopped running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The next instruction is at a breakpoint
+    Breakpoint(usize),
+    /// The instruction pointer has run off the end of the program
+    Halted,
+}
+
+/// Runs a program an instruction at a time, keeping a trace of each one
+#[derive(Debug, Clone)]
+pub struct Debugger<'p> {
+    cpu: Cpu<'p>,
+    breakpoints: BTreeSet<usize>,
+    trace: Vec<Trace>,
+    output: Vec<u8>,
+}
+
+impl<'p> Debugger<'p> {
+    pub(crate) const fn new(cpu: Cpu<'p>) -> Self {
+        Self {
+            cpu,
+            breakpoints: BTreeSet::new(),
+            trace: Vec::new(),
+            output: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn registers(&self) -> Registers {
+        Registers {
+            a: self.cpu.a,
+            b: self.cpu.b,
+            c: self.cpu.c,
+        }
+    }
+
+    pub const fn set_registers(&mut self, Registers { a, b, c }: Registers) {
+        self.cpu.a = a;
+        self.cpu.b = b;
+        self.cpu.c = c;
+    }
+
+    /// The instruction pointer
+    #[must_use]
+    pub const fn ip(&self) -> usize {
+        self.cpu.ip
+    }
+
+    /// Jumps to `ip`, without running anything
+    pub const fn jump(&mut self, ip: usize) {
+        self.cpu.ip = ip;
+    }
+
+    /// The instruction which will run next, if the program hasn't halted
+    #[must_use]
+    pub fn next_instruction(&self) -> Option<Instruction> {
+        self.cpu.fetch()
+    }
+
+    #[must_use]
+    pub fn is_halted(&self) -> bool {
+        self.next_instruction().is_none()
+    }
+
+    /// Stops before running the instruction at `ip`, returning whether it's a new breakpoint
+    pub fn add_breakpoint(&mut self, ip: usize) -> bool {
+        self.breakpoints.insert(ip)
+    }
+
+    /// Returns whether there was a breakpoint at `ip`
+    pub fn remove_breakpoint(&mut self, ip: usize) -> bool {
+        self.breakpoints.remove(&ip)
+    }
+
+    pub fn breakpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.breakpoints.iter().copied()
+    }
+
+    /// Every instruction run so far, oldest first
+    #[must_use]
+    pub fn trace(&self) -> &[Trace] {
+        &self.trace
+    }
+
+    /// Everything output so far
+    #[must_use]
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Runs the next instruction, or returns `None` if the program has halted
+    pub fn step(&mut self) -> Option<&Trace> {
+        let (ip, before) = (self.ip(), self.registers());
+        let (instruction, out) = self.cpu.step()?;
+        self.output.extend(out);
+        self.trace.push(Trace {
+            ip,
+            instruction,
+            before,
+            after: self.registers(),
+            out,
+        });
+        self.trace.last()
+    }
+
+    /// Runs until the next instruction is at a breakpoint, or the program halts. The instruction
+    /// at the current breakpoint, if any, is always run, so this can be called again to carry on.
+    /// Like the program, this may never stop.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            if self.step().is_none() {
+                break Stop::Halted;
+            }
+            if self.breakpoints.contains(&self.ip()) && !self.is_halted() {
+                break Stop::Breakpoint(self.ip());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Combo, Puzzle};
+
+    use super::*;
+
+    #[test]
+    fn test_breakpoints() -> color_eyre::Result<()> {
+        let puzzle: Puzzle = "Register A: 729\nRegister B: 0\nRegister C: 0\n\n\
+            Program: 0,1,5,4,3,0"
+            .parse()?;
+        let mut debugger = puzzle.debugger();
+        assert_eq!(
+            debugger.next_instruction(),
+            Some(Instruction::Adv(Combo::Literal(1)))
+        );
+        assert!(debugger.add_breakpoint(4));
+
+        assert_eq!(debugger.resume(), Stop::Breakpoint(4));
+        assert_eq!(debugger.output(), [4]);
+        assert_eq!(debugger.registers().a, 729 / 2);
+        assert_eq!(debugger.trace().len(), 2);
+
+        assert_eq!(debugger.resume(), Stop::Breakpoint(4));
+        assert_eq!(debugger.output(), [4, 6]);
+        assert_eq!(debugger.trace()[2].instruction, Instruction::Jnz(0));
+        assert_eq!(debugger.trace()[2].ip, 4);
+
+        assert!(debugger.remove_breakpoint(4));
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.output(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+        assert!(debugger.step().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace() -> color_eyre::Result<()> {
+        let puzzle: Puzzle =
+            "Register A: 0\nRegister B: 29\nRegister C: 0\n\nProgram: 1,7,5,5".parse()?;
+        let mut debugger = puzzle.debugger();
+        debugger.set_registers(Registers { a: 0, b: 8, c: 0 });
+        assert_eq!(
+            debugger.step().map(ToString::to_string).as_deref(),
+            Some("  0: bxl 7  a=0 b=10 c=0 -> a=0 b=17 c=0")
+        );
+        assert_eq!(
+            debugger.step().map(ToString::to_string).as_deref(),
+            Some("  2: out b  a=0 b=17 c=0 -> a=0 b=17 c=0, out 7")
+        );
+        assert_eq!(debugger.ip(), 4);
+        assert!(debugger.is_halted());
+        Ok(())
+    }
+}
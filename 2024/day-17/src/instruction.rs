@@ -0,0 +1,263 @@
+use std::fmt;
+
+use color_eyre::eyre::{bail, eyre, OptionExt, WrapErr};
+use itertools::Itertools;
+
+/// A combo operand: a literal from 0 to 3, or one of the registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Combo {
+    Literal(u8),
+    A,
+    B,
+    C,
+    /// Operand 7, which the puzzle says never appears in a valid program
+    Reserved,
+}
+
+impl Combo {
+    /// Decodes a 3-bit operand
+    #[must_use]
+    pub const fn decode(operand: u8) -> Option<Self> {
+        Some(match operand {
+            0..=3 => Self::Literal(operand),
+            4 => Self::A,
+            5 => Self::B,
+            6 => Self::C,
+            7 => Self::Reserved,
+            _ => return None,
+        })
+    }
+
+    #[must_use]
+    pub const fn encode(self) -> u8 {
+        match self {
+            Self::Literal(x) => x,
+            Self::A => 4,
+            Self::B => 5,
+            Self::C => 6,
+            Self::Reserved => 7,
+        }
+    }
+}
+
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(x) => write!(f, "{x}"),
+            Self::A => f.write_str("a"),
+            Self::B => f.write_str("b"),
+            Self::C => f.write_str("c"),
+            Self::Reserved => f.write_str("7"),
+        }
+    }
+}
+
+impl std::str::FromStr for Combo {
+    type Err = color_eyre::Report;
+
+    /// A register by name, or any operand by number
+    fn from_str(s: &str) -> color_eyre::Result<Self> {
+        match s {
+            "a" => Ok(Self::A),
+            "b" => Ok(Self::B),
+            "c" => Ok(Self::C),
+            _ => Ok(Self::decode(operand(s)?).expect("operand to be 3 bits")),
+        }
+    }
+}
+
+/// One instruction of the 3-bit computer, with its operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// Opcode 0: `A = A / 2^x`
+    Adv(Combo),
+    /// Opcode 1: `B = B ^ x`
+    Bxl(u8),
+    /// Opcode 2: `B = x % 8`
+    Bst(Combo),
+    /// Opcode 3: jumps to `x` unless `A` is 0
+    Jnz(u8),
+    /// Opcode 4: `B = B ^ C`, ignoring its operand (which is kept so it can be reassembled)
+    Bxc(u8),
+    /// Opcode 5: outputs `x % 8`
+    Out(Combo),
+    /// Opcode 6: `B = A / 2^x`
+    Bdv(Combo),
+    /// Opcode 7: `C = A / 2^x`
+    Cdv(Combo),
+}
+
+impl Instruction {
+    /// Decodes an opcode and its operand, if both are 3 bits
+    #[must_use]
+    pub const fn decode(opcode: u8, operand: u8) -> Option<Self> {
+        let Some(combo) = Combo::decode(operand) else {
+            return None;
+        };
+        Some(match opcode {
+            0 => Self::Adv(combo),
+            1 => Self::Bxl(operand),
+            2 => Self::Bst(combo),
+            3 => Self::Jnz(operand),
+            4 => Self::Bxc(operand),
+            5 => Self::Out(combo),
+            6 => Self::Bdv(combo),
+            7 => Self::Cdv(combo),
+            _ => return None,
+        })
+    }
+
+    /// The opcode and operand
+    #[must_use]
+    pub const fn encode(self) -> [u8; 2] {
+        match self {
+            Self::Adv(x) => [0, x.encode()],
+            Self::Bxl(x) => [1, x],
+            Self::Bst(x) => [2, x.encode()],
+            Self::Jnz(x) => [3, x],
+            Self::Bxc(x) => [4, x],
+            Self::Out(x) => [5, x.encode()],
+            Self::Bdv(x) => [6, x.encode()],
+            Self::Cdv(x) => [7, x.encode()],
+        }
+    }
+
+    #[must_use]
+    pub const fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Adv(_) => "adv",
+            Self::Bxl(_) => "bxl",
+            Self::Bst(_) => "bst",
+            Self::Jnz(_) => "jnz",
+            Self::Bxc(_) => "bxc",
+            Self::Out(_) => "out",
+            Self::Bdv(_) => "bdv",
+            Self::Cdv(_) => "cdv",
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = self.mnemonic();
+        match self {
+            Self::Adv(x) | Self::Bst(x) | Self::Out(x) | Self::Bdv(x) | Self::Cdv(x) => {
+                write!(f, "{mnemonic} {x}")
+            }
+            Self::Bxl(x) | Self::Jnz(x) | Self::Bxc(x @ 1..) => write!(f, "{mnemonic} {x}"),
+            Self::Bxc(0) => f.write_str(mnemonic),
+        }
+    }
+}
+
+impl std::str::FromStr for Instruction {
+    type Err = color_eyre::Report;
+
+    /// One line of assembly, as written by `Display`
+    fn from_str(s: &str) -> color_eyre::Result<Self> {
+        let mut words = s.split_whitespace();
+        let mnemonic = words.next().ok_or_eyre("Missing instruction")?;
+        let argument = words.next();
+        if let Some(extra) = words.next() {
+            bail!("Unexpected {extra:?} after the operand");
+        }
+        let required = || argument.ok_or_else(|| eyre!("{mnemonic} needs an operand"));
+        Ok(match mnemonic {
+            "adv" => Self::Adv(required()?.parse()?),
+            "bxl" => Self::Bxl(operand(required()?)?),
+            "bst" => Self::Bst(required()?.parse()?),
+            "jnz" => Self::Jnz(operand(required()?)?),
+            "bxc" => Self::Bxc(argument.map_or(Ok(0), operand)?),
+            "out" => Self::Out(required()?.parse()?),
+            "bdv" => Self::Bdv(required()?.parse()?),
+            "cdv" => Self::Cdv(required()?.parse()?),
+            _ => bail!("Unknown instruction {mnemonic:?}"),
+        })
+    }
+}
+
+/// A literal operand, which has to fit in 3 bits
+fn operand(s: &str) -> color_eyre::Result<u8> {
+    let x: u8 = s
+        .parse()
+        .wrap_err_with(|| format!("Invalid operand {s:?}"))?;
+    if x > 7 {
+        bail!("Operand {x} doesn't fit in 3 bits");
+    }
+    Ok(x)
+}
+
+/// Decodes a whole program, which has to be an even number of 3-bit numbers
+pub fn decode(program: &[u8]) -> color_eyre::Result<Vec<Instruction>> {
+    if !program.len().is_multiple_of(2) {
+        bail!("Program has an odd length of {}", program.len());
+    }
+    program
+        .iter()
+        .tuples()
+        .enumerate()
+        .map(|(i, (&opcode, &operand))| {
+            Instruction::decode(opcode, operand)
+                .ok_or_else(|| eyre!("Invalid instruction {opcode},{operand} at {}", i * 2))
+        })
+        .collect()
+}
+
+/// The program as assembly, one instruction per line
+pub fn disassemble(program: &[u8]) -> color_eyre::Result<String> {
+    Ok(decode(program)?.iter().join("\n"))
+}
+
+/// The program which `assembly` describes, one instruction per line. Blank lines, and anything
+/// after a `;`, are ignored.
+pub fn assemble(assembly: &str) -> color_eyre::Result<Vec<u8>> {
+    assembly
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.split(';').next().unwrap_or_default().trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            line.parse::<Instruction>()
+                .map(Instruction::encode)
+                .wrap_err_with(|| format!("Line {}: {line:?}", i + 1))
+        })
+        .flatten_ok()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Instruction::Adv(Combo::Literal(3)).to_string(), "adv 3");
+        assert_eq!(Instruction::Out(Combo::B).to_string(), "out b");
+        assert_eq!(Instruction::Jnz(0).to_string(), "jnz 0");
+        assert_eq!(Instruction::Bxc(0).to_string(), "bxc");
+        assert_eq!(Instruction::Bxc(5).to_string(), "bxc 5");
+    }
+
+    #[test]
+    fn test_round_trip() -> color_eyre::Result<()> {
+        let program = (0..8)
+            .cartesian_product(0..8)
+            .flat_map(<[u8; 2]>::from)
+            .collect_vec();
+        let assembly = disassemble(&program)?;
+        assert_eq!(assemble(&assembly)?, program);
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble() -> color_eyre::Result<()> {
+        let assembly = "adv 3 ; A = A >> 3\n\nout a\njnz 0\n";
+        assert_eq!(assemble(assembly)?, [0, 3, 5, 4, 3, 0]);
+        assert_eq!(disassemble(&[0, 3, 5, 4, 3, 0])?, "adv 3\nout a\njnz 0");
+        assert!(assemble("bxl a").is_err());
+        assert!(assemble("adv 8").is_err());
+        assert!(assemble("nop").is_err());
+        assert!(disassemble(&[0, 3, 5]).is_err());
+        Ok(())
+    }
+}
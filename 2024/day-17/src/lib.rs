@@ -2,6 +2,13 @@ use color_eyre::eyre::OptionExt;
 use itertools::Itertools;
 use tap::prelude::*;
 
+pub use compiled::Compiled;
+pub use debugger::{Debugger, Registers, Stop, Trace};
+pub use instruction::{assemble, disassemble, Combo, Instruction};
+
+pub mod compiled;
+pub mod debugger;
+pub mod instruction;
 pub mod part1;
 pub mod part2;
 
@@ -13,7 +20,7 @@ pub struct Puzzle {
     program: Vec<u8>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Cpu<'p> {
     a: u64,
     b: u64,
@@ -27,47 +34,59 @@ impl Iterator for Cpu<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            assert_eq!(self.ip % 2, 0);
-            match self.program.get(self.ip..=self.ip + 1) {
-                Some([0, x]) => {
-                    self.a /= 1 << self.combo(*x);
-                    self.ip += 2;
-                }
-                Some([1, x]) => {
-                    self.b ^= u64::from(*x);
-                    self.ip += 2;
-                }
-                Some([2, x]) => {
-                    self.b = self.combo(*x) % 8;
-                    self.ip += 2;
-                }
-                Some([3, x]) => {
-                    if self.a != 0 {
-                        self.ip = usize::from(*x);
-                    } else {
-                        self.ip += 2;
-                    }
-                }
-                Some([4, _]) => {
-                    self.b ^= self.c;
-                    self.ip += 2;
-                }
-                Some([5, x]) => {
-                    self.ip += 2;
-                    break Some((self.combo(*x) % 8) as u8);
-                }
-                Some([6, x]) => {
-                    self.b = self.a / (1 << self.combo(*x));
-                    self.ip += 2;
-                }
-                Some([7, x]) => {
-                    self.c = self.a / (1 << self.combo(*x));
-                    self.ip += 2;
-                }
-                None | Some([_]) => break None,
-                _ => unreachable!(),
+            if let (_, Some(out)) = self.step()? {
+                break Some(out);
+            }
+        }
+    }
+}
+
+impl<'p> Cpu<'p> {
+    /// Runs `program` from the start with `registers`
+    #[must_use]
+    pub const fn new(program: &'p [u8], Registers { a, b, c }: Registers) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            ip: 0,
+            program,
+        }
+    }
+
+    /// The instruction at the instruction pointer, or `None` if it's run off the end of the
+    /// program
+    fn fetch(&self) -> Option<Instruction> {
+        match self.program.get(self.ip..self.ip + 2)? {
+            &[opcode, operand] => Some(
+                Instruction::decode(opcode, operand)
+                    .unwrap_or_else(|| panic!("Invalid instruction {opcode},{operand}")),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Runs one instruction, returning it along with anything it output, or `None` if the
+    /// program has halted
+    fn step(&mut self) -> Option<(Instruction, Option<u8>)> {
+        let instruction = self.fetch()?;
+        let mut out = None;
+        match instruction {
+            Instruction::Adv(x) => self.a = dv(self.a, self.combo(x)),
+            Instruction::Bxl(x) => self.b ^= u64::from(x),
+            Instruction::Bst(x) => self.b = self.combo(x) % 8,
+            Instruction::Jnz(x) if self.a != 0 => {
+                self.ip = usize::from(x);
+                return Some((instruction, None));
             }
+            Instruction::Jnz(_) => {}
+            Instruction::Bxc(_) => self.b ^= self.c,
+            Instruction::Out(x) => out = Some((self.combo(x) % 8) as u8),
+            Instruction::Bdv(x) => self.b = dv(self.a, self.combo(x)),
+            Instruction::Cdv(x) => self.c = dv(self.a, self.combo(x)),
         }
+        self.ip += 2;
+        Some((instruction, out))
     }
 }
 
@@ -86,6 +105,37 @@ impl Puzzle {
     fn iter(&self) -> Cpu<'_> {
         self.into_iter()
     }
+
+    #[must_use]
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// The registers the program starts with
+    #[must_use]
+    pub const fn registers(&self) -> Registers {
+        Registers {
+            a: self.a,
+            b: self.b,
+            c: self.c,
+        }
+    }
+
+    /// The program, compiled to run faster than `Cpu` can interpret it
+    pub fn compile(&self) -> color_eyre::Result<Compiled> {
+        Compiled::compile(&self.program)
+    }
+
+    /// The program as assembly, such as `adv 3` / `out a` / `jnz 0`
+    pub fn disassemble(&self) -> color_eyre::Result<String> {
+        instruction::disassemble(&self.program)
+    }
+
+    /// A debugger for the program, stopped before its first instruction
+    #[must_use]
+    pub fn debugger(&self) -> Debugger<'_> {
+        Debugger::new(self.iter())
+    }
 }
 
 impl<'p> IntoIterator for &'p Puzzle {
@@ -94,34 +144,29 @@ impl<'p> IntoIterator for &'p Puzzle {
     type IntoIter = Cpu<'p>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Cpu {
-            a: self.a,
-            b: self.b,
-            c: self.c,
-            ip: 0,
-            program: &self.program,
-        }
+        Cpu::new(&self.program, self.registers())
     }
 }
 
 impl Cpu<'_> {
-    fn combo(&self, x: u8) -> u64 {
+    fn combo(&self, x: Combo) -> u64 {
         match x {
-            x @ 0..=3 => x.into(),
-            4 => self.a,
-            5 => self.b,
-            6 => self.c,
-            _ => panic!("Invalid operand {x}"),
+            Combo::Literal(x) => x.into(),
+            Combo::A => self.a,
+            Combo::B => self.b,
+            Combo::C => self.c,
+            Combo::Reserved => panic!("Invalid operand {}", x.encode()),
         }
     }
 }
 
-// enum Instruction {
-//     Adv(u8), // opcode 0, combo,   A = A / 2^x
-//     Bxl(u8), // opcode 1, literal, B = B ^ x
-//     Bst(u8), // opcode 2, combo,   B = x & 0b111
-//     Jnz(u8), //
-// }
+/// `a / 2^x`, which is 0 once `x` shifts every bit out
+fn dv(a: u64, x: u64) -> u64 {
+    u32::try_from(x)
+        .ok()
+        .and_then(|shift| a.checked_shr(shift))
+        .unwrap_or(0)
+}
 
 impl std::str::FromStr for Puzzle {
     type Err = color_eyre::Report;
@@ -145,6 +190,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -12,9 +12,12 @@ pub fn process(puzzle: Puzzle) -> String {
         .join(",")
 }
 
+common::solution!(Part1: Puzzle => String, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
 
     use super::*;
 
@@ -82,7 +85,7 @@ mod tests {
     fn test_example() -> Result<()> {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(output, common::expected!(Part::One, "example.txt"));
         Ok(())
     }
 
@@ -90,7 +93,7 @@ mod tests {
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part1.txt").parse()?;
         let output = process(input);
-        assert_eq!(output, "2,7,4,7,2,1,7,5,1");
+        assert_eq!(output, common::expected!(Part::One, "part1.txt"));
         Ok(())
     }
 }
@@ -1,43 +1,152 @@
-use itertools::Itertools;
+use crate::{instruction, Combo, Compiled, Instruction, Puzzle, Registers};
 
-use crate::Puzzle;
+/// Why a program's quines can't be found
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// the program can't be decoded: {0}
+    Invalid(String),
+    /// operand 7 at {0} is reserved
+    Reserved(usize),
+    /// the program doesn't end by jumping back to the start with `jnz 0`
+    NoLoop,
+    /// the program jumps at {0} before the end of its loop
+    Jumps(usize),
+    /// the program has to shift A by 3 bits once per loop with `adv 3`, but {0}
+    Shift(String),
+    /// the program has to output once per loop, but outputs {0} times
+    Outputs(usize),
+    /// the program reads {0} at {1} before setting it from A, so its output depends on more than A
+    Carried(char, usize),
+    /// a program of {0} digits is too long for A to fit in 64 bits
+    TooLong(usize),
+    /// no value of A makes the program output itself
+    NoQuine,
+}
 
-#[must_use]
+/// The lowest value of A which makes the program output itself
 #[allow(clippy::needless_pass_by_value)]
-pub fn process(puzzle: Puzzle) -> u64 {
-    // can only claim partial credit for this.  I noticed that the was a function of
-    // the least significant bits of a, but didn't realize that the bits involved could overlap
-    let mut factors = vec![0u64; puzzle.program.len()];
-    *factors.last_mut().expect("Non empty") = 1;
-    loop {
-        let mut cpu = puzzle.iter();
-        let a = factors
-            .iter()
-            .copied()
-            .enumerate()
-            .map(|(p, f)| f << ((p * 3) as u64))
-            .sum::<u64>();
-        cpu.a = a;
-        let output = cpu.collect_vec();
-        if output == puzzle.program {
-            break a;
+pub fn process(puzzle: Puzzle) -> Result<u64, Error> {
+    quines(&puzzle)?.first().copied().ok_or(Error::NoQuine)
+}
+
+/// Every value of A which makes the program output itself, in ascending order.
+///
+/// Each time round its loop, the program outputs a digit which depends only on A, then shifts A
+/// along by 3 bits until it's 0. So the last digit output depends only on the top 3 bits of A,
+/// the one before on the top 6 bits and so on, and A can be built up 3 bits at a time from the
+/// top, backtracking whenever no 3 bits give the next digit back from the end of the program.
+pub fn quines(puzzle: &Puzzle) -> Result<Vec<u64>, Error> {
+    check_shape(&puzzle.program)?;
+    let digits = puzzle.program.len();
+    if digits * 3 > 63 {
+        return Err(Error::TooLong(digits));
+    }
+    let compiled = puzzle
+        .compile()
+        .map_err(|err| Error::Invalid(err.to_string()))?;
+    let mut found = vec![];
+    search(puzzle, &compiled, 0, 0, &mut found);
+    Ok(found)
+}
+
+/// Finds each quine whose top `depth` octal digits are `a`
+fn search(puzzle: &Puzzle, compiled: &Compiled, a: u64, depth: usize, found: &mut Vec<u64>) {
+    let digits = puzzle.program.len();
+    if depth == digits {
+        found.push(a);
+        return;
+    }
+    let target = puzzle.program[digits - 1 - depth];
+    // A leading 0 would leave the program a loop short
+    let first = u64::from(depth == 0);
+    for digit in first..8 {
+        let a = a << 3 | digit;
+        let registers = Registers {
+            a,
+            ..puzzle.registers()
+        };
+        if compiled.run(registers).next() == Some(target) {
+            search(puzzle, compiled, a, depth + 1, found);
         }
+    }
+}
 
-        for idx in (0..puzzle.program.len()).rev() {
-            if output[idx] != puzzle.program[idx] {
-                factors[idx] += 1;
-                break;
+/// Checks that the program is a single loop which outputs one digit, depending only on A, for
+/// every 3 bits of A
+fn check_shape(program: &[u8]) -> Result<(), Error> {
+    let instructions =
+        instruction::decode(program).map_err(|err| Error::Invalid(err.to_string()))?;
+    let Some((Instruction::Jnz(0), body)) = instructions.split_last() else {
+        return Err(Error::NoLoop);
+    };
+
+    let shifts = body
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::Adv(_)))
+        .collect::<Vec<_>>();
+    match shifts.as_slice() {
+        [Instruction::Adv(Combo::Literal(3))] => {}
+        [] => return Err(Error::Shift("it never shifts A".to_owned())),
+        [shift] => return Err(Error::Shift(format!("it has `{shift}`"))),
+        _ => return Err(Error::Shift(format!("it shifts A {} times", shifts.len()))),
+    }
+    let outputs = body
+        .iter()
+        .filter(|instruction| matches!(instruction, Instruction::Out(_)))
+        .count();
+    if outputs != 1 {
+        return Err(Error::Outputs(outputs));
+    }
+
+    // Whether B and C have been set from A yet this time round the loop
+    let (mut b, mut c) = (false, false);
+    for (ip, instruction) in body.iter().enumerate().map(|(i, &x)| (i * 2, x)) {
+        let read = |register| match register {
+            'b' if !b => Err(Error::Carried('b', ip)),
+            'c' if !c => Err(Error::Carried('c', ip)),
+            _ => Ok(()),
+        };
+        let combo = |x| match x {
+            Combo::Literal(_) | Combo::A => Ok(()),
+            Combo::B => read('b'),
+            Combo::C => read('c'),
+            Combo::Reserved => Err(Error::Reserved(ip)),
+        };
+        match instruction {
+            Instruction::Jnz(_) => return Err(Error::Jumps(ip)),
+            Instruction::Adv(x) | Instruction::Out(x) => combo(x)?,
+            Instruction::Bxl(_) => read('b')?,
+            Instruction::Bxc(_) => {
+                read('b')?;
+                read('c')?;
+            }
+            Instruction::Bst(x) | Instruction::Bdv(x) => {
+                combo(x)?;
+                b = true;
+            }
+            Instruction::Cdv(x) => {
+                combo(x)?;
+                c = true;
             }
         }
     }
+    Ok(())
 }
 
+common::solution!(Part2: Puzzle => u64, |puzzle| Ok(process(puzzle)?));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
+    use itertools::Itertools;
 
     use super::*;
 
+    fn puzzle(program: &str) -> Result<Puzzle> {
+        format!("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {program}").parse()
+    }
+
     #[test]
     fn test_example() {
         let input: Puzzle = Puzzle {
@@ -52,11 +161,50 @@ mod tests {
             .any(|(actual, expected)| actual != *expected));
     }
 
+    #[test]
+    fn test_quines() -> Result<()> {
+        let input = puzzle("0,3,5,4,3,0")?;
+        let quines = quines(&input)?;
+        assert_eq!(quines.first(), Some(&117_440));
+        assert!(quines.iter().tuple_windows().all(|(a, b)| a < b));
+        for &a in &quines {
+            let mut cpu = input.iter();
+            cpu.a = a;
+            assert_eq!(cpu.collect_vec(), input.program);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported() -> Result<()> {
+        let shape = |program| quines(&puzzle(program).expect("a valid puzzle"));
+        assert!(matches!(shape("0,1,5,4,3,0"), Err(Error::Shift(_))));
+        assert!(matches!(shape("5,4,3,0"), Err(Error::Shift(_))));
+        assert!(matches!(shape("0,3,0,3,5,4,3,0"), Err(Error::Shift(_))));
+        assert!(matches!(shape("0,3,5,4,5,4,3,0"), Err(Error::Outputs(2))));
+        assert!(matches!(shape("0,3,5,4,3,2"), Err(Error::NoLoop)));
+        assert!(matches!(shape("0,3,3,0,5,4,3,0"), Err(Error::Jumps(2))));
+        assert!(matches!(
+            shape("1,1,0,3,5,5,3,0"),
+            Err(Error::Carried('b', 0))
+        ));
+        assert!(matches!(shape("0,3,5,7,3,0"), Err(Error::Reserved(2))));
+        assert!(matches!(shape("0,3,5,0,3,0"), Ok(quines) if quines.is_empty()));
+        assert!(matches!(
+            process(puzzle("0,3,5,0,3,0")?),
+            Err(Error::NoQuine)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
-        let output = process(input);
-        assert_eq!(output, 37_221_274_271_220);
+        let output = process(input)?;
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::Two, "part2.txt")
+        );
         Ok(())
     }
 }
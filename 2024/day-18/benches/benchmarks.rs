@@ -26,6 +26,28 @@ fn part1(bencher: divan::Bencher) {
         });
 }
 
+#[divan::bench]
+fn part1_bfs(bencher: divan::Bencher) {
+    bencher
+        .with_inputs(|| common::read_input!("part1.txt").parse::<Puzzle>())
+        .bench_values(|res| {
+            res.expect("parsing to suceed")
+                .pipe(divan::black_box)
+                .pipe(part1::process_bfs)
+        });
+}
+
+#[divan::bench]
+fn part1_par_bfs(bencher: divan::Bencher) {
+    bencher
+        .with_inputs(|| common::read_input!("part1.txt").parse::<Puzzle>())
+        .bench_values(|res| {
+            res.expect("parsing to suceed")
+                .pipe(divan::black_box)
+                .pipe(part1::process_par_bfs)
+        });
+}
+
 #[divan::bench]
 fn part2_astar(bencher: divan::Bencher) {
     bencher
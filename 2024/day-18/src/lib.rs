@@ -1,6 +1,4 @@
-use std::collections::BinaryHeap;
-
-use common::grid::Grid;
+use common::{grid::Grid, search};
 use glam::IVec2;
 use itertools::Itertools;
 use tap::prelude::*;
@@ -47,6 +45,29 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    const VARIANTS: &'static [common::bench::Variant] = common::variants![
+        One "astar": str::parse::<Puzzle> => part1::process;
+        One "bfs": str::parse::<Puzzle> => part1::process_bfs;
+        One "par_bfs": str::parse::<Puzzle> => part1::process_par_bfs;
+        Two "astar": str::parse::<Puzzle> => part2::process_astar;
+        Two "astar_rev": str::parse::<Puzzle> => part2::process_astar_rev;
+        Two "astar_binary": str::parse::<Puzzle> => part2::process_astar_binary;
+        Two "uf": str::parse::<Puzzle> => part2::process_uf;
+    ];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -79,84 +100,31 @@ fn is_passable_on(map: &Grid<Option<usize>>, pos: IVec2, t: usize) -> bool {
     }
 }
 
-fn astar(map: &Grid<Option<usize>>, cutoff: usize) -> usize {
-    let goal = map.size() - IVec2::ONE;
-    let mut costs = Grid::from_value(usize::MAX, map.size());
-    costs[IVec2::ZERO] = 0;
-    let mut stack = BinaryHeap::<Entry>::new();
-    stack.push(Entry {
-        position: IVec2::ZERO,
-        cost: 0,
-        estimated_cost: 0,
-    });
-    while let Some(Entry {
-        position: pos,
-        cost,
-        ..
-    }) = stack.pop()
-    {
-        for new_p in [IVec2::X, IVec2::Y, -IVec2::X, -IVec2::Y]
-            .into_iter()
-            .map(|d| pos + d)
-            // .filter(|pos| grid.get(pos).is_some())
-            .filter(|pos| is_passable_on(map, *pos, cutoff))
-        {
-            let heuristic = {
-                let delta = goal - new_p;
-                delta.x.abs() + delta.y.abs()
-            }
-            .try_conv::<usize>()
-            .expect("the conversion to succeed");
-
-            let prev = costs[new_p];
-            if prev > cost + 1 {
-                costs[new_p] = cost + 1;
-                stack.push(Entry {
-                    position: new_p,
-                    cost: cost + 1,
-                    estimated_cost: cost + heuristic + 1,
-                });
-                if new_p == goal {
-                    break;
-                }
-            }
-        }
-    }
-
-    costs[goal]
-}
-
-struct Entry {
-    position: IVec2,
-    cost: usize,
-    estimated_cost: usize,
-}
-
-impl std::cmp::PartialEq for Entry {
-    fn eq(&self, other: &Self) -> bool {
-        self.estimated_cost.eq(&other.estimated_cost) && self.cost.eq(&other.cost)
-    }
-}
-
-impl std::cmp::Eq for Entry {}
-
-impl std::cmp::Ord for Entry {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        use std::cmp::Ordering;
-        let o = match self.estimated_cost.cmp(&other.estimated_cost) {
-            Ordering::Equal => return self.cost.cmp(&other.cost),
-            o => o,
-        };
-        match o {
-            Ordering::Less => Ordering::Greater,
-            Ordering::Equal => Ordering::Equal,
-            Ordering::Greater => Ordering::Less,
-        }
-    }
+fn neighbors(
+    map: &Grid<Option<usize>>,
+    pos: IVec2,
+    cutoff: usize,
+) -> impl Iterator<Item = IVec2> + '_ {
+    [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
+        .map(|d| pos + d)
+        .into_iter()
+        .filter(move |p| is_passable_on(map, *p, cutoff))
 }
 
-impl std::cmp::PartialOrd for Entry {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
+fn astar(map: &Grid<Option<usize>>, cutoff: usize) -> usize {
+    let goal = map.size() - IVec2::ONE;
+    search::astar(
+        [IVec2::ZERO],
+        |&pos| neighbors(map, pos, cutoff).map(|p| (p, 1)),
+        |&pos| {
+            (goal - pos)
+                .abs()
+                .element_sum()
+                .try_conv::<usize>()
+                .expect("the conversion to succeed")
+        },
+        |&pos| pos == goal,
+    )
+    .distance(&goal)
+    .unwrap_or(usize::MAX)
 }
@@ -1,4 +1,7 @@
-use crate::{astar, Puzzle};
+use common::{par, search};
+use glam::IVec2;
+
+use crate::{astar, neighbors, Puzzle};
 
 #[must_use]
 #[allow(clippy::needless_pass_by_value)]
@@ -9,25 +12,63 @@ pub fn process(puzzle: Puzzle) -> usize {
     )
 }
 
+#[must_use]
+#[allow(clippy::needless_pass_by_value)]
+pub fn process_bfs(puzzle: Puzzle) -> usize {
+    let (map, cutoff) = (
+        puzzle.map(),
+        if puzzle.bytes.len() < 1024 { 12 } else { 1024 },
+    );
+    search::bfs([IVec2::ZERO], |&pos| neighbors(&map, pos, cutoff))
+        .distance(&(map.size() - IVec2::ONE))
+        .unwrap_or(usize::MAX)
+}
+
+#[must_use]
+#[allow(clippy::needless_pass_by_value)]
+pub fn process_par_bfs(puzzle: Puzzle) -> usize {
+    let (map, cutoff) = (
+        puzzle.map(),
+        if puzzle.bytes.len() < 1024 { 12 } else { 1024 },
+    );
+    par::bfs([IVec2::ZERO], |&pos| neighbors(&map, pos, cutoff))
+        .distance(&(map.size() - IVec2::ONE))
+        .unwrap_or(usize::MAX)
+}
+
+common::solution!(Part1: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
+    use tap::prelude::*;
 
     use super::*;
 
     #[test]
     fn test_example() -> Result<()> {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
-        let output = process(input);
-        assert_eq!(output, 22);
+        let output = input.clone().pipe(process);
+        let bfs = input.clone().pipe(process_bfs);
+        let par_bfs = input.pipe(process_par_bfs);
+        let expected = common::expected!(Part::One, "example.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(bfs.to_string(), expected);
+        assert_eq!(par_bfs.to_string(), expected);
         Ok(())
     }
 
     #[test]
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part1.txt").parse()?;
-        let output = process(input);
-        assert_eq!(output, 408);
+        let output = input.clone().pipe(process);
+        let bfs = input.clone().pipe(process_bfs);
+        let par_bfs = input.pipe(process_par_bfs);
+        let expected = common::expected!(Part::One, "part1.txt");
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(bfs.to_string(), expected);
+        assert_eq!(par_bfs.to_string(), expected);
         Ok(())
     }
 }
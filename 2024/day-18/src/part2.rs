@@ -1,4 +1,4 @@
-use common::grid::Grid;
+use common::{dsu::GridDsu, grid::Grid};
 use glam::IVec2;
 
 use crate::{astar, is_passable_on, reachable, Puzzle};
@@ -31,7 +31,7 @@ pub fn process_astar_binary(puzzle: Puzzle) -> IVec2 {
     while lo < hi - 1 {
         debug_assert!(n < 20);
         n += 1;
-        let mid = (hi + lo) / 2;
+        let mid = usize::midpoint(hi, lo);
         debug_assert_ne!(astar(&map, lo), usize::MAX, "lo unreachable");
         // debug_assert_eq!(astar(&map, hi), usize::MAX, "hi reachable");
         let reachable = reachable(&map, mid);
@@ -66,28 +66,7 @@ pub fn process_astar_rev(puzzle: Puzzle) -> IVec2 {
 #[must_use]
 #[allow(clippy::needless_pass_by_value)]
 pub fn process_uf(mut puzzle: Puzzle) -> IVec2 {
-    fn find(parents: &Grid<IVec2>, mut pos: IVec2) -> Option<IVec2> {
-        loop {
-            let parent = *parents.get(pos)?;
-            if parent == pos {
-                break Some(pos);
-            }
-            pos = parent;
-        }
-    }
-
-    fn unite(parents: &mut Grid<IVec2>, a: IVec2, b: IVec2) {
-        let a = find(parents, a).expect("didn't find a");
-        let b = find(parents, b).expect("to find b");
-        *parents.get_mut(a).expect("to find a in grid") = b;
-    }
-
-    fn unite_neighbors(
-        parents: &mut Grid<IVec2>,
-        pos: IVec2,
-        map: &Grid<Option<usize>>,
-        cutoff: usize,
-    ) {
+    fn unite_neighbors(dsu: &mut GridDsu, pos: IVec2, map: &Grid<Option<usize>>, cutoff: usize) {
         for other in [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
             .into_iter()
             .map(|d| d + pos)
@@ -96,21 +75,21 @@ pub fn process_uf(mut puzzle: Puzzle) -> IVec2 {
                 continue;
             }
             if is_passable_on(map, other, cutoff) && is_passable_on(map, pos, cutoff) {
-                unite(parents, pos, other);
+                dsu.unite(pos, other);
             }
         }
     }
 
-    fn is_connected(parents: &Grid<IVec2>) -> bool {
-        find(parents, IVec2::ZERO) == find(parents, parents.size() - IVec2::ONE)
+    fn is_connected(dsu: &GridDsu) -> bool {
+        dsu.root(IVec2::ZERO) == dsu.root(dsu.size() - IVec2::ONE)
     }
 
     let map = puzzle.map();
-    let mut parents = Grid::from_positions(std::convert::identity, map.size());
-    for pos in parents.positions() {
-        unite_neighbors(&mut parents, pos, &map, puzzle.bytes.len());
+    let mut dsu = GridDsu::new(map.size());
+    for pos in map.positions() {
+        unite_neighbors(&mut dsu, pos, &map, puzzle.bytes.len());
     }
-    debug_assert!(!is_connected(&parents));
+    debug_assert!(!is_connected(&dsu));
 
     loop {
         let pos = puzzle
@@ -118,18 +97,21 @@ pub fn process_uf(mut puzzle: Puzzle) -> IVec2 {
             .pop()
             .expect("The graph to become connected before the last byte is removed");
 
-        unite_neighbors(&mut parents, pos, &map, puzzle.bytes.len());
+        unite_neighbors(&mut dsu, pos, &map, puzzle.bytes.len());
 
-        if is_connected(&parents) {
+        if is_connected(&dsu) {
             break pos;
         }
     }
 }
 
+common::solution!(Part2: Puzzle => glam::IVec2, process_astar_rev);
+
 #[allow(clippy::redundant_clone)]
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use tap::prelude::*;
 
     use super::*;
@@ -140,11 +122,12 @@ mod tests {
         let astar = input.clone().pipe(process_astar);
         let rev = input.clone().pipe(process_astar_rev);
         let binary = input.clone().pipe(process_astar_binary);
-        let uf = input.clone().pipe(process_uf);
-        assert_eq!(astar, IVec2::new(6, 1));
-        assert_eq!(rev, IVec2::new(6, 1));
-        assert_eq!(binary, IVec2::new(6, 1));
-        assert_eq!(uf, IVec2::new(6, 1));
+        let uf = input.pipe(process_uf);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(astar.to_string(), expected);
+        assert_eq!(rev.to_string(), expected);
+        assert_eq!(binary.to_string(), expected);
+        assert_eq!(uf.to_string(), expected);
         Ok(())
     }
 
@@ -153,10 +136,11 @@ mod tests {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
         let astar = input.clone().pipe(process_astar);
         let rev = input.clone().pipe(process_astar_rev);
-        let binary = input.clone().pipe(process_astar_binary);
-        assert_eq!(astar, IVec2::new(45, 16));
-        assert_eq!(rev, IVec2::new(45, 16));
-        assert_eq!(binary, IVec2::new(45, 16));
+        let binary = input.pipe(process_astar_binary);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(astar.to_string(), expected);
+        assert_eq!(rev.to_string(), expected);
+        assert_eq!(binary.to_string(), expected);
         Ok(())
     }
 }
@@ -156,7 +156,7 @@ impl NodeSmall {
         self.next[3] as u64
     }
 
-    fn set_towel(&mut self) {
+    const fn set_towel(&mut self) {
         self.next[3] = 1;
     }
 }
@@ -211,6 +211,7 @@ impl<'a> FromIterator<&'a str> for TrieSmall {
 
 #[cfg(test)]
 mod tests {
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
@@ -240,11 +241,12 @@ mod tests {
         let no_parse = process_no_parse(&input)?;
         let partial_inline = process_partial_inline(&input)?;
         let fully_inline = process_fully_inline(&input)?;
-        assert_eq!(big, 16);
-        assert_eq!(small, 16);
-        assert_eq!(no_parse, 16);
-        assert_eq!(partial_inline, 16);
-        assert_eq!(fully_inline, 16);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(big.to_string(), expected);
+        assert_eq!(small.to_string(), expected);
+        assert_eq!(no_parse.to_string(), expected);
+        assert_eq!(partial_inline.to_string(), expected);
+        assert_eq!(fully_inline.to_string(), expected);
         Ok(())
     }
 
@@ -257,11 +259,12 @@ mod tests {
         let no_parse = process_no_parse(&input)?;
         let partial_inline = process_partial_inline(&input)?;
         let fully_inline = process_fully_inline(&input)?;
-        assert_eq!(big, 571_894_474_468_161);
-        assert_eq!(small, 571_894_474_468_161);
-        assert_eq!(no_parse, 571_894_474_468_161);
-        assert_eq!(partial_inline, 571_894_474_468_161);
-        assert_eq!(fully_inline, 571_894_474_468_161);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(big.to_string(), expected);
+        assert_eq!(small.to_string(), expected);
+        assert_eq!(no_parse.to_string(), expected);
+        assert_eq!(partial_inline.to_string(), expected);
+        assert_eq!(fully_inline.to_string(), expected);
         Ok(())
     }
 }
@@ -30,7 +30,7 @@ pub fn process_recursive(puzzle: &Puzzle) -> u64 {
 }
 
 #[must_use]
-#[allow(clippy::needless_pass_by_value, clippy::match_on_vec_items)]
+#[allow(clippy::needless_pass_by_value)]
 pub fn process_loop(puzzle: &Puzzle) -> u64 {
     let trie: Trie = puzzle.towels.iter().map(String::deref).collect();
 
@@ -38,7 +38,7 @@ pub fn process_loop(puzzle: &Puzzle) -> u64 {
 }
 
 #[must_use]
-#[allow(clippy::needless_pass_by_value, clippy::match_on_vec_items)]
+#[allow(clippy::needless_pass_by_value)]
 pub fn process_loop_on_stack(puzzle: &Puzzle) -> u64 {
     let trie: Trie = puzzle.towels.iter().map(String::deref).collect();
 
@@ -157,7 +157,7 @@ impl Trie {
 }
 
 #[must_use]
-#[allow(clippy::needless_pass_by_value, clippy::match_on_vec_items)]
+#[allow(clippy::needless_pass_by_value)]
 pub fn process(puzzle: Puzzle) -> u64 {
     let trie: Trie = puzzle.towels.iter().map(String::deref).collect();
 
@@ -198,6 +198,7 @@ fn ways_recursive(goal: &str, trie: &Trie, idx: usize, cache: &mut [Option<u64>]
 #[cfg(test)]
 mod tests {
     use color_eyre::Result;
+    use common::runner::Part;
     use itertools::Itertools as _;
     use rstest::rstest;
 
@@ -243,9 +244,10 @@ mod tests {
         let recurisve = process_recursive(&input);
         let looping = process_loop(&input);
         let loop_on_stack = process_loop_on_stack(&input);
-        assert_eq!(recurisve, 16);
-        assert_eq!(looping, 16);
-        assert_eq!(loop_on_stack, 16);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(recurisve.to_string(), expected);
+        assert_eq!(looping.to_string(), expected);
+        assert_eq!(loop_on_stack.to_string(), expected);
         Ok(())
     }
 
@@ -255,9 +257,10 @@ mod tests {
         let recursive = process_recursive(&input);
         let looping = process_loop(&input);
         let loop_on_stack = process_loop_on_stack(&input);
-        assert_eq!(recursive, 571_894_474_468_161);
-        assert_eq!(looping, 571_894_474_468_161);
-        assert_eq!(loop_on_stack, 571_894_474_468_161);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(recursive.to_string(), expected);
+        assert_eq!(looping.to_string(), expected);
+        assert_eq!(loop_on_stack.to_string(), expected);
         Ok(())
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use color_eyre::eyre::OptionExt;
+use common::parse;
 use itertools::Itertools;
 use tap::Pipe;
 
@@ -38,21 +38,45 @@ impl std::str::FromStr for Puzzle {
     type Err = color_eyre::Report;
 
     fn from_str(s: &str) -> color_eyre::Result<Self> {
-        let (patterns, designs) = s
-            .split_once("\n\n")
-            .ok_or_eyre("Couldn't seperate blocks")?;
+        let (patterns, designs) = parse::two_blocks(s)?;
         Self {
             towels: patterns
-                .trim()
-                .split(", ")
-                .map(ToOwned::to_owned)
+                .split(",")
+                .map(|towel| towel.as_str().to_owned())
+                .collect_vec(),
+            goals: designs
+                .lines()
+                .map(|goal| goal.as_str().to_owned())
                 .collect_vec(),
-            goals: designs.trim().lines().map(ToOwned::to_owned).collect_vec(),
         }
         .pipe(Ok)
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    const VARIANTS: &'static [common::bench::Variant] = common::variants![
+        Two "vec_cache": str::parse::<Puzzle> => part2::process_vec_cache;
+        Two "hash_trie_recursive": str::parse::<Puzzle>
+            => |p: Puzzle| hash_trie::process_recursive(&p);
+        Two "hash_trie_loop": str::parse::<Puzzle> => |p: Puzzle| hash_trie::process_loop(&p);
+        Two "hash_trie_loop_on_stack": str::parse::<Puzzle>
+            => |p: Puzzle| hash_trie::process_loop_on_stack(&p);
+        Two "array_trie_big": str::parse::<Puzzle> => |p: Puzzle| array_trie::process_big(&p);
+        Two "array_trie_small": str::parse::<Puzzle> => |p: Puzzle| array_trie::process_small(&p);
+    ];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
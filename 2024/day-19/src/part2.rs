@@ -21,11 +21,7 @@ https://www.reddit.com/r/adventofcode/comments/1hhlb8g/2024_day_19_solutions/m2s
 */
 
 #[must_use]
-#[allow(
-    clippy::needless_pass_by_value,
-    clippy::option_if_let_else,
-    clippy::match_on_vec_items
-)]
+#[allow(clippy::needless_pass_by_value, clippy::option_if_let_else)]
 pub fn process_vec_cache(puzzle: Puzzle) -> u64 {
     fn ways(goal: &str, towels: &[String], idx: usize, cache: &mut Vec<Option<u64>>) -> u64 {
         match cache[idx] {
@@ -66,32 +62,37 @@ pub fn process_vec_cache(puzzle: Puzzle) -> u64 {
         .sum()
 }
 
+common::solution!(Part2: Puzzle => u64, process);
+
 #[allow(clippy::redundant_clone)]
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
 
     use super::*;
 
     #[test]
-    #[ignore]
+    #[ignore = "the naive solution is slow"]
     fn test_example() -> Result<()> {
         let input: Puzzle = common::read_input!("example.txt").parse()?;
         let naive = input.clone().pipe(process);
-        let vec_cache = input.clone().pipe(process_vec_cache);
-        assert_eq!(naive, 16);
-        assert_eq!(vec_cache, 16);
+        let vec_cache = input.pipe(process_vec_cache);
+        let expected = common::expected!(Part::Two, "example.txt");
+        assert_eq!(naive.to_string(), expected);
+        assert_eq!(vec_cache.to_string(), expected);
         Ok(())
     }
 
     #[test]
-    #[ignore]
+    #[ignore = "the naive solution is slow"]
     fn test_actual() -> Result<()> {
         let input: Puzzle = common::read_input!("part2.txt").parse()?;
         let naive = input.clone().pipe(process);
-        let vec_cache = input.clone().pipe(process_vec_cache);
-        assert_eq!(naive, 571_894_474_468_161);
-        assert_eq!(vec_cache, 571_894_474_468_161);
+        let vec_cache = input.pipe(process_vec_cache);
+        let expected = common::expected!(Part::Two, "part2.txt");
+        assert_eq!(naive.to_string(), expected);
+        assert_eq!(vec_cache.to_string(), expected);
         Ok(())
     }
 }
@@ -1,11 +1,12 @@
-use std::collections::BinaryHeap;
-
 use color_eyre::eyre::OptionExt;
 use glam::IVec2;
 use itertools::Itertools;
 use tap::prelude::*;
 
-use common::grid::Grid;
+use common::{
+    grid::Grid,
+    search::{self, Search},
+};
 
 pub mod part1;
 pub mod part2;
@@ -19,47 +20,13 @@ pub struct Puzzle {
 
 impl Puzzle {
     #[must_use]
-    pub fn astar(&self) -> Costs {
-        let mut costs = Costs::new(self.map.size());
-        assert!(costs.replace_if_lt(self.start, 0));
-        let mut stack = BinaryHeap::<Entry>::new();
-        stack.push(Entry {
-            position: self.start,
-            cost: 0,
-            estimated_cost: 0,
-        });
-        while let Some(Entry {
-            position: pos,
-            cost,
-            ..
-        }) = stack.pop()
-        {
-            for new_p in [
-                pos + IVec2::X,
-                pos + IVec2::Y,
-                pos - IVec2::X,
-                pos - IVec2::Y,
-            ]
-            .into_iter()
-            .filter(|p| self.map[*p] != b'#')
-            {
-                let heuristic = {
-                    let delta = self.end - new_p;
-                    delta.x.abs() + delta.y.abs()
-                }
-                .try_conv::<u32>()
-                .expect("the conversion to succeed");
-
-                if costs.replace_if_lt(new_p, cost + 1) {
-                    stack.push(Entry {
-                        position: new_p,
-                        cost: cost + 1,
-                        estimated_cost: cost + heuristic + 1,
-                    });
-                }
-            }
-        }
-        costs
+    pub fn astar(&self) -> Search<IVec2, u32> {
+        search::astar(
+            [self.start],
+            |&pos| self.open(pos).map(|p| (p, 1)),
+            |&pos| (self.end - pos).abs().element_sum().unsigned_abs(),
+            |&pos| pos == self.end,
+        )
     }
 
     fn follow_route(&self) -> (Costs, Vec<IVec2>) {
@@ -89,6 +56,13 @@ impl Puzzle {
         }
         (costs, route)
     }
+
+    fn open(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
+            .into_iter()
+            .map(move |d| pos + d)
+            .filter(|p| self.map.get(*p).is_some_and(|t| *t != b'#'))
+    }
 }
 
 pub struct Costs(Grid<u32>);
@@ -114,41 +88,6 @@ impl Costs {
     }
 }
 
-pub struct Entry {
-    position: IVec2,
-    cost: u32,
-    estimated_cost: u32,
-}
-
-impl std::cmp::PartialEq for Entry {
-    fn eq(&self, other: &Self) -> bool {
-        self.estimated_cost.eq(&other.estimated_cost) && self.cost.eq(&other.cost)
-    }
-}
-
-impl std::cmp::Eq for Entry {}
-
-impl std::cmp::Ord for Entry {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        use std::cmp::Ordering;
-        let o = match self.estimated_cost.cmp(&other.estimated_cost) {
-            Ordering::Equal => return self.cost.cmp(&other.cost),
-            o => o,
-        };
-        match o {
-            Ordering::Less => Ordering::Greater,
-            Ordering::Equal => Ordering::Equal,
-            Ordering::Greater => Ordering::Less,
-        }
-    }
-}
-
-impl std::cmp::PartialOrd for Entry {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 impl std::str::FromStr for Puzzle {
     type Err = color_eyre::Report;
 
@@ -173,6 +112,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
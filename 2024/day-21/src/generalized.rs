@@ -58,7 +58,9 @@ pub fn process(puzzle: &Puzzle, layers: u8) -> usize {
             let mut new_route = HashMap::new();
             for (sub_route, n) in route_counter {
                 let mut new_counts = routes(sub_route, &dir_pad).pipe(counter);
-                new_counts.values_mut().for_each(|v| *v *= n);
+                for v in new_counts.values_mut() {
+                    *v *= n;
+                }
                 update_map(&mut new_route, new_counts);
             }
             new_routes.push(new_route);
@@ -148,22 +150,26 @@ fn step(source: char, target: char, pad: &HashMap<char, IVec2>) -> Option<String
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 2, 126_384)]
-    #[case::example("example.txt", 25, 154_115_708_116_294)]
-    #[case::actual("part1.txt", 2, 164_960)]
-    #[case::actual("part1.txt", 25, 205_620_604_017_764)]
+    #[case::example("example.txt", Part::One, 2)]
+    #[case::example("example.txt", Part::Two, 25)]
+    #[case::actual("part1.txt", Part::One, 2)]
+    #[case::actual("part1.txt", Part::Two, 25)]
     fn finds_solution(
         #[case] input_path: &str,
+        #[case] part: Part,
         #[case] layers: u8,
-        #[case] answer: usize,
     ) -> Result<()> {
         let input: Puzzle = common::read_input!(input_path).parse()?;
-        assert_eq!(process(&input, layers), answer);
+        assert_eq!(
+            process(&input, layers).to_string(),
+            common::expected!(part, input_path)
+        );
         Ok(())
     }
 }
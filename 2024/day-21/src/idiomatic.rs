@@ -184,22 +184,26 @@ fn step(
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 2, 126_384)]
-    #[case::example("example.txt", 25, 154_115_708_116_294)]
-    #[case::actual("part1.txt", 2, 164_960)]
-    #[case::actual("part1.txt", 25, 205_620_604_017_764)]
+    #[case::example("example.txt", Part::One, 2)]
+    #[case::example("example.txt", Part::Two, 25)]
+    #[case::actual("part1.txt", Part::One, 2)]
+    #[case::actual("part1.txt", Part::Two, 25)]
     fn finds_solution(
         #[case] input_path: &str,
+        #[case] part: Part,
         #[case] layers: u8,
-        #[case] answer: usize,
     ) -> Result<()> {
         let input: Puzzle = common::read_input!(input_path).parse()?;
-        assert_eq!(process(&input, layers), answer);
+        assert_eq!(
+            process(&input, layers).to_string(),
+            common::expected!(part, input_path)
+        );
         Ok(())
     }
 }
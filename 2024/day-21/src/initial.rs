@@ -146,7 +146,9 @@ pub fn part2(puzzle: Puzzle) -> usize {
             let mut new_route = HashMap::new();
             for (sub_route, n) in route_counter {
                 let mut new_counts = routes(sub_route, &dir_pad);
-                new_counts.values_mut().for_each(|v| *v *= n);
+                for v in new_counts.values_mut() {
+                    *v *= n;
+                }
                 update_map(&mut new_route, new_counts);
             }
             new_routes.push(new_route);
@@ -214,21 +216,24 @@ pub(crate) fn step(source: char, target: char, pad: &HashMap<char, IVec2>) -> Op
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 126_384, 154_115_708_116_294)]
-    #[case::actual("part1.txt", 164_960, 205_620_604_017_764)]
-    fn finds_solution(
-        #[case] input_path: &str,
-        #[case] part1_ans: usize,
-        #[case] part2_ans: usize,
-    ) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::actual("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
         let input: Puzzle = common::read_input!(input_path).parse()?;
-        assert_eq!(input.clone().pipe(part1), part1_ans);
-        assert_eq!(input.pipe(part2), part2_ans);
+        assert_eq!(
+            input.clone().pipe(part1).to_string(),
+            common::expected!(Part::One, input_path)
+        );
+        assert_eq!(
+            input.pipe(part2).to_string(),
+            common::expected!(Part::Two, input_path)
+        );
         Ok(())
     }
 }
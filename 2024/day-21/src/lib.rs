@@ -41,6 +41,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 21;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -60,3 +73,42 @@ pub fn init_tracing() -> color_eyre::Result<()> {
     tracing::subscriber::set_global_default(subscriber)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use color_eyre::eyre::Result;
+    use common::check::{self, Codes, KeypadCodes};
+
+    use super::*;
+
+    fn puzzle(codes: &Codes) -> Puzzle {
+        codes.to_string().parse().expect("codes to parse")
+    }
+
+    #[test]
+    fn variants_agree() -> Result<()> {
+        check::agree(
+            &KeypadCodes(1..=5),
+            &[
+                ("initial", |codes| {
+                    (initial::part1(puzzle(codes)), initial::part2(puzzle(codes)))
+                }),
+                ("generalized", |codes| {
+                    let puzzle = puzzle(codes);
+                    (
+                        generalized::process(&puzzle, 2),
+                        generalized::process(&puzzle, 25),
+                    )
+                }),
+                ("idiomatic", |codes| {
+                    let puzzle = puzzle(codes);
+                    (
+                        idiomatic::process(&puzzle, 2),
+                        idiomatic::process(&puzzle, 25),
+                    )
+                }),
+            ],
+        )?;
+        Ok(())
+    }
+}
@@ -14,3 +14,5 @@ pub(crate) fn routes<'a>(path: &'a str, pad: &'a HashMap<char, IVec2>) -> String
         })
         .collect()
 }
+
+common::solution!(Part1: crate::Puzzle => usize, process);
@@ -1 +1,3 @@
 pub use crate::initial::part2 as process;
+
+common::solution!(Part2: crate::Puzzle => usize, process);
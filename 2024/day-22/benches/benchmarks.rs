@@ -41,6 +41,21 @@ fn part2(bencher: divan::Bencher) {
         });
 }
 
+#[divan::bench]
+fn part2_sharded_counter(bencher: divan::Bencher) {
+    bencher
+        .with_inputs(|| {
+            common::read_input!("part2.txt")
+                .parse::<Puzzle>()
+                .map(divan::black_box)
+        })
+        .bench_values(|res| {
+            res.expect("parsing to suceed")
+                .pipe(divan::black_box)
+                .pipe_ref(part2::sharded_counter)
+        });
+}
+
 #[divan::bench]
 fn part2_one_pass(bencher: divan::Bencher) {
     bencher
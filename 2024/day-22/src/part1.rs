@@ -17,9 +17,12 @@ fn nth<const N: usize>(seed: u32) -> Option<u32> {
     Rng(seed).nth(N)
 }
 
+common::solution!(Part1: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
@@ -31,11 +34,12 @@ mod tests {
     }
 
     #[rstest]
-    #[case::actual("part1.txt", 13_185_239_446)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::actual("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 
+use common::par::ShardedCounter;
 use itertools::Itertools;
 use rayon::prelude::*;
 use tap::prelude::*;
@@ -24,7 +25,7 @@ pub fn initial(puzzle: &Puzzle) -> u64 {
             .take(610) // the sequences seem to repeat?
             .map(price)
             .tuple_windows()
-            .map(|(first, second)| (second - first))
+            .map(|(first, second)| second - first)
             .tuple_windows()
     }
 
@@ -53,6 +54,27 @@ pub fn initial(puzzle: &Puzzle) -> u64 {
         .unwrap_or(0)
 }
 
+/// `initial`, but counting the profit of every sequence with a shared counter instead of
+/// checking each sequence against every buyer
+#[must_use]
+pub fn sharded_counter(puzzle: &Puzzle) -> u64 {
+    let profits = ShardedCounter::new();
+    puzzle.numbers.par_iter().for_each(|seed| {
+        let mut seen = HashSet::new();
+        for (a, b, c, d, e) in Rng(*seed).take(2001).map(price).tuple_windows() {
+            let seq = (b - a, c - b, d - c, e - d);
+            if seen.insert(seq) {
+                profits.add_n(seq, usize::try_from(e).expect("prices to be positive"));
+            }
+        }
+    });
+    profits
+        .into_counter()
+        .counts()
+        .max()
+        .map_or(0, |profit| profit as u64)
+}
+
 #[must_use]
 pub fn one_pass(puzzle: &Puzzle) -> u64 {
     let mut cache: HashMap<(i8, i8, i8, i8), Entry> = HashMap::new();
@@ -379,29 +401,34 @@ fn price(seed: u32) -> i8 {
     i8::try_from(seed % 10).expect("10 < i32::MAX")
 }
 
+common::solution!(Part2: Puzzle => u64, |puzzle| Ok(initial(&puzzle)));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 23)]
-    #[case::actual("part2.txt", 1501)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::actual("part2.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
-        assert_eq!(initial(&input), expected);
-        assert_eq!(one_pass(&input), expected);
-        assert_eq!(u32_key(&input), expected);
-        assert_eq!(fxhash_cache(&input), expected);
-        assert_eq!(pre_alloc(&input), expected);
-        assert_eq!(btree(&input), expected);
-        assert_eq!(continuous_windows(&input), expected);
-        assert_eq!(vec_cache(&input), expected);
-        assert_eq!(mul_windows(&input), expected);
-        assert_eq!(small_cache(&input), expected);
-        assert_eq!(small_cache_rayon(&input), expected);
+        assert_eq!(initial(&input).to_string(), expected);
+        assert_eq!(sharded_counter(&input).to_string(), expected);
+        assert_eq!(one_pass(&input).to_string(), expected);
+        assert_eq!(u32_key(&input).to_string(), expected);
+        assert_eq!(fxhash_cache(&input).to_string(), expected);
+        assert_eq!(pre_alloc(&input).to_string(), expected);
+        assert_eq!(btree(&input).to_string(), expected);
+        assert_eq!(continuous_windows(&input).to_string(), expected);
+        assert_eq!(vec_cache(&input).to_string(), expected);
+        assert_eq!(mul_windows(&input).to_string(), expected);
+        assert_eq!(small_cache(&input).to_string(), expected);
+        assert_eq!(small_cache_rayon(&input).to_string(), expected);
         Ok(())
     }
 }
@@ -1,25 +1,24 @@
-use std::borrow::Cow;
+use common::bitset::BitSet;
 
-use tap::Pipe;
+/// Enough words to hold every two letter node id (26 * 26 = 676 bits)
+const WORDS: usize = 676_usize.div_ceil(64);
 
-#[derive(Debug)]
-pub struct NodeSet<'a>(Cow<'a, [bool]>);
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeSet(BitSet<WORDS>);
 
 #[derive(Debug)]
-pub struct EdgeMap(Vec<bool>);
+pub struct EdgeMap(Vec<NodeSet>);
 
-pub fn parse(input: &crate::int::Graph) -> (EdgeMap, NodeSet<'static>) {
-    let (mut nodes, mut edges) = (vec![false; 676], vec![false; 456_976]);
+#[must_use]
+pub fn parse(input: &crate::int::Graph) -> (EdgeMap, NodeSet) {
+    let (mut nodes, mut edges) = (NodeSet::default(), vec![NodeSet::default(); 676]);
     for edge in input.all_edges() {
         let (from, to) = ((edge.from as usize), (edge.to as usize));
-        edges[from * 676 + to] = true;
-        nodes[from] = true;
-        nodes[to] = true;
+        edges[from].0.insert(to);
+        nodes.0.insert(from);
+        nodes.0.insert(to);
     }
-    (
-        EdgeMap(edges),
-        Cow::<'static, [bool]>::Owned(nodes).pipe(NodeSet),
-    )
+    (EdgeMap(edges), nodes)
 }
 
 pub(crate) fn cliques(edges: &EdgeMap, nodes: &NodeSet) -> impl Iterator<Item = Vec<u16>> {
@@ -40,53 +39,44 @@ pub(crate) fn cliques(edges: &EdgeMap, nodes: &NodeSet) -> impl Iterator<Item =
 
 impl EdgeMap {
     #[inline]
-    pub(crate) fn get(&self, from: u16) -> NodeSet<'_> {
-        self.0[((from as usize) * 676)..((from as usize + 1) * 676)]
-            .pipe_ref(Cow::Borrowed)
-            .pipe(NodeSet)
+    pub(crate) fn get(&self, from: u16) -> &NodeSet {
+        &self.0[from as usize]
     }
 
     #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = (u16, NodeSet<'_>)> {
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &NodeSet)> {
         (0..676).map(|k| (k, self.get(k)))
     }
 
     #[inline]
-    pub fn prefixed_by(&self, c: u8) -> impl Iterator<Item = (u16, NodeSet<'_>)> {
+    pub fn prefixed_by(&self, c: u8) -> impl Iterator<Item = (u16, &NodeSet)> {
         let c = u16::from(c - b'a');
         ((c * 26)..((c + 1) * 26)).map(|k| (k, self.get(k)))
     }
 }
 
-impl NodeSet<'_> {
+impl NodeSet {
     #[must_use]
     #[inline]
     pub fn contains(&self, id: u16) -> bool {
-        self.0[id as usize]
+        self.0.contains(id.into())
     }
 
     #[allow(
         clippy::cast_possible_truncation,
-        reason = "check it once at the begining"
+        reason = "the capacity of the set fits in a u16"
     )]
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
-        debug_assert!(u16::try_from(self.0.len()).is_ok());
-        self.0
-            .iter()
-            .copied()
-            .enumerate()
-            .filter(|(_, b)| *b)
-            .map(|(idx, _)| idx as u16)
+        self.0.iter().map(|idx| idx as u16)
     }
 }
 
 impl FromIterator<crate::int::Edge> for EdgeMap {
     fn from_iter<T: IntoIterator<Item = crate::int::Edge>>(iter: T) -> Self {
-        let mut v = vec![false; 456_976];
+        let mut v = vec![NodeSet::default(); 676];
         for edge in iter {
-            let (from, to) = ((edge.from as usize), (edge.to as usize));
-            v[from * 676 + to] = true;
+            v[edge.from as usize].0.insert(edge.to as usize);
         }
 
         Self(v)
@@ -9,6 +9,41 @@ pub use int::Graph as IntGraph;
 pub(crate) use string::EdgeRef;
 pub use string::Graph as StringGraph;
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 23;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    const VARIANTS: &'static [common::bench::Variant] = common::variants![
+        One "initial": str::parse::<StringGraph> => |g: StringGraph| part1::initial(&g);
+        One "common_methods": str::parse::<StringGraph>
+            => |g: StringGraph| part1::common_methods(&g);
+        One "edge_set": str::parse::<StringGraph> => |g: StringGraph| part1::edge_set(&g);
+        One "pre_filter": str::parse::<StringGraph> => |g: StringGraph| part1::pre_filter(&g);
+        One "int_graph": str::parse::<IntGraph> => |g: IntGraph| part1::int_graph(&g);
+        One "array": str::parse::<IntGraph> => |g: IntGraph| part1::array(&g);
+        One "array_preparsed": |text: &str| text.parse::<IntGraph>().map(|g| array::parse(&g))
+            => |graph| part1::array_preparsed(&graph);
+        Two "initial": str::parse::<StringGraph> => |g: StringGraph| part2::initial(&g);
+        Two "common_methods": str::parse::<StringGraph>
+            => |g: StringGraph| part2::common_methods(&g);
+        Two "bron_kerbosh": str::parse::<StringGraph> => |g: StringGraph| part2::bron_kerbosh(&g);
+        Two "fx_hash": str::parse::<StringGraph> => |g: StringGraph| part2::fx_hash(&g);
+        Two "int_graph": str::parse::<IntGraph> => |g: IntGraph| part2::int_graph(&g);
+        Two "array": str::parse::<IntGraph> => |g: IntGraph| part2::array(&g);
+        Two "array_inline": str::parse::<IntGraph> => |g: IntGraph| part2::array_inline(&g);
+        Two "array_preparsed": |text: &str| text.parse::<IntGraph>().map(|g| array::parse(&g))
+            => |graph| part2::array_preparsed(&graph);
+    ];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -163,28 +163,34 @@ pub fn array_preparsed((edges, _): &(crate::array::EdgeMap, crate::array::NodeSe
         .count()
 }
 
+common::solution!(Part1: crate::StringGraph => usize, |puzzle| Ok(initial(&puzzle)));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
     use tap::Pipe;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 7)]
-    #[case::example("part1.txt", 1358)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: usize) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::example("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let string: StringGraph = common::read_input!(input_path).parse()?;
         let int: IntGraph = common::read_input!(input_path).parse()?;
-        assert_eq!(initial(&string), expected);
-        assert_eq!(common_methods(&string), expected);
-        assert_eq!(edge_set(&string), expected);
-        assert_eq!(pre_filter(&string), expected);
-        assert_eq!(int_graph(&int), expected);
-        assert_eq!(array(&int), expected);
+        assert_eq!(initial(&string).to_string(), expected);
+        assert_eq!(common_methods(&string).to_string(), expected);
+        assert_eq!(edge_set(&string).to_string(), expected);
+        assert_eq!(pre_filter(&string).to_string(), expected);
+        assert_eq!(int_graph(&int).to_string(), expected);
+        assert_eq!(array(&int).to_string(), expected);
         assert_eq!(
-            crate::array::parse(&int).pipe_ref(array_preparsed),
+            crate::array::parse(&int)
+                .pipe_ref(array_preparsed)
+                .to_string(),
             expected
         );
         Ok(())
@@ -208,18 +208,22 @@ pub fn array_preparsed((edges, nodes): &(crate::array::EdgeMap, crate::array::No
         .join(",")
 }
 
+common::solution!(Part2: crate::StringGraph => String, |puzzle| Ok(initial(&puzzle)));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
     use tap::prelude::*;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", "co,de,ka,ta")]
-    #[case::example("part2.txt", "cl,ei,fd,hc,ib,kq,kv,ky,rv,vf,wk,yx,zf")]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: &str) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::example("part2.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let string: StringGraph = common::read_input!(input_path).parse()?;
         let int: IntGraph = common::read_input!(input_path).parse()?;
         assert_eq!(initial(&string), expected);
@@ -0,0 +1,26 @@
+use std::path::PathBuf;
+
+use color_eyre::Result;
+
+use day_24::{init_tracing, Puzzle};
+
+/// Prints the circuit as a Graphviz graph, or as Verilog if given `--verilog`, optionally
+/// followed by the path to the input
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    init_tracing()?;
+    let (flags, path): (Vec<_>, Vec<_>) = std::env::args()
+        .skip(1)
+        .partition(|arg| arg.starts_with("--"));
+    let puzzle: Puzzle = common::input::Resolver::new(env!("CARGO_MANIFEST_DIR"))
+        .with_cli(path.first().map(PathBuf::from))
+        .read("part1.txt")?
+        .parse()?;
+    let netlist = puzzle.netlist()?;
+    if flags.iter().any(|flag| flag == "--verilog") {
+        print!("{}", netlist.verilog("adder"));
+    } else {
+        print!("{}", netlist.dot());
+    }
+    Ok(())
+}
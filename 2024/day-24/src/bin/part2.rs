@@ -1,10 +1,13 @@
-use day_24::{init_tracing, part2::process};
+use day_24::{init_tracing, part2::repair};
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     init_tracing()?;
-    let puzzle = common::read_input!("part1.txt").parse()?;
-    let output = process(&puzzle).expect("to find a solution");
-    println!("{output}");
+    let puzzle = common::input::Resolver::from_args(env!("CARGO_MANIFEST_DIR"))
+        .read("part1.txt")?
+        .parse()?;
+    let report = repair(&puzzle)?;
+    eprintln!("{report}");
+    println!("{}", report.answer());
     Ok(())
 }
@@ -5,6 +5,9 @@ use fxhash::FxHashMap;
 use itertools::Itertools;
 use tap::prelude::*;
 
+pub use netlist::Netlist;
+
+pub mod netlist;
 pub mod part1;
 pub mod part2;
 
@@ -38,7 +41,7 @@ pub(crate) struct Gate {
 }
 
 impl Gate {
-    fn reveresed(mut self) -> Self {
+    const fn reveresed(mut self) -> Self {
         std::mem::swap(&mut self.left, &mut self.right);
         self
     }
@@ -160,6 +163,19 @@ impl std::str::FromStr for Operation {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 24;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
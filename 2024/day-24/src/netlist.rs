@@ -0,0 +1,357 @@
+use std::fmt::Write;
+
+use color_eyre::eyre::{bail, eyre};
+use fxhash::FxHashMap;
+use itertools::Itertools;
+
+use crate::{Operation, Puzzle, Wire};
+
+/// A gate, with its wires as indices into the netlist's wires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    out: usize,
+    op: Operation,
+    left: usize,
+    right: usize,
+}
+
+/// The circuit flattened into a list of gates, each after every gate it depends on, so that it
+/// can be simulated in one pass and exported to other tools
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    /// Every wire's name, sorted
+    wires: Vec<String>,
+    /// Each `x`, `y` and `z` bit's wire, if it has one
+    x: Vec<Option<usize>>,
+    y: Vec<Option<usize>>,
+    z: Vec<Option<usize>>,
+    /// Every gate, in topological order
+    cells: Vec<Cell>,
+}
+
+impl Netlist {
+    /// Sorts the puzzle's gates topologically, failing if any of them form a loop or read a wire
+    /// which is neither an input nor driven by a gate
+    pub fn new(puzzle: &Puzzle) -> color_eyre::Result<Self> {
+        let wires = puzzle
+            .operations
+            .iter()
+            .flat_map(|(&out, gate)| [out, gate.left, gate.right])
+            .chain(puzzle.state.keys().copied())
+            .unique()
+            .map(|wire| (puzzle.wire_str(wire).into_owned(), wire))
+            .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
+            .collect::<Vec<_>>();
+        let index = wires
+            .iter()
+            .enumerate()
+            .map(|(i, &(_, wire))| (wire, i))
+            .collect::<FxHashMap<_, _>>();
+        let bits = |input: fn(Wire) -> Option<u8>| {
+            let mut bits = Vec::new();
+            for (i, &(_, wire)) in wires.iter().enumerate() {
+                if let Some(bit) = input(wire).map(usize::from) {
+                    if bits.len() <= bit {
+                        bits.resize(bit + 1, None);
+                    }
+                    bits[bit] = Some(i);
+                }
+            }
+            bits
+        };
+        let (x, y, z) = (
+            bits(|wire| if let Wire::X(n) = wire { Some(n) } else { None }),
+            bits(|wire| if let Wire::Y(n) = wire { Some(n) } else { None }),
+            bits(|wire| if let Wire::Z(n) = wire { Some(n) } else { None }),
+        );
+
+        let drivers = puzzle
+            .operations
+            .iter()
+            .map(|(out, gate)| {
+                let cell = Cell {
+                    out: index[out],
+                    op: gate.op,
+                    left: index[&gate.left],
+                    right: index[&gate.right],
+                };
+                (cell.out, cell)
+            })
+            .collect::<FxHashMap<_, _>>();
+        let names = wires.into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+
+        let mut sorter = Sorter {
+            names: &names,
+            drivers: &drivers,
+            state: vec![Visit::New; names.len()],
+            cells: Vec::with_capacity(drivers.len()),
+        };
+        for wire in drivers.keys().copied().sorted_unstable() {
+            sorter.visit(wire)?;
+        }
+        for (i, name) in names.iter().enumerate() {
+            let input = x.contains(&Some(i)) || y.contains(&Some(i));
+            if !input && !drivers.contains_key(&i) {
+                bail!("{name} isn't an input, and no gate drives it");
+            }
+        }
+        Ok(Self {
+            cells: sorter.cells,
+            wires: names,
+            x,
+            y,
+            z,
+        })
+    }
+
+    /// The widths of `x`, `y` and `z`
+    #[must_use]
+    pub const fn widths(&self) -> (usize, usize, usize) {
+        (self.x.len(), self.y.len(), self.z.len())
+    }
+
+    /// Runs the circuit with `x` and `y` given least significant bit first, returning `z` the
+    /// same way. Bits missing from either input, or beyond their width, are ignored.
+    #[must_use]
+    pub fn simulate(&self, x: &[bool], y: &[bool]) -> Vec<bool> {
+        let mut values = vec![false; self.wires.len()];
+        for (bits, wires) in [(x, &self.x), (y, &self.y)] {
+            for (&bit, wire) in bits.iter().zip(wires) {
+                if let Some(wire) = *wire {
+                    values[wire] = bit;
+                }
+            }
+        }
+        for cell in &self.cells {
+            let (left, right) = (values[cell.left], values[cell.right]);
+            values[cell.out] = match cell.op {
+                Operation::Xor => left != right,
+                Operation::Or => left || right,
+                Operation::And => left && right,
+            };
+        }
+        self.z
+            .iter()
+            .map(|wire| wire.is_some_and(|wire| values[wire]))
+            .collect()
+    }
+
+    /// Runs the circuit with `x` and `y` as numbers, keeping the lowest 64 bits of `z`
+    #[must_use]
+    pub fn simulate_u64(&self, x: u64, y: u64) -> u64 {
+        let bits = |n: u64| (0..64).map(|bit| (n >> bit) & 1 == 1).collect::<Vec<_>>();
+        self.simulate(&bits(x), &bits(y))
+            .into_iter()
+            .take(64)
+            .enumerate()
+            .fold(0, |z, (bit, set)| z | (u64::from(set) << bit))
+    }
+
+    /// The circuit as a Graphviz graph, with inputs on the left, outputs on the right, and each
+    /// gate labelled with its output and coloured by its operation
+    #[must_use]
+    pub fn dot(&self) -> String {
+        let mut dot = String::from("digraph circuit {\n    rankdir=LR;\n");
+        dot.push_str("    node [style=filled, fontname=monospace];\n");
+        for (name, inputs) in [("x", &self.x), ("y", &self.y)] {
+            let _ = writeln!(dot, "    subgraph cluster_{name} {{");
+            let _ = writeln!(dot, "        label=\"{name}\"; rank=source;");
+            for &wire in inputs.iter().flatten() {
+                let _ = writeln!(
+                    dot,
+                    "        {} [shape=circle, fillcolor=white];",
+                    self.wires[wire]
+                );
+            }
+            dot.push_str("    }\n");
+        }
+        for cell in &self.cells {
+            let name = &self.wires[cell.out];
+            let (op, colour) = match cell.op {
+                Operation::And => ("AND", "lightgoldenrod"),
+                Operation::Or => ("OR", "palegreen"),
+                Operation::Xor => ("XOR", "lightskyblue"),
+            };
+            let shape = if self.z.contains(&Some(cell.out)) {
+                ", peripheries=2"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                dot,
+                "    {name} [label=\"{name}\\n{op}\", shape=box, fillcolor={colour}{shape}];"
+            );
+            for input in [cell.left, cell.right] {
+                let _ = writeln!(dot, "    {} -> {name};", self.wires[input]);
+            }
+        }
+        let outputs = self.z.iter().flatten().map(|&wire| &self.wires[wire]);
+        let _ = writeln!(dot, "    {{ rank=sink; {} }}", outputs.format("; "));
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The circuit as a structural Verilog module, with `x`, `y` and `z` as buses
+    #[must_use]
+    pub fn verilog(&self, module: &str) -> String {
+        let mut verilog = format!("module {module} (\n");
+        let ports = [
+            ("input", "x", &self.x),
+            ("input", "y", &self.y),
+            ("output", "z", &self.z),
+        ]
+        .into_iter()
+        .filter(|(_, _, bits)| !bits.is_empty())
+        .map(|(direction, name, bits)| {
+            format!("    {direction} wire [{}:0] {name}", bits.len() - 1)
+        })
+        .join(",\n");
+        let _ = writeln!(verilog, "{ports}\n);");
+        for cell in &self.cells {
+            if !self.z.contains(&Some(cell.out)) {
+                let _ = writeln!(verilog, "    wire {};", self.net(cell.out));
+            }
+        }
+        for cell in &self.cells {
+            let op = match cell.op {
+                Operation::And => "and",
+                Operation::Or => "or",
+                Operation::Xor => "xor",
+            };
+            let _ = writeln!(
+                verilog,
+                "    {op} g_{} ({}, {}, {});",
+                self.wires[cell.out],
+                self.net(cell.out),
+                self.net(cell.left),
+                self.net(cell.right)
+            );
+        }
+        verilog.push_str("endmodule\n");
+        verilog
+    }
+
+    /// A wire's name in Verilog: a bit of a bus, or prefixed so that it can't be a keyword
+    fn net(&self, wire: usize) -> String {
+        [("x", &self.x), ("y", &self.y), ("z", &self.z)]
+            .into_iter()
+            .find_map(|(bus, bits)| {
+                let bit = bits.iter().position(|&bit| bit == Some(wire))?;
+                Some(format!("{bus}[{bit}]"))
+            })
+            .unwrap_or_else(|| format!("w_{}", self.wires[wire]))
+    }
+}
+
+impl Puzzle {
+    /// The circuit as a netlist, to simulate or export
+    pub fn netlist(&self) -> color_eyre::Result<Netlist> {
+        Netlist::new(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    /// Still finding what it depends on, so reaching it again means there's a loop
+    Open,
+    Done,
+}
+
+/// A depth first topological sort of the gates
+struct Sorter<'n> {
+    names: &'n [String],
+    drivers: &'n FxHashMap<usize, Cell>,
+    state: Vec<Visit>,
+    cells: Vec<Cell>,
+}
+
+impl Sorter<'_> {
+    fn visit(&mut self, wire: usize) -> color_eyre::Result<()> {
+        match self.state[wire] {
+            Visit::Done => return Ok(()),
+            Visit::Open => bail!("{} is part of a loop", self.names[wire]),
+            Visit::New => {}
+        }
+        self.state[wire] = Visit::Open;
+        if let Some(&cell) = self.drivers.get(&wire) {
+            for input in [cell.left, cell.right] {
+                self.visit(input)
+                    .map_err(|err| eyre!("{err}, through {}", self.names[wire]))?;
+            }
+            self.cells.push(cell);
+        }
+        self.state[wire] = Visit::Done;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A correct ripple carry adder of two 2 bit numbers
+    const ADDER: &str = "x00: 1\nx01: 1\ny00: 1\ny01: 0\n\n\
+        x00 XOR y00 -> z00\nx00 AND y00 -> c00\n\
+        x01 XOR y01 -> s01\nx01 AND y01 -> g01\n\
+        s01 XOR c00 -> z01\ns01 AND c00 -> p01\n\
+        g01 OR p01 -> z02\n";
+
+    #[test]
+    fn test_simulate() -> color_eyre::Result<()> {
+        let puzzle: Puzzle = ADDER.parse()?;
+        let netlist = puzzle.netlist()?;
+        assert_eq!(netlist.widths(), (2, 2, 3));
+        for (x, y) in (0..4).flat_map(|x| (0..4).map(move |y| (x, y))) {
+            assert_eq!(netlist.simulate_u64(x, y), x + y, "{x} + {y}");
+        }
+        assert_eq!(netlist.simulate(&[true], &[true]), [false, true, false]);
+
+        let example: Puzzle = "x00: 1\nx01: 1\nx02: 1\ny00: 0\ny01: 1\ny02: 0\n\n\
+            x00 AND y00 -> z00\nx01 XOR y01 -> z01\nx02 OR y02 -> z02\n"
+            .parse()?;
+        assert_eq!(
+            example.netlist()?.simulate_u64(0b111, 0b010),
+            crate::part1::process(example)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects() -> color_eyre::Result<()> {
+        let looped: Puzzle = "x00: 1\ny00: 0\n\nx00 AND abc -> def\ndef OR y00 -> abc\n\
+            abc XOR x00 -> z00\n"
+            .parse()?;
+        assert!(looped.netlist().is_err());
+        let undriven: Puzzle = "x00: 1\ny00: 0\n\nx00 AND abc -> z00\n".parse()?;
+        assert!(undriven.netlist().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_export() -> color_eyre::Result<()> {
+        let netlist = ADDER.parse::<Puzzle>()?.netlist()?;
+        let dot = netlist.dot();
+        assert!(dot.starts_with("digraph circuit {"));
+        assert!(dot.contains(
+            "    z01 [label=\"z01\\nXOR\", shape=box, fillcolor=lightskyblue, peripheries=2];"
+        ));
+        assert!(dot.contains("    s01 -> z01;"));
+
+        let verilog = netlist.verilog("adder");
+        assert!(verilog.starts_with(
+            "module adder (\n    input wire [1:0] x,\n    input wire [1:0] y,\n    \
+            output wire [2:0] z\n);\n"
+        ));
+        assert!(verilog.contains("    wire w_s01;\n"));
+        assert!(verilog.contains("    xor g_z01 (z[1], w_s01, w_c00);\n"));
+        assert!(verilog.contains("    or g_z02 (z[2], w_g01, w_p01);\n"));
+        assert!(verilog.ends_with("endmodule\n"));
+        // Each gate comes after the gates driving it
+        assert!(verilog
+            .find("g_s01")
+            .zip(verilog.find("g_z01"))
+            .is_some_and(|(s, z)| s < z));
+        Ok(())
+    }
+}
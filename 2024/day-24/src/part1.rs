@@ -14,21 +14,25 @@ pub fn process(mut puzzle: Puzzle) -> u64 {
         })
 }
 
+common::solution!(Part1: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[ignore]
-    #[case::example("example.txt", 2024)]
-    #[case::example("part1.txt", 55_920_211_035_878)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[ignore = "needs the puzzle inputs"]
+    #[case::example("example.txt")]
+    #[case::example("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
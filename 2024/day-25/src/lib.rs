@@ -1,3 +1,4 @@
+use common::bitset::BitSet;
 use itertools::Itertools;
 use tap::prelude::*;
 
@@ -5,7 +6,7 @@ pub mod part1;
 
 #[derive(Debug)]
 pub struct Puzzle {
-    items: Vec<u64>,
+    items: Vec<BitSet<1>>,
 }
 
 impl std::str::FromStr for Puzzle {
@@ -19,9 +20,8 @@ impl std::str::FromStr for Puzzle {
                 schematic
                     .chars()
                     .filter(char::is_ascii_punctuation)
-                    .map(|c| c as u64)
-                    // '#' has a 1 as it's least significant bit, '.' a zero
-                    .fold(0, |acc, c| (c & 1) | (acc << 1))
+                    .positions(|c| c == '#')
+                    .collect::<BitSet<1>>()
             })
             .collect_vec()
             .pipe(|items| Self { items })
@@ -29,6 +29,20 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+    const PART2: bool = false;
+
+    type Part1 = part1::Part1;
+    type Part2 = common::solution::NoPart;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -7,26 +7,29 @@ pub fn process(puzzle: &Puzzle) -> usize {
     puzzle
         .items
         .iter()
-        .copied()
         .tuple_combinations()
-        .filter(|(a, b)| a & b == 0)
+        .filter(|(a, b)| a.is_disjoint(b))
         .count()
 }
 
+common::solution!(Part1: Puzzle => usize, |puzzle| Ok(process(&puzzle)));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 3)]
-    #[case::example("part1.txt", 3249)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: usize) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::example("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(&input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
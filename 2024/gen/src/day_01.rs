@@ -0,0 +1,30 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Two columns of location IDs, with some of the left ones repeated on the right
+pub const GENERATOR: Generator = Generator {
+    day: 1,
+    size: "lines",
+    sizes: 1..=100_000,
+    default_size: 1000,
+    generate,
+};
+
+fn generate(rng: &mut Rng, lines: usize) -> String {
+    let left = (0..lines)
+        .map(|_| rng.range(10_000..=99_999))
+        .collect::<Vec<u32>>();
+    let mut text = String::new();
+    for &id in &left {
+        let right = if rng.chance(1, 3) {
+            *rng.choose(&left)
+        } else {
+            rng.range(10_000..=99_999)
+        };
+        let _ = writeln!(text, "{id}   {right}");
+    }
+    text
+}
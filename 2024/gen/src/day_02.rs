@@ -0,0 +1,36 @@
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Reports of levels which mostly change steadily, with a bad level in some of them
+pub const GENERATOR: Generator = Generator {
+    day: 2,
+    size: "reports",
+    sizes: 1..=100_000,
+    default_size: 1000,
+    generate,
+};
+
+fn generate(rng: &mut Rng, reports: usize) -> String {
+    (0..reports)
+        .map(|_| {
+            let mut direction = *rng.choose(&[-1, 1]);
+            let mut levels = vec![rng.range(1..=99)];
+            while levels.len() < rng.range(5..=8) {
+                let last = levels[levels.len() - 1];
+                let mut next = last + direction * rng.range(1..=3);
+                if !(1..=99).contains(&next) {
+                    direction = -direction;
+                    next = last + direction * rng.range(1..=3);
+                }
+                levels.push(next);
+            }
+            if rng.chance(1, 2) {
+                let bad = rng.range(0..=levels.len() - 1);
+                levels[bad] = rng.range(1..=99);
+            }
+            levels.iter().join(" ") + "\n"
+        })
+        .collect()
+}
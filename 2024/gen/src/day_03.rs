@@ -0,0 +1,52 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Corrupted memory: `mul`, `do` and `don't` instructions among junk, some of which looks a lot
+/// like them
+pub const GENERATOR: Generator = Generator {
+    day: 3,
+    size: "instructions",
+    sizes: 1..=100_000,
+    default_size: 700,
+    generate,
+};
+
+const JUNK: &[u8] = b"!@#$%^&*()[]{}<>,.;:'?/+- _~";
+const DECOYS: &[&str] = &[
+    "mul(3*4)",
+    "mul ( 2 , 4 )",
+    "mul(32,64]",
+    "mul(4,1234)",
+    "mul[3,7]",
+    "do_not_mul(5,5)",
+    "where()",
+    "select()",
+    "don't",
+    "do(",
+];
+
+fn generate(rng: &mut Rng, instructions: usize) -> String {
+    let per_line = instructions.div_ceil(6);
+    let mut text = String::new();
+    for i in 0..instructions {
+        match rng.range(0..=9) {
+            0 => text.push_str("do()"),
+            1 => text.push_str("don't()"),
+            2..=5 => {
+                let (a, b) = (rng.range(1..=999), rng.range(1..=999));
+                let _ = write!(text, "mul({a},{b})");
+            }
+            _ => text.push_str(rng.choose::<&str>(DECOYS)),
+        }
+        for _ in 0..rng.range(0..=6) {
+            text.push(char::from(*rng.choose(JUNK)));
+        }
+        if (i + 1) % per_line == 0 || i + 1 == instructions {
+            text.push('\n');
+        }
+    }
+    text
+}
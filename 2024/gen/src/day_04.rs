@@ -0,0 +1,19 @@
+use common::{check::Rng, grid::Grid};
+
+use crate::{render, square, Generator};
+
+/// A word search of the letters in `XMAS`
+pub const GENERATOR: Generator = Generator {
+    day: 4,
+    size: "side",
+    sizes: 1..=1000,
+    default_size: 140,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    render(&Grid::from_positions(
+        |_| *rng.choose(b"XMAS"),
+        square(side),
+    ))
+}
@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Rules ordering every pair of pages, then updates of an odd number of pages, about half of
+/// which are in order
+pub const GENERATOR: Generator = Generator {
+    day: 5,
+    size: "updates",
+    sizes: 1..=10_000,
+    default_size: 200,
+    generate,
+};
+
+fn generate(rng: &mut Rng, updates: usize) -> String {
+    let mut order = (11..=99).collect::<Vec<u32>>();
+    rng.shuffle(&mut order);
+    order.truncate(49);
+    let mut rules = order.iter().tuple_combinations().collect::<Vec<_>>();
+    rng.shuffle(&mut rules);
+
+    let mut text = String::new();
+    for (before, after) in rules {
+        let _ = writeln!(text, "{before}|{after}");
+    }
+    text.push('\n');
+    for _ in 0..updates {
+        let mut pages = order.clone();
+        rng.shuffle(&mut pages);
+        pages.truncate(rng.range(2..=11) * 2 + 1);
+        if rng.chance(1, 2) {
+            pages.sort_by_key(|page| order.iter().position(|p| p == page));
+        }
+        let _ = writeln!(text, "{}", pages.iter().join(","));
+    }
+    text
+}
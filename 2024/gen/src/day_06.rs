@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use common::{check::Rng, grid::Grid};
+use glam::IVec2;
+
+use crate::{render, square, Generator};
+
+/// A lab with scattered obstructions, and a guard who walks out of it rather than round in a
+/// loop
+pub const GENERATOR: Generator = Generator {
+    day: 6,
+    size: "side",
+    sizes: 2..=1000,
+    default_size: 130,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    loop {
+        let mut lab = Grid::from_positions(
+            |_| if rng.chance(1, 20) { b'#' } else { b'.' },
+            square(side),
+        );
+        let guard = lab
+            .positions()
+            .filter(|&pos| lab[pos] == b'.')
+            .collect::<Vec<_>>();
+        if guard.is_empty() {
+            continue;
+        }
+        let guard = *rng.choose(&guard);
+        if leaves(&lab, guard) {
+            lab[guard] = b'^';
+            return render(&lab);
+        }
+    }
+}
+
+fn leaves(lab: &Grid<u8>, mut guard: IVec2) -> bool {
+    let mut facing = IVec2::NEG_Y;
+    let mut seen = HashSet::new();
+    while seen.insert((guard, facing)) {
+        match lab.get(guard + facing) {
+            None => return true,
+            Some(b'#') => facing = facing.perp(),
+            Some(_) => guard += facing,
+        }
+    }
+    false
+}
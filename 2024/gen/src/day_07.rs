@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Calibration equations, about half of which can be made true with `+`, `*` and `||`
+pub const GENERATOR: Generator = Generator {
+    day: 7,
+    size: "equations",
+    sizes: 1..=100_000,
+    default_size: 850,
+    generate,
+};
+
+/// Keeps the test values well within a `u64`, like the real ones
+const MAX_VALUE: u64 = 1_000_000_000_000_000;
+
+fn generate(rng: &mut Rng, equations: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..equations {
+        let operands = (0..rng.range(2..=12))
+            .map(|_| {
+                if rng.chance(4, 5) {
+                    rng.range(1..=99)
+                } else {
+                    rng.range(100..=999)
+                }
+            })
+            .collect::<Vec<u64>>();
+        let mut value = operands[0];
+        for &operand in &operands[1..] {
+            let concatenated = format!("{value}{operand}").parse().ok();
+            value = match rng.range(0..=2) {
+                0 => value.checked_mul(operand),
+                1 => concatenated,
+                _ => None,
+            }
+            .filter(|&value| value <= MAX_VALUE)
+            .unwrap_or(value + operand);
+        }
+        if rng.chance(1, 2) {
+            value += rng.range(1..=99);
+        }
+        let _ = writeln!(text, "{value}: {}", operands.iter().join(" "));
+    }
+    text
+}
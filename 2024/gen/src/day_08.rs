@@ -0,0 +1,27 @@
+use common::{check::Rng, grid::Grid};
+
+use crate::{render, square, Generator};
+
+/// A map of antennas, a few for each frequency
+pub const GENERATOR: Generator = Generator {
+    day: 8,
+    size: "side",
+    sizes: 2..=1000,
+    default_size: 50,
+    generate,
+};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let mut map = Grid::from_value(b'.', square(side));
+    let mut tiles = map.positions().collect::<Vec<_>>();
+    rng.shuffle(&mut tiles);
+    let mut tiles = tiles.into_iter();
+    for &frequency in &FREQUENCIES[..(side / 4).clamp(1, FREQUENCIES.len())] {
+        for tile in tiles.by_ref().take(rng.range(3..=4)) {
+            map[tile] = frequency;
+        }
+    }
+    render(&map)
+}
@@ -0,0 +1,16 @@
+use common::check::{DiskMaps, Gen, Rng};
+
+use crate::Generator;
+
+/// A disk map, from `common::check`
+pub const GENERATOR: Generator = Generator {
+    day: 9,
+    size: "files",
+    sizes: 1..=100_000,
+    default_size: 10_000,
+    generate,
+};
+
+fn generate(rng: &mut Rng, files: usize) -> String {
+    format!("{}\n", DiskMaps(files..=files).generate(rng))
+}
@@ -0,0 +1,36 @@
+use common::{check::Rng, grid::Grid};
+
+use tap::TryConv;
+
+use crate::{render, square, Generator};
+
+/// A topographic map whose heights mostly change gradually, so there are plenty of trails
+pub const GENERATOR: Generator = Generator {
+    day: 10,
+    size: "side",
+    sizes: 1..=1000,
+    default_size: 50,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let mut walk = || {
+        (0..side)
+            .scan(rng.range(0..=9), |height, _| {
+                *height += rng.range(-1..=1);
+                Some(*height)
+            })
+            .collect::<Vec<i32>>()
+    };
+    let (across, down) = (walk(), walk());
+    render(&Grid::from_positions(
+        |pos| {
+            let (x, y) = (pos.x.try_conv::<usize>(), pos.y.try_conv::<usize>());
+            let height = (across[x.expect("x to be positive")]
+                + down[y.expect("y to be positive")])
+            .rem_euclid(10);
+            b'0' + u8::try_from(height).expect("height to be a digit")
+        },
+        square(side),
+    ))
+}
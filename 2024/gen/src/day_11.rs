@@ -0,0 +1,26 @@
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// A row of stones, mostly with large numbers engraved on them
+pub const GENERATOR: Generator = Generator {
+    day: 11,
+    size: "stones",
+    sizes: 1..=10_000,
+    default_size: 8,
+    generate,
+};
+
+fn generate(rng: &mut Rng, stones: usize) -> String {
+    (0..stones)
+        .map(|_| {
+            if rng.chance(1, 4) {
+                rng.range(0..=9)
+            } else {
+                rng.range(10..=9_999_999u64)
+            }
+        })
+        .join(" ")
+        + "\n"
+}
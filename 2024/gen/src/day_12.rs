@@ -0,0 +1,32 @@
+use common::{check::Rng, grid::Grid};
+use glam::IVec2;
+
+use crate::{render, square, Generator};
+
+/// A garden of regions grown around random plots, some of them sharing a plant with a neighbour
+pub const GENERATOR: Generator = Generator {
+    day: 12,
+    size: "side",
+    sizes: 1..=1000,
+    default_size: 140,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let bounds = square(side);
+    let seeds = (0..(side * side / 30).max(1))
+        .map(|_| {
+            let plot = IVec2::new(rng.range(0..=bounds.x - 1), rng.range(0..=bounds.y - 1));
+            (plot, rng.range(b'A'..=b'Z'))
+        })
+        .collect::<Vec<_>>();
+    render(&Grid::from_positions(
+        |pos| {
+            seeds
+                .iter()
+                .min_by_key(|(plot, _)| (*plot - pos).abs().element_sum())
+                .map_or(b'A', |&(_, plant)| plant)
+        },
+        bounds,
+    ))
+}
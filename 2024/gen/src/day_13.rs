@@ -0,0 +1,34 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Claw machines, about half of which can win their prize
+pub const GENERATOR: Generator = Generator {
+    day: 13,
+    size: "machines",
+    sizes: 1..=100_000,
+    default_size: 320,
+    generate,
+};
+
+fn generate(rng: &mut Rng, machines: usize) -> String {
+    let mut text = String::new();
+    for i in 0..machines {
+        let [ax, ay, bx, by] = [(); 4].map(|()| rng.range(10..=99));
+        let (a, b) = (rng.range(0..=100), rng.range(0..=100));
+        let (mut x, mut y) = (a * ax + b * bx, a * ay + b * by);
+        if rng.chance(1, 2) {
+            x += rng.range(1..=99);
+            y += rng.range(1..=99);
+        }
+        if i > 0 {
+            text.push('\n');
+        }
+        let _ = writeln!(text, "Button A: X+{ax}, Y+{ay}");
+        let _ = writeln!(text, "Button B: X+{bx}, Y+{by}");
+        let _ = writeln!(text, "Prize: X={x}, Y={y}");
+    }
+    text
+}
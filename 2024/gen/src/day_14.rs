@@ -0,0 +1,29 @@
+use common::check::{Gen, Rng, Skies};
+use glam::IVec2;
+
+use crate::Generator;
+
+/// Robots in the real puzzle's 101 by 103 area, which all line up into a picture at some point,
+/// from `common::check`
+pub const GENERATOR: Generator = Generator {
+    day: 14,
+    size: "robots",
+    sizes: 1..=5000,
+    default_size: 500,
+    generate,
+};
+
+fn generate(rng: &mut Rng, robots: usize) -> String {
+    // leave a quarter of the picture empty, so it's not just a filled in square
+    let side = (1..=101)
+        .find(|side| side * side * 3 >= robots * 4)
+        .expect("the picture to fit in the area");
+    let picture = IVec2::splat(side.try_into().expect("picture to fit in an IVec2"));
+    Skies {
+        size: IVec2::new(101, 103),
+        robots: robots..=robots,
+        picture,
+    }
+    .generate(rng)
+    .to_string()
+}
@@ -0,0 +1,41 @@
+use common::{check::Rng, grid::Grid};
+
+use crate::{render, square, Generator};
+
+/// A walled warehouse full of boxes with the robot in the middle, then the robot's moves
+pub const GENERATOR: Generator = Generator {
+    day: 15,
+    size: "side",
+    sizes: 3..=1000,
+    default_size: 50,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let bounds = square(side);
+    let mut warehouse = Grid::from_positions(
+        |pos| {
+            if pos.min_element() == 0 || pos.x == bounds.x - 1 || pos.y == bounds.y - 1 {
+                b'#'
+            } else {
+                match rng.range(0..=99) {
+                    0..=7 => b'#',
+                    8..=33 => b'O',
+                    _ => b'.',
+                }
+            }
+        },
+        bounds,
+    );
+    warehouse[bounds / 2] = b'@';
+    let moves = (0..side * side * 8)
+        .map(|_| char::from(*rng.choose(b"<>^v")))
+        .collect::<Vec<_>>();
+    let mut text = render(&warehouse);
+    for line in moves.chunks(1000) {
+        text.push('\n');
+        text.extend(line);
+    }
+    text.push('\n');
+    text
+}
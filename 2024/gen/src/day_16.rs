@@ -0,0 +1,35 @@
+use common::check::Rng;
+use glam::IVec2;
+
+use crate::{render, square, Generator, Maze};
+
+/// A maze from the bottom left corner to the top right, with some walls knocked through so that
+/// there's more than one way round
+pub const GENERATOR: Generator = Generator {
+    day: 16,
+    size: "side (odd)",
+    sizes: 5..=1001,
+    default_size: 141,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let bounds = square(side | 1);
+    let start = IVec2::new(1, bounds.y - 2);
+    let mut maze = Maze::new(rng, bounds, start).walls();
+    let walls = maze
+        .positions()
+        .filter(|&pos| {
+            pos.cmpgt(IVec2::ZERO).all()
+                && pos.cmplt(bounds - IVec2::ONE).all()
+                && (pos.x % 2 == 0) != (pos.y % 2 == 0)
+                && maze[pos] == b'#'
+        })
+        .collect::<Vec<_>>();
+    for _ in 0..walls.len() / 10 {
+        maze[*rng.choose(&walls)] = b'.';
+    }
+    maze[start] = b'S';
+    maze[IVec2::new(bounds.x - 2, 1)] = b'E';
+    render(&maze)
+}
@@ -0,0 +1,32 @@
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// A program shaped like the real ones: each time round its loop it outputs a few bits of `A`
+/// mixed with some higher up ones, then shifts `A` along by 3 bits until it's 0
+pub const GENERATOR: Generator = Generator {
+    day: 17,
+    size: "octal digits in A",
+    sizes: 1..=21,
+    default_size: 16,
+    generate,
+};
+
+fn generate(rng: &mut Rng, digits: usize) -> String {
+    let digits = u32::try_from(digits).expect("at most 21 digits");
+    let a = rng.range(8u64.pow(digits - 1)..=8u64.pow(digits) - 1);
+    let mut body = vec![[1, rng.range(0..=7)], [4, rng.range(0..=7)]];
+    rng.shuffle(&mut body);
+    let mut program = [[2, 4], [1, rng.range(0..=7)], [7, 5]]
+        .into_iter()
+        .chain(body)
+        .chain([[5, 5]])
+        .collect::<Vec<[u8; 2]>>();
+    program.insert(rng.range(1..=program.len()), [0, 3]);
+    program.push([3, 0]);
+    format!(
+        "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        program.iter().flatten().join(",")
+    )
+}
@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+
+use common::{check::Rng, grid::Grid};
+use glam::IVec2;
+use itertools::Itertools;
+
+use crate::{square, Generator};
+
+/// Bytes falling into a square memory space, which still leave a way from corner to corner after
+/// as many as the first part counts, but not after all of them
+pub const GENERATOR: Generator = Generator {
+    day: 18,
+    size: "side",
+    sizes: 7..=1000,
+    default_size: 71,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let bounds = square(side);
+    let mut bytes = Grid::from_value((), bounds)
+        .positions()
+        .filter(|&pos| pos != IVec2::ZERO && pos != bounds - IVec2::ONE)
+        .collect::<Vec<_>>();
+    loop {
+        rng.shuffle(&mut bytes);
+        // the index of the first byte to cut the corners off from each other
+        let mut blocked = (0, bytes.len());
+        while blocked.0 < blocked.1 {
+            let mid = usize::midpoint(blocked.0, blocked.1);
+            if connected(bounds, &bytes[..=mid]) {
+                blocked.0 = mid + 1;
+            } else {
+                blocked.1 = mid;
+            }
+        }
+        let blocked = blocked.0;
+        let count = (bytes.len() * 2 / 3).max(blocked + 1).min(bytes.len());
+        // the first part only counts this many, or 12 for small examples
+        let counted = if count < 1024 { 12 } else { 1024 };
+        if blocked < bytes.len() && counted <= blocked {
+            return bytes[..count]
+                .iter()
+                .map(|byte| format!("{},{}\n", byte.x, byte.y))
+                .join("");
+        }
+    }
+}
+
+fn connected(bounds: IVec2, bytes: &[IVec2]) -> bool {
+    let mut open = Grid::from_value(true, bounds);
+    for &byte in bytes {
+        open[byte] = false;
+    }
+    let mut queue = VecDeque::from([IVec2::ZERO]);
+    open[IVec2::ZERO] = false;
+    while let Some(pos) = queue.pop_front() {
+        if pos == bounds - IVec2::ONE {
+            return true;
+        }
+        for next in [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y].map(|dir| pos + dir) {
+            if open.get(next) == Some(&true) {
+                open[next] = false;
+                queue.push_back(next);
+            }
+        }
+    }
+    false
+}
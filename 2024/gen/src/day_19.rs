@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Towel patterns, then designs about half of which are made out of them
+pub const GENERATOR: Generator = Generator {
+    day: 19,
+    size: "designs",
+    sizes: 1..=100_000,
+    default_size: 400,
+    generate,
+};
+
+const COLOURS: &[u8] = b"wubrg";
+
+fn stripes(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| char::from(*rng.choose(COLOURS))).collect()
+}
+
+fn generate(rng: &mut Rng, designs: usize) -> String {
+    let mut towels = HashSet::new();
+    while towels.len() < 447 {
+        let len = rng.range(1..=8);
+        towels.insert(stripes(rng, len));
+    }
+    let towels = towels.into_iter().sorted().collect::<Vec<_>>();
+    let mut text = towels.iter().join(", ") + "\n\n";
+    for _ in 0..designs {
+        let len = rng.range(20..=60);
+        let design = if rng.chance(1, 2) {
+            let mut design = String::new();
+            while design.len() < len {
+                design.push_str(rng.choose::<String>(&towels));
+            }
+            design
+        } else {
+            stripes(rng, len)
+        };
+        text.push_str(&design);
+        text.push('\n');
+    }
+    text
+}
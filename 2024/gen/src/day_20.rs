@@ -0,0 +1,32 @@
+use common::{check::Rng, grid::Grid};
+use glam::IVec2;
+
+use crate::{render, square, Generator, Maze};
+
+/// A single winding track through walls, from the start to the far end of a maze
+pub const GENERATOR: Generator = Generator {
+    day: 20,
+    size: "side (odd)",
+    sizes: 5..=1001,
+    default_size: 141,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    let bounds = square(side | 1);
+    let start = IVec2::new(rng.range(0..=bounds.x / 2 - 1) * 2 + 1, 1);
+    let maze = Maze::new(rng, bounds, start);
+    let end = maze
+        .depth
+        .positions()
+        .filter(|&pos| maze.depth[pos] != usize::MAX)
+        .max_by_key(|&pos| maze.depth[pos])
+        .unwrap_or(start);
+    let mut track = Grid::from_value(b'#', bounds);
+    for tile in maze.path(end) {
+        track[tile] = b'.';
+    }
+    track[start] = b'S';
+    track[end] = b'E';
+    render(&track)
+}
@@ -0,0 +1,16 @@
+use common::check::{Gen, KeypadCodes, Rng};
+
+use crate::Generator;
+
+/// Door codes, from `common::check`
+pub const GENERATOR: Generator = Generator {
+    day: 21,
+    size: "codes",
+    sizes: 1..=1000,
+    default_size: 5,
+    generate,
+};
+
+fn generate(rng: &mut Rng, codes: usize) -> String {
+    KeypadCodes(codes..=codes).generate(rng).to_string()
+}
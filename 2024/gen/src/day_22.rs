@@ -0,0 +1,17 @@
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Each buyer's initial secret number
+pub const GENERATOR: Generator = Generator {
+    day: 22,
+    size: "buyers",
+    sizes: 1..=100_000,
+    default_size: 2000,
+    generate,
+};
+
+fn generate(rng: &mut Rng, buyers: usize) -> String {
+    (0..buyers).map(|_| rng.range(1..=16_777_215u32)).join("\n") + "\n"
+}
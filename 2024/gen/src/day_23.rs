@@ -0,0 +1,50 @@
+use std::collections::BTreeSet;
+
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::{names, Generator};
+
+/// A network where each computer is connected to around 13 others, with a LAN party of 13 of
+/// them all connected to each other
+pub const GENERATOR: Generator = Generator {
+    day: 23,
+    size: "computers",
+    sizes: 2..=676,
+    default_size: 520,
+    generate,
+};
+
+const DEGREE: usize = 13;
+
+fn generate(rng: &mut Rng, computers: usize) -> String {
+    let names = names(rng, computers, 2, &[]);
+    let mut connections = BTreeSet::new();
+    let party = DEGREE.min(computers);
+    for (a, b) in (0..party).tuple_combinations() {
+        connections.insert((a, b));
+    }
+    let mut degrees = vec![0; computers];
+    for &(a, b) in &connections {
+        degrees[a] += 1;
+        degrees[b] += 1;
+    }
+    for a in party..computers {
+        while degrees[a] < DEGREE.min(computers - 1) {
+            let b = rng.range(0..=computers - 1);
+            if a != b && connections.insert((a.min(b), a.max(b))) {
+                degrees[a] += 1;
+                degrees[b] += 1;
+            }
+        }
+    }
+    let mut lines = connections
+        .into_iter()
+        .map(|(a, b)| {
+            let (a, b) = if rng.chance(1, 2) { (a, b) } else { (b, a) };
+            format!("{}-{}\n", names[a], names[b])
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
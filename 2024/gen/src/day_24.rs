@@ -0,0 +1,122 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+
+use crate::{names, Generator};
+
+/// A ripple carry adder of `x` and `y` into `z`, with the outputs of four pairs of gates swapped
+/// like the real ones: each within a different bit's full adder, so there are no loops
+pub const GENERATOR: Generator = Generator {
+    day: 24,
+    size: "bits",
+    sizes: 6..=63,
+    default_size: 45,
+    generate,
+};
+
+/// The gates in a full adder for bit `i`, named after what they do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    /// `x XOR y`
+    Sum,
+    /// `x AND y`
+    Generate,
+    /// `sum XOR carry in`, which is `z`
+    Output,
+    /// `sum AND carry in`
+    Propagate,
+    /// `generate OR propagate`
+    CarryOut,
+}
+
+/// The pairs of gates the real puzzles swap
+const SWAPS: &[(Role, Role)] = &[
+    (Role::Output, Role::Propagate),
+    (Role::Output, Role::CarryOut),
+    (Role::Output, Role::Generate),
+    (Role::Sum, Role::Generate),
+];
+
+fn generate(rng: &mut Rng, bits: usize) -> String {
+    let mut names = names(rng, 4 * bits, 3, b"xyz").into_iter();
+    let mut name = |role: Role, bit: usize, last: bool| match role {
+        Role::Output => format!("z{bit:02}"),
+        Role::CarryOut if last => format!("z{:02}", bit + 1),
+        _ => names.next().expect("enough names for every gate"),
+    };
+    let wires = (0..bits)
+        .map(|bit| {
+            let last = bit + 1 == bits;
+            [
+                Role::Sum,
+                Role::Generate,
+                Role::Output,
+                Role::Propagate,
+                Role::CarryOut,
+            ]
+            .map(|role| name(role, bit, last))
+        })
+        .collect::<Vec<_>>();
+    let wire = |bit: usize, role: Role| wires[bit][role as usize].clone();
+
+    let mut gates = Vec::new();
+    for bit in 0..bits {
+        let (x, y) = (format!("x{bit:02}"), format!("y{bit:02}"));
+        if bit == 0 {
+            gates.push((x.clone(), "XOR", y.clone(), wire(0, Role::Output)));
+            gates.push((x, "AND", y, wire(0, Role::CarryOut)));
+            continue;
+        }
+        let carry = wire(bit - 1, Role::CarryOut);
+        gates.extend([
+            (x.clone(), "XOR", y.clone(), wire(bit, Role::Sum)),
+            (x, "AND", y, wire(bit, Role::Generate)),
+            (
+                wire(bit, Role::Sum),
+                "XOR",
+                carry.clone(),
+                wire(bit, Role::Output),
+            ),
+            (
+                wire(bit, Role::Sum),
+                "AND",
+                carry,
+                wire(bit, Role::Propagate),
+            ),
+            (
+                wire(bit, Role::Generate),
+                "OR",
+                wire(bit, Role::Propagate),
+                wire(bit, Role::CarryOut),
+            ),
+        ]);
+    }
+
+    let mut swapped = (1..bits - 1).collect::<Vec<_>>();
+    rng.shuffle(&mut swapped);
+    for &bit in &swapped[..4] {
+        let (a, b) = *rng.choose(SWAPS);
+        let (a, b) = (wire(bit, a), wire(bit, b));
+        for gate in &mut gates {
+            if gate.3 == a {
+                gate.3.clone_from(&b);
+            } else if gate.3 == b {
+                gate.3.clone_from(&a);
+            }
+        }
+    }
+    rng.shuffle(&mut gates);
+
+    let mut text = String::new();
+    for input in ["x", "y"] {
+        for bit in 0..bits {
+            let _ = writeln!(text, "{input}{bit:02}: {}", rng.range(0..=1));
+        }
+    }
+    text.push('\n');
+    for (a, op, b, out) in gates {
+        let (a, b) = if rng.chance(1, 2) { (a, b) } else { (b, a) };
+        let _ = writeln!(text, "{a} {op} {b} -> {out}");
+    }
+    text
+}
@@ -0,0 +1,32 @@
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Lock and key schematics, half of each
+pub const GENERATOR: Generator = Generator {
+    day: 25,
+    size: "schematics",
+    sizes: 1..=10_000,
+    default_size: 500,
+    generate,
+};
+
+fn generate(rng: &mut Rng, schematics: usize) -> String {
+    let mut text = String::new();
+    for i in 0..schematics {
+        let lock = rng.chance(1, 2);
+        let heights = [(); 5].map(|()| rng.range(0..=5));
+        if i > 0 {
+            text.push('\n');
+        }
+        for row in 0..7 {
+            for &height in &heights {
+                // rows counted from the top for locks, and from the bottom for keys
+                let from_edge = if lock { row } else { 6 - row };
+                text.push(if from_edge <= height { '#' } else { '.' });
+            }
+            text.push('\n');
+        }
+    }
+    text
+}
@@ -0,0 +1,240 @@
+pub use common::check::Generator;
+use common::{check::Rng, grid::Grid};
+use glam::IVec2;
+use tap::TryConv;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_20;
+pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+
+/// Every day's generator, in order
+pub static DAYS: [Generator; 25] = [
+    day_01::GENERATOR,
+    day_02::GENERATOR,
+    day_03::GENERATOR,
+    day_04::GENERATOR,
+    day_05::GENERATOR,
+    day_06::GENERATOR,
+    day_07::GENERATOR,
+    day_08::GENERATOR,
+    day_09::GENERATOR,
+    day_10::GENERATOR,
+    day_11::GENERATOR,
+    day_12::GENERATOR,
+    day_13::GENERATOR,
+    day_14::GENERATOR,
+    day_15::GENERATOR,
+    day_16::GENERATOR,
+    day_17::GENERATOR,
+    day_18::GENERATOR,
+    day_19::GENERATOR,
+    day_20::GENERATOR,
+    day_21::GENERATOR,
+    day_22::GENERATOR,
+    day_23::GENERATOR,
+    day_24::GENERATOR,
+    day_25::GENERATOR,
+];
+
+/// The generator for `day`
+#[must_use]
+pub fn day(day: u8) -> Option<&'static Generator> {
+    DAYS.iter().find(|generator| generator.day == day)
+}
+
+/// The side of a square grid as a vector
+pub(crate) fn square(side: usize) -> IVec2 {
+    IVec2::splat(side.try_conv().expect("grid to fit in an IVec2"))
+}
+
+/// A grid as lines of text
+pub(crate) fn render(grid: &Grid<u8>) -> String {
+    format!("{grid}\n")
+}
+
+/// `count` different names of `len` lowercase letters, none of which start with anything in
+/// `reserved`
+pub(crate) fn names(rng: &mut Rng, count: usize, len: usize, reserved: &[u8]) -> Vec<String> {
+    let mut names = std::collections::HashSet::new();
+    let mut unique = Vec::new();
+    while unique.len() < count {
+        let name = (0..len)
+            .map(|i| loop {
+                let letter = rng.range(b'a'..=b'z');
+                if i > 0 || !reserved.contains(&letter) {
+                    break char::from(letter);
+                }
+            })
+            .collect::<String>();
+        if names.insert(name.clone()) {
+            unique.push(name);
+        }
+    }
+    unique
+}
+
+/// A maze carved by a randomised depth first search from `start`, as a spanning tree of the
+/// tiles with odd coordinates. The tiles between them are walls, except where they join a tile to
+/// its parent.
+pub(crate) struct Maze {
+    pub parent: Grid<Option<IVec2>>,
+    pub depth: Grid<usize>,
+}
+
+impl Maze {
+    /// `size` must be odd in both directions, and `start` odd in both coordinates
+    pub fn new(rng: &mut Rng, size: IVec2, start: IVec2) -> Self {
+        let mut parent = Grid::from_value(None, size);
+        let mut depth = Grid::from_value(usize::MAX, size);
+        depth[start] = 0;
+        let mut stack = vec![start];
+        while let Some(&tile) = stack.last() {
+            let mut next = [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
+                .map(|dir| tile + dir * 2)
+                .into_iter()
+                .filter(|&next| {
+                    next.cmpgt(IVec2::ZERO).all()
+                        && next.cmplt(size - IVec2::ONE).all()
+                        && depth[next] == usize::MAX
+                })
+                .collect::<Vec<_>>();
+            if next.is_empty() {
+                stack.pop();
+                continue;
+            }
+            rng.shuffle(&mut next);
+            parent[next[0]] = Some(tile);
+            depth[next[0]] = depth[tile] + 1;
+            stack.push(next[0]);
+        }
+        Self { parent, depth }
+    }
+
+    /// Every tile of the maze, with `#` for walls and `.` for the passages
+    pub fn walls(&self) -> Grid<u8> {
+        let mut grid = Grid::from_value(b'#', self.parent.size());
+        for tile in self.parent.positions() {
+            if let Some(parent) = self.parent[tile] {
+                grid[tile] = b'.';
+                grid[(tile + parent) / 2] = b'.';
+                grid[parent] = b'.';
+            }
+        }
+        grid
+    }
+
+    /// The tiles from `end` back to the start, including those joining each tile to the next
+    pub fn path(&self, end: IVec2) -> Vec<IVec2> {
+        let mut path = vec![end];
+        let mut tile = end;
+        while let Some(parent) = self.parent[tile] {
+            path.extend([(tile + parent) / 2, parent]);
+            tile = parent;
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        runner::{Day, Part, Registered, RunError, Solver},
+        solution::Error,
+        Solution,
+    };
+
+    use super::*;
+
+    /// Parses `text` as both parts of `D` would
+    fn parses<D: Day>(text: &str) -> Result<(), Error> {
+        D::Part1::prepare(text, &Default::default())?;
+        D::Part2::prepare(text, &Default::default())?;
+        Ok(())
+    }
+
+    /// How to parse a day's input, and how to solve it
+    type Check = (fn(&str) -> Result<(), Error>, &'static dyn Solver);
+
+    macro_rules! days {
+        ($($day:ident),* $(,)?) => {
+            [$((
+                parses::<::$day::Day> as fn(&str) -> Result<(), Error>,
+                &Registered::<::$day::Day>::new() as &dyn Solver,
+            )),*]
+        };
+    }
+
+    /// Each generator's day, to check it against
+    static CHECKS: [Check; 25] = days![
+        day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11,
+        day_12, day_13, day_14, day_15, day_16, day_17, day_18, day_19, day_20, day_21, day_22,
+        day_23, day_24, day_25,
+    ];
+
+    #[test]
+    fn round_trips() {
+        for (generator, (parses, _)) in DAYS.iter().zip(&CHECKS) {
+            for size in [*generator.sizes.start(), generator.default_size] {
+                for seed in 0..3 {
+                    let text = generator.generate(seed, size);
+                    assert_eq!(
+                        generator.generate(seed, size),
+                        text,
+                        "day {}",
+                        generator.day
+                    );
+                    if let Err(err) = parses(&text) {
+                        panic!("day {} size {size} seed {seed}: {err}", generator.day);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether `err` is an answer in itself: a random program needn't have a quine for day 17
+    fn no_answer(day: u8, part: Part, err: &RunError) -> bool {
+        matches!(
+            (day, part, err),
+            (17, Part::Two, RunError::Solve(err))
+                if matches!(err.downcast_ref(), Some(::day_17::part2::Error::NoQuine))
+        )
+    }
+
+    #[test]
+    fn solves_small_inputs() {
+        for (generator, (_, solver)) in DAYS.iter().zip(&CHECKS) {
+            assert_eq!(solver.day(), generator.day);
+            let text = generator.generate(0, *generator.sizes.start());
+            for part in solver.parts() {
+                match solver.run(*part, &text) {
+                    Err(err) if !no_answer(generator.day, *part, &err) => {
+                        panic!("day {} part {part}: {err}\n{text}", generator.day);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
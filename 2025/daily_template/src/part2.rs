@@ -1,27 +1,28 @@
-use tap::prelude::*;
-
 use crate::Puzzle;
 
 #[must_use]
 #[allow(clippy::needless_pass_by_value)]
-pub fn process(puzzle: Puzzle) -> String {
+pub fn process(puzzle: Puzzle) -> u64 {
     todo!("{{crate_name}} part 2")
 }
 
+common::solution!(Part2: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", "{{crate_name}} part2")]
-    #[case::puzzle("input.txt", "{{crate_name}} part2")]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: &str) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("input.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), common::expected!(Part::Two, input_path));
         Ok(())
     }
 }
@@ -22,20 +22,24 @@ pub fn process(puzzle: Puzzle) -> u64 {
         .sum()
 }
 
+common::solution!(Part2: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 6)]
-    #[case::example("part2.txt", 6932)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::example("part2.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
@@ -24,22 +24,26 @@ fn repeats(range: crate::Range) -> impl Iterator<Item = u64> {
         .take_while(move |&n| n <= range.end)
 }
 
+common::solution!(Part1: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
     use tap::prelude::*;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 1_227_775_554)]
-    #[case::part1("part1.txt", 30_599_400_849)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::part1("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = input.clone().pipe(process);
-        assert_eq!(output, expected);
-        assert_eq!(process_specialized(input), expected);
+        assert_eq!(output.to_string(), expected);
+        assert_eq!(process_specialized(input).to_string(), expected);
         Ok(())
     }
 }
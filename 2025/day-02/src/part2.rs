@@ -13,20 +13,24 @@ pub fn process(puzzle: Puzzle) -> u64 {
         .sum::<u64>()
 }
 
+common::solution!(Part2: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 4_174_379_265)]
-    #[case::part2("part2.txt", 46_270_373_595)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::part2("part2.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
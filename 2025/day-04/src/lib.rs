@@ -4,17 +4,6 @@ use tap::prelude::*;
 pub mod part1;
 pub mod part2;
 
-const NEIGBORS: [IVec2; 8] = [
-    IVec2::new(-1, -1),
-    IVec2::new(-1, 0),
-    IVec2::new(-1, 1),
-    IVec2::new(0, -1),
-    IVec2::new(0, 1),
-    IVec2::new(1, -1),
-    IVec2::new(1, 0),
-    IVec2::new(1, 1),
-];
-
 #[derive(Debug, Clone)]
 pub struct Puzzle {
     pub grid: common::grid::Grid<u8>,
@@ -25,15 +14,7 @@ impl Puzzle {
         self.grid
             .positions()
             .filter(|&center| match self.grid.get(center).copied() {
-                Some(b'@') => {
-                    NEIGBORS
-                        .iter()
-                        .copied()
-                        .map(|pos| pos + center)
-                        .filter(|&pos| self.grid.get(pos).is_some_and(|&b| b == b'@'))
-                        .count()
-                        < 4
-                }
+                Some(b'@') => self.rolls_around(center) < 4,
                 Some(_) | None => false,
             })
             // pre-allocate more than enough space for the rolls
@@ -43,17 +24,16 @@ impl Puzzle {
             })
     }
 
+    fn rolls_around(&self, center: IVec2) -> usize {
+        self.grid
+            .neighbors8(center)
+            .filter(|&pos| self.grid[pos] == b'@')
+            .count()
+    }
+
     fn reachable(&self, center: IVec2) -> bool {
         match self.grid.get(center).copied() {
-            Some(b'@') => {
-                NEIGBORS
-                    .iter()
-                    .copied()
-                    .map(|pos| pos + center)
-                    .filter(|&pos| self.grid.get(pos).is_some_and(|&b| b == b'@'))
-                    .count()
-                    < 4
-            }
+            Some(b'@') => self.rolls_around(center) < 4,
             Some(_) | None => false,
         }
     }
@@ -67,6 +47,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
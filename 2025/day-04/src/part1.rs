@@ -14,11 +14,10 @@ pub fn process_specialized(puzzle: Puzzle) -> usize {
         .positions()
         .filter(|&center| match puzzle.grid.get(center).copied() {
             Some(b'@') => {
-                crate::NEIGBORS
-                    .iter()
-                    .copied()
-                    .map(|pos| pos + center)
-                    .filter(|&pos| puzzle.grid.get(pos).is_some_and(|&b| b == b'@'))
+                puzzle
+                    .grid
+                    .neighbors8(center)
+                    .filter(|&pos| puzzle.grid[pos] == b'@')
                     .count()
                     < 4
             }
@@ -27,21 +26,25 @@ pub fn process_specialized(puzzle: Puzzle) -> usize {
         .count()
 }
 
+common::solution!(Part1: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
     use tap::prelude::*;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 13)]
-    #[case::puzzle("part1.txt", 1397)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: usize) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
-        assert_eq!(input.clone().pipe(process), expected);
-        assert_eq!(process_specialized(input), expected);
+        assert_eq!(input.clone().pipe(process).to_string(), expected);
+        assert_eq!(process_specialized(input).to_string(), expected);
         Ok(())
     }
 }
@@ -10,30 +10,33 @@ pub fn process(mut puzzle: Puzzle) -> usize {
         }
         count += 1;
         puzzle.grid[pos] = b'.';
-        let exposed = crate::NEIGBORS
-            .iter()
-            .copied()
-            .map(|offset| pos + offset)
+        let exposed = puzzle
+            .grid
+            .neighbors8(pos)
             .filter(|&center| puzzle.reachable(center));
         queue.extend(exposed);
     }
     count
 }
 
+common::solution!(Part2: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 43)]
-    #[case::puzzle("part2.txt", 8758)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: usize) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("part2.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
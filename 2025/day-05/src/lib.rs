@@ -1,6 +1,6 @@
 use std::ops::RangeInclusive;
 
-use color_eyre::eyre::OptionExt;
+use common::parse::{self, Span};
 use itertools::Itertools;
 use tap::prelude::*;
 
@@ -17,25 +17,20 @@ impl std::str::FromStr for Puzzle {
     type Err = color_eyre::Report;
 
     fn from_str(s: &str) -> color_eyre::Result<Self> {
-        let (ranges, ids) = s
-            .trim()
-            .split_once("\n\n")
-            .ok_or_eyre("Missing blank line")?;
+        let (ranges, ids) = parse::two_blocks(s)?;
         Self {
             ranges: {
                 let mut unmerged: Vec<_> = ranges
-                    .trim()
                     .lines()
-                    .map(str::trim)
                     .map(|s| {
-                        let (start, end) = s.split_once('-').ok_or_eyre("Missing '-'")?;
+                        let (start, end) = s.split_once("-")?;
                         let (start, end): (u64, u64) = (start.parse()?, end.parse()?);
                         if start > end {
                             end..=start
                         } else {
                             start..=end
                         }
-                        .pipe(Ok::<_, color_eyre::Report>)
+                        .pipe(Ok::<_, parse::Error>)
                     })
                     .try_collect()?;
                 unmerged.sort_by_key(|r| *r.start());
@@ -53,12 +48,25 @@ impl std::str::FromStr for Puzzle {
                     },
                 )
             },
-            ids: ids.trim().lines().map(str::parse::<u64>).try_collect()?,
+            ids: ids.lines().map(Span::parse::<u64>).try_collect()?,
         }
         .pipe(Ok)
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -24,21 +24,25 @@ pub fn process(puzzle: Puzzle) -> usize {
         .count()
 }
 
+common::solution!(Part1: Puzzle => usize, process);
+
 #[cfg(test)]
 mod tests {
 
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 3)]
-    #[case::puzzle("part1.txt", 744)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: usize) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
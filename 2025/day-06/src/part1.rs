@@ -23,20 +23,24 @@ pub fn process(puzzle: Puzzle) -> Result<u64, std::num::ParseIntError> {
         .pipe(|it| Operator::Add.solve(it))
 }
 
+common::solution!(Part1: Puzzle => u64, |puzzle| Ok(process(puzzle)?));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 4_277_556)]
-    #[case::puzzle("part1.txt", 6_957_525_317_641)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("part1.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input = common::read_input!(input_path).parse()?;
         let output = process(input)?;
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
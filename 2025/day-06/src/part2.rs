@@ -56,20 +56,24 @@ pub fn process(puzzle: Puzzle) -> Result<u64> {
         .pipe(|it| Operator::Add.solve(it))
 }
 
+common::solution!(Part2: Puzzle => u64, |puzzle| Ok(process(puzzle)?));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 3_263_827)]
-    #[case::puzzle("part2.txt", 13_215_665_360_076)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("part2.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input = common::read_input!(input_path).parse()?;
         let output = process(input)?;
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
@@ -91,6 +91,19 @@ pub fn process(puzzle: Puzzle) -> (u64, u64) {
     (splits, part_2)
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -114,21 +127,19 @@ pub fn init_tracing() -> color_eyre::Result<()> {
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 21, 40)]
-    #[case::part1("part1.txt", 1672, 231_229_866_702_355)]
-    fn finds_solution(
-        #[case] input_path: &str,
-        #[case] part_1: u64,
-        #[case] part_2: u64,
-    ) -> Result<()> {
-        let input: Puzzle = common::read_input!(input_path).parse()?;
-        let output = process(input);
-        assert_eq!(output, (part_1, part_2));
+    #[case::example("example.txt", "example.txt")]
+    #[case::part1("part1.txt", "part2.txt")]
+    fn finds_solution(#[case] part1_path: &str, #[case] part2_path: &str) -> Result<()> {
+        let input: Puzzle = common::read_input!(part1_path).parse()?;
+        let (part_1, part_2) = process(input);
+        assert_eq!(part_1.to_string(), common::expected!(Part::One, part1_path));
+        assert_eq!(part_2.to_string(), common::expected!(Part::Two, part2_path));
         Ok(())
     }
 }
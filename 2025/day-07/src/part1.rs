@@ -29,6 +29,8 @@ pub fn process(mut puzzle: Puzzle) -> usize {
     res
 }
 
+common::solution!(Part1: Puzzle => usize, process);
+
 // #[cfg(test)]
 // mod tests {
 //     use color_eyre::eyre::Result;
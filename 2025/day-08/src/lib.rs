@@ -1,4 +1,5 @@
-use color_eyre::eyre::{ensure, OptionExt};
+use color_eyre::eyre::ensure;
+use common::parse::{self, Span};
 use glam::I64Vec3 as IVec3;
 use itertools::Itertools;
 use rayon::slice::ParallelSliceMut;
@@ -44,79 +45,37 @@ impl Puzzle {
     }
 }
 
+/// How many of the closest pairs of boxes part 1 connects, which is fewer in the example
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Connections(pub usize);
+
+impl Default for Connections {
+    fn default() -> Self {
+        Self(1000)
+    }
+}
+
 impl std::str::FromStr for Puzzle {
     type Err = color_eyre::Report;
 
     fn from_str(s: &str) -> color_eyre::Result<Self> {
-        let boxes: Vec<_> = s
-            .lines()
-            .map(str::trim)
-            .filter(|s| !s.is_empty())
-            .map(|s| {
-                s.split(',')
-                    .collect_tuple::<(_, _, _)>()
-                    .ok_or_eyre("Wrong number of coordinates")
-            })
-            .map(|res| {
-                res.and_then(|(x, y, z)| {
-                    IVec3 {
-                        x: x.parse()?,
-                        y: y.parse()?,
-                        z: z.parse()?,
-                    }
-                    .pipe(Ok)
-                })
-            })
-            .try_collect()?;
+        let boxes: Vec<_> = parse::lines(s).map(Span::i64vec3).try_collect()?;
         ensure!(boxes.iter().copied().all_unique());
         Self { boxes }.pipe(Ok)
     }
 }
 
-#[derive(Debug)]
-struct Dsu {
-    nodes: Box<[Circuit]>,
-}
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
 
-#[derive(Debug)]
-struct Circuit {
-    parent: usize,
-    size: usize,
-}
-
-impl Dsu {
-    fn new(len: usize) -> Self {
-        Self {
-            nodes: (0..len).map(|parent| Circuit { parent, size: 1 }).collect(),
-        }
-    }
-    fn parent(&mut self, mut x: usize) -> Option<usize> {
-        loop {
-            let parent = self.nodes.get(x)?.parent;
-            if parent == x {
-                break Some(parent);
-            }
-            (x, self.nodes[x].parent) = (parent, self.nodes[parent].parent);
-        }
-    }
-
-    fn unite(&mut self, u: usize, v: usize) -> Option<usize> {
-        let (larger, smaller) = {
-            let (pu, pv) = (self.parent(u)?, self.parent(v)?);
-            if self.nodes[pu].size < self.nodes[pv].size {
-                (pv, pu)
-            } else {
-                (pu, pv)
-            }
-        };
-        if larger == smaller {
-            return Some(self.nodes[u].size);
-        }
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["part1.txt", "part1.txt"];
 
-        self.nodes[smaller].parent = larger;
-        self.nodes[larger].size += self.nodes[smaller].size;
-        Some(self.nodes[larger].size)
-    }
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
 }
 
 pub fn init_tracing() -> color_eyre::Result<()> {
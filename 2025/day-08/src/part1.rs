@@ -1,6 +1,8 @@
+use common::dsu::Dsu;
+use itertools::Itertools;
 use tap::prelude::*;
 
-use crate::{Dsu, Puzzle};
+use crate::Puzzle;
 
 #[must_use]
 #[allow(clippy::needless_pass_by_value)]
@@ -9,34 +11,57 @@ pub fn process((Puzzle { boxes }, by_distance): (Puzzle, Vec<(usize, usize)>)) -
     for &(u, v) in &by_distance {
         dsu.unite(u, v).expect("known valid indicies");
     }
-    dsu.nodes.sort_unstable_by_key(|circuit| circuit.size);
-    dsu.nodes
-        .iter()
-        .rev()
-        .take(3)
-        .map(|circuit| circuit.size)
-        .product()
+    dsu.component_sizes().k_largest(3).product()
+}
+
+/// Part 1 as a `Solution`, for the runner
+pub struct Part1;
+
+impl common::Solution for Part1 {
+    type Input = (Puzzle, Vec<(usize, usize)>);
+    type Params = crate::Connections;
+    type Answer = usize;
+
+    fn prepare(
+        text: &str,
+        connections: &crate::Connections,
+    ) -> Result<Self::Input, common::solution::Error> {
+        let puzzle: Puzzle = text.parse()?;
+        let by_distance = puzzle.n_by_distance(connections.0);
+        Ok((puzzle, by_distance))
+    }
+
+    fn solve(
+        puzzle: Self::Input,
+        _: &crate::Connections,
+    ) -> Result<Self::Answer, common::solution::Error> {
+        Ok(process(puzzle))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use color_eyre::eyre::Result;
-    use rstest::rstest;
+    use common::{runner::Part, Solution};
 
     use super::*;
+    use crate::Connections;
+
+    /// The example only makes 10 connections rather than the puzzle's 1000, so isn't in
+    /// `answers.toml`, which `aoc verify` solves with the puzzle's params
+    #[test]
+    fn test_example() -> Result<(), common::solution::Error> {
+        let output = Part1::run(&common::read_input!("example.txt"), &Connections(10))?;
+        assert_eq!(output, 40);
+        Ok(())
+    }
 
-    #[rstest]
-    #[case::example("example.txt", 10, 40)]
-    #[case::puzzle("part1.txt", 1000, 62_186)]
-    fn finds_solution(
-        #[case] input_path: &str,
-        #[case] n: usize,
-        #[case] expected: usize,
-    ) -> Result<()> {
-        let input: Puzzle = common::read_input!(input_path).parse()?;
-        let by_distance = input.n_by_distance(n);
-        let output = process((input, by_distance));
-        assert_eq!(output, expected);
+    #[test]
+    fn test_actual() -> Result<(), common::solution::Error> {
+        let output = Part1::run(&common::read_input!("part1.txt"), &Connections(1000))?;
+        assert_eq!(
+            output.to_string(),
+            common::expected!(Part::One, "part1.txt")
+        );
         Ok(())
     }
 }
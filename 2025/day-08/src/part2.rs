@@ -1,6 +1,7 @@
+use common::dsu::Dsu;
 use tap::prelude::*;
 
-use crate::{Dsu, Puzzle};
+use crate::Puzzle;
 
 #[must_use]
 #[allow(clippy::needless_pass_by_value)]
@@ -14,21 +15,42 @@ pub fn process((Puzzle { boxes }, by_distance): (Puzzle, Vec<(usize, usize)>)) -
     unreachable!();
 }
 
+/// Part 2 as a `Solution`, for the runner
+pub struct Part2;
+
+impl common::Solution for Part2 {
+    type Input = (Puzzle, Vec<(usize, usize)>);
+    type Params = ();
+    type Answer = i64;
+
+    fn prepare(text: &str, (): &()) -> Result<Self::Input, common::solution::Error> {
+        let puzzle: Puzzle = text.parse()?;
+        let by_distance = puzzle.by_distance();
+        Ok((puzzle, by_distance))
+    }
+
+    fn solve(puzzle: Self::Input, (): &()) -> Result<Self::Answer, common::solution::Error> {
+        Ok(process(puzzle))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 25_272)]
-    #[case::puzzle("part2.txt", 8_420_405_530)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: i64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("part2.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let by_distance = input.by_distance();
         let output = process((input, by_distance));
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
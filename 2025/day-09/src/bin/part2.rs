@@ -4,7 +4,9 @@ use day_09::{init_tracing, part2::process};
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     init_tracing()?;
-    let puzzle = common::read_input!("input.txt").parse()?;
+    let puzzle = common::input::Resolver::from_args(env!("CARGO_MANIFEST_DIR"))
+        .read("input.txt")?
+        .parse()?;
     let output = process(puzzle).ok_or_eyre("No valid solution found")?;
     println!("{output}");
     Ok(())
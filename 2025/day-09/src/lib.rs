@@ -1,7 +1,7 @@
-use color_eyre::eyre::{bail, ensure, OptionExt};
+use color_eyre::eyre::{bail, ensure};
+use common::parse::{self, Span};
 use glam::IVec2;
 use itertools::Itertools;
-use tap::prelude::*;
 
 pub mod part1;
 pub mod part2;
@@ -21,19 +21,7 @@ impl std::str::FromStr for Puzzle {
     type Err = color_eyre::Report;
 
     fn from_str(s: &str) -> color_eyre::Result<Self> {
-        let tiles: Vec<_> = s
-            .lines()
-            .map(str::trim)
-            .filter(|s| !s.is_empty())
-            .map(|s| {
-                let (x, y) = s.split_once(',').ok_or_eyre("Missing ','")?;
-                IVec2 {
-                    x: x.parse()?,
-                    y: y.parse()?,
-                }
-                .pipe(Ok::<_, color_eyre::Report>)
-            })
-            .try_collect()?;
+        let tiles: Vec<_> = parse::lines(s).map(Span::ivec2).try_collect()?;
         ensure!(tiles.len() > 1, "input too short");
         ensure!(
             tiles.iter().all(|tile| tile.x >= 0 && tile.y >= 0),
@@ -52,6 +40,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["input.txt", "input.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -22,7 +22,7 @@ pub fn process(Puzzle { tiles }: Puzzle) -> Option<u64> {
     flood(&mut grid, &shrunk, &tiles);
     // compute summed area table
     for y in 1..grid.size().y {
-        for x in 1..grid.size().y {
+        for x in 1..grid.size().x {
             let point = IVec2::new(x, y);
             let value = !matches!(grid[point].0, TileState::Outside);
             grid[point].1 = u64::from(value) + grid[point - IVec2::Y].1 + grid[point - IVec2::X].1
@@ -161,20 +161,67 @@ impl Shrunk {
     }
 }
 
+common::solution!(Part2: Puzzle => u64, |puzzle| Ok(process(puzzle).ok_or("No valid solution found")?));
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 24)]
-    #[case::puzzle("input.txt", 1_476_550_548)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("input.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, Some(expected));
+        assert_eq!(output.map(|area| area.to_string()), Some(expected));
+        Ok(())
+    }
+
+    /// Whether `point` is on the loop through `tiles`, or inside it
+    fn contains(tiles: &[IVec2], point: IVec2) -> bool {
+        let mut crossings = 0;
+        for (a, b) in tiles.iter().copied().circular_tuple_windows() {
+            let (min, max) = (a.min(b), a.max(b));
+            if point.cmpge(min).all() && point.cmple(max).all() {
+                return true;
+            }
+            if a.x == b.x && a.x > point.x && (min.y..max.y).contains(&point.y) {
+                crossings += 1;
+            }
+        }
+        crossings % 2 == 1
+    }
+
+    /// The summed-area table must cover every column, which is easy to miss when there are more
+    /// distinct xs than ys
+    #[test]
+    fn non_square_matches_brute_force() -> Result<()> {
+        let input: Puzzle = [
+            "1,1", "9,1", "9,0", "11,0", "11,1", "19,1", "19,3", "16,3", "16,5", "12,5", "12,3",
+            "8,3", "8,5", "4,5", "4,3", "1,3",
+        ]
+        .join("\n")
+        .parse()?;
+        let brute_force = input
+            .tiles
+            .iter()
+            .copied()
+            .tuple_combinations()
+            .filter(|&(a, b)| {
+                let (min, max) = (a.min(b), a.max(b));
+                (min.y..=max.y)
+                    .cartesian_product(min.x..=max.x)
+                    .all(|(y, x)| contains(&input.tiles, IVec2::new(x, y)))
+            })
+            .map(|(a, b)| crate::area(a, b))
+            .max();
+        assert_eq!(brute_force, Some(57));
+        assert_eq!(process(input), brute_force);
         Ok(())
     }
 
@@ -1,4 +1,5 @@
 use color_eyre::eyre::{bail, ensure, Context, OptionExt};
+use common::bitset::BitSet;
 use itertools::Itertools;
 use tap::prelude::*;
 
@@ -12,8 +13,8 @@ pub struct Puzzle {
 
 #[derive(Debug)]
 pub struct Machine {
-    lights: u16,
-    buttons: Vec<u16>,
+    lights: BitSet<1>,
+    buttons: Vec<BitSet<1>>,
     joltages: Vec<u16>,
 }
 
@@ -46,27 +47,23 @@ impl std::str::FromStr for Machine {
             .wrap_err_with(|| format!("Failed to parse joltage: {joltages:?}"))?;
         ensure!(goal.len() <= 16, "Buttons too long");
         ensure!(goal.len() == joltages.len());
-        let goal_len: u32 = goal.len().try_conv()?;
 
         Self {
-            lights: goal.as_bytes().iter().try_fold(0u16, |out, &b| {
-                ensure!(matches!(b, b'.' | b'#'), "Invalid light");
-                Ok(out << 1 | (u16::from(b) & 1))
-            })?,
+            lights: goal.as_bytes().iter().positions(|&b| b == b'#').collect(),
             buttons: buttons
                 .split(") (")
                 .map(|button| {
-                    button
-                        .split(',')
-                        .map(u32::from_str)
-                        .try_fold(0u16, |out, res| {
-                            match res.wrap_err("Failed to parse button") {
-                                Ok(bit) if bit < goal_len => (out | (1 << (goal_len - bit - 1)))
-                                    .pipe(Ok::<_, color_eyre::Report>),
-                                Ok(bit) => bail!("Switch too large ({bit})"),
-                                Err(err) => Err(err),
+                    button.split(',').map(usize::from_str).try_fold(
+                        BitSet::new(),
+                        |mut out, res| match res.wrap_err("Failed to parse button") {
+                            Ok(bit) if bit < goal.len() => {
+                                out.insert(bit);
+                                Ok(out)
                             }
-                        })
+                            Ok(bit) => bail!("Switch too large ({bit})"),
+                            Err(err) => Err(err),
+                        },
+                    )
                 })
                 .try_collect()?,
             joltages,
@@ -91,6 +88,19 @@ impl std::str::FromStr for Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["input.txt", "input.txt"];
+
+    type Part1 = part1::Part1;
+    type Part2 = part2::Part2;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
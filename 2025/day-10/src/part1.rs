@@ -1,3 +1,5 @@
+use common::bitset::BitSet;
+
 use crate::{Machine, Puzzle};
 
 #[must_use]
@@ -24,7 +26,7 @@ fn fewest_presses(machine: &Machine) -> Option<u64> {
                         None
                     }
                 })
-                .fold(0, |lights, button| lights ^ button);
+                .fold(BitSet::new(), |lights, button| lights ^ button);
             machine.lights == actual
         })
         .map(u16::count_ones)
@@ -32,20 +34,24 @@ fn fewest_presses(machine: &Machine) -> Option<u64> {
         .map(u64::from)
 }
 
+common::solution!(Part1: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 7)]
-    #[case::puzzle("input.txt", 428)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("input.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::One, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 
@@ -32,8 +32,8 @@ fn fewest_presses(machine: &Machine) -> Result<u64> {
         .iter()
         .enumerate()
         .cartesian_product(0..n_goals)
-        .filter_map(|((j, &button), i)| {
-            if (button >> (n_goals - i - 1)) & 1 != 0 {
+        .filter_map(|((j, button), i)| {
+            if button.contains(i) {
                 (i, j).pipe(Some)
             } else {
                 None
@@ -250,20 +250,24 @@ fn simplex(lhs: &[Vec<f64>]) -> Option<(f64, Vec<f64>)> {
 
 const EPS: f64 = 1e-9;
 
+common::solution!(Part2: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example.txt", 33)]
-    #[case::puzzle("input.txt", 16_613)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example.txt")]
+    #[case::puzzle("input.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert_eq!(output, expected);
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 
@@ -15,15 +15,15 @@ fn parse(bencher: divan::Bencher) {
         .bench_values(|res| res.expect("file to be loaded").parse::<Puzzle>());
 }
 
-
 #[divan::bench]
 fn part1(bencher: divan::Bencher) {
     bencher
-        .with_inputs(|| {
-            common::read_input!("input.txt")
-                .parse::<Puzzle>()
-        })
-        .bench_values(|res| res.expect("parsing to suceed").pipe(divan::black_box).pipe(part1::process));
+        .with_inputs(|| common::read_input!("input.txt").parse::<Puzzle>())
+        .bench_values(|res| {
+            res.expect("parsing to suceed")
+                .pipe(divan::black_box)
+                .pipe(part1::process)
+        });
 }
 
 #[divan::bench]
@@ -34,5 +34,9 @@ fn part2(bencher: divan::Bencher) {
                 .parse::<Puzzle>()
                 .map(divan::black_box)
         })
-        .bench_values(|res| res.expect("parsing to suceed").pipe(divan::black_box).pipe(part2::process));
+        .bench_values(|res| {
+            res.expect("parsing to suceed")
+                .pipe(divan::black_box)
+                .pipe(part2::process)
+        });
 }
@@ -30,21 +30,25 @@ pub fn process(puzzle: Puzzle) -> u64 {
         .product()
 }
 
+common::solution!(Part2: Puzzle => u64, process);
+
 #[cfg(test)]
 mod tests {
     use color_eyre::eyre::Result;
+    use common::runner::Part;
     use rstest::rstest;
 
     use super::*;
 
     #[rstest]
-    #[case::example("example2.txt", 2)]
-    #[case::puzzle("input.txt", 450_854_305_019_580)]
-    fn finds_solution(#[case] input_path: &str, #[case] expected: u64) -> Result<()> {
+    #[case::example("example2.txt")]
+    #[case::puzzle("input.txt")]
+    fn finds_solution(#[case] input_path: &str) -> Result<()> {
+        let expected = common::expected!(Part::Two, input_path);
         let input: Puzzle = common::read_input!(input_path).parse()?;
         let output = process(input);
-        assert!(output > 184 || expected == 2);
-        assert_eq!(output, expected);
+        assert!(output > 184 || input_path == "example2.txt");
+        assert_eq!(output.to_string(), expected);
         Ok(())
     }
 }
@@ -121,6 +121,20 @@ impl Puzzle {
     }
 }
 
+/// The real puzzle, as solved by the `aoc` runner
+pub struct Day;
+
+impl common::runner::Day for Day {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUTS: [&'static str; 2] = ["input.txt", "input.txt"];
+    const PART2: bool = false;
+
+    type Part1 = process::Part1;
+    type Part2 = common::solution::NoPart;
+}
+
 pub fn init_tracing() -> color_eyre::Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, EnvFilter};
 
@@ -140,3 +154,4 @@ pub fn init_tracing() -> color_eyre::Result<()> {
     tracing::subscriber::set_global_default(subscriber)?;
     Ok(())
 }
+
@@ -0,0 +1,28 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Rotations of the safe's dial, some of them more than once round
+pub const GENERATOR: Generator = Generator {
+    day: 1,
+    size: "rotations",
+    sizes: 1..=100_000,
+    default_size: 4000,
+    generate,
+};
+
+fn generate(rng: &mut Rng, rotations: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..rotations {
+        let direction = if rng.chance(1, 2) { 'L' } else { 'R' };
+        let clicks = if rng.chance(1, 10) {
+            rng.range(100..=999)
+        } else {
+            rng.range(1..=99)
+        };
+        let _ = writeln!(text, "{direction}{clicks}");
+    }
+    text
+}
@@ -0,0 +1,38 @@
+use std::collections::BTreeSet;
+
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Ranges of product IDs with up to 10 digits, none of which overlap, on a single line
+pub const GENERATOR: Generator = Generator {
+    day: 2,
+    size: "ranges",
+    sizes: 1..=1000,
+    default_size: 35,
+    generate,
+};
+
+fn generate(rng: &mut Rng, ranges: usize) -> String {
+    let mut starts = BTreeSet::new();
+    while starts.len() < ranges {
+        let digits = rng.range(1..=10);
+        starts.insert(rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1));
+    }
+    let starts = starts.into_iter().collect::<Vec<_>>();
+    let mut ranges = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let digits = start.ilog10() + 1;
+            let width = rng.range(0..=10u64.pow(digits.min(6)));
+            let end = starts
+                .get(i + 1)
+                .map_or(start + width, |&next| (start + width).min(next - 1));
+            format!("{start}-{end}")
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut ranges);
+    format!("{}\n", ranges.iter().join(","))
+}
@@ -0,0 +1,25 @@
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Banks of 100 batteries, each with a joltage from 1 to 9
+pub const GENERATOR: Generator = Generator {
+    day: 3,
+    size: "banks",
+    sizes: 1..=10_000,
+    default_size: 200,
+    generate,
+};
+
+const BATTERIES: usize = 100;
+
+fn generate(rng: &mut Rng, banks: usize) -> String {
+    (0..banks)
+        .flat_map(|_| {
+            (0..BATTERIES)
+                .map(|_| char::from(rng.range(b'1'..=b'9')))
+                .chain(['\n'])
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
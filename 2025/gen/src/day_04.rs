@@ -0,0 +1,19 @@
+use common::{check::Rng, grid::Grid};
+
+use crate::{render, vec2, Generator};
+
+/// A grid with rolls of paper on about two thirds of the tiles
+pub const GENERATOR: Generator = Generator {
+    day: 4,
+    size: "side",
+    sizes: 1..=1000,
+    default_size: 137,
+    generate,
+};
+
+fn generate(rng: &mut Rng, side: usize) -> String {
+    render(&Grid::from_positions(
+        |_| if rng.chance(2, 3) { b'@' } else { b'.' },
+        vec2(side, side),
+    ))
+}
@@ -0,0 +1,44 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Overlapping ranges of fresh ingredient IDs, then five times as many IDs to look up
+pub const GENERATOR: Generator = Generator {
+    day: 5,
+    size: "ranges",
+    sizes: 1..=10_000,
+    default_size: 190,
+    generate,
+};
+
+const MAX_ID: u64 = 560_000_000_000_000;
+
+fn generate(rng: &mut Rng, ranges: usize) -> String {
+    let mut text = String::new();
+    let mut starts = Vec::new();
+    for _ in 0..ranges {
+        let start = if starts.is_empty() || rng.chance(2, 3) {
+            rng.range(1..=MAX_ID)
+        } else {
+            // Near to another range, so that some of them overlap
+            let near: u64 = *rng.choose(&starts);
+            near.saturating_add(rng.range(0..=MAX_ID / 1000))
+        };
+        let end = start.saturating_add(rng.range(0..=MAX_ID / 500));
+        starts.push(start);
+        let _ = writeln!(text, "{start}-{end}");
+    }
+    text.push('\n');
+    for _ in 0..ranges * 5 {
+        let id = if rng.chance(1, 2) {
+            let start = *rng.choose(&starts);
+            start.saturating_add(rng.range(0..=MAX_ID / 500))
+        } else {
+            rng.range(1..=MAX_ID)
+        };
+        let _ = writeln!(text, "{id}");
+    }
+    text
+}
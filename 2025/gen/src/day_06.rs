@@ -0,0 +1,61 @@
+use common::check::Rng;
+
+use crate::Generator;
+
+/// A worksheet of problems side by side, each with four numbers of up to four digits lined up
+/// on the left or the right, and whether to add or multiply them underneath
+pub const GENERATOR: Generator = Generator {
+    day: 6,
+    size: "problems",
+    sizes: 1..=10_000,
+    default_size: 1000,
+    generate,
+};
+
+const ROWS: usize = 4;
+
+fn generate(rng: &mut Rng, problems: usize) -> String {
+    let mut lines = vec![String::new(); ROWS + 1];
+    for problem in 0..problems {
+        let width = rng.range(1..=4);
+        let left = rng.chance(1, 2);
+        // One of the numbers takes up the whole width, and the whole input is trimmed before
+        // it's read, so the first number mustn't start with a space
+        let full = if problem == 0 {
+            0
+        } else {
+            rng.range(0..=ROWS - 1)
+        };
+        for (row, line) in lines.iter_mut().take(ROWS).enumerate() {
+            if problem > 0 {
+                line.push(' ');
+            }
+            let digits = if row == full {
+                width
+            } else {
+                rng.range(1..=width)
+            };
+            let number = rng
+                .range(10u32.pow(digits - 1)..=10u32.pow(digits) - 1)
+                .to_string();
+            let padding = " ".repeat((width - digits) as usize);
+            if left || problem == 0 {
+                line.push_str(&number);
+                line.push_str(&padding);
+            } else {
+                line.push_str(&padding);
+                line.push_str(&number);
+            }
+        }
+        let operators = &mut lines[ROWS];
+        if problem > 0 {
+            operators.push(' ');
+        }
+        operators.push(if rng.chance(1, 2) { '+' } else { '*' });
+        operators.push_str(&" ".repeat(width as usize - 1));
+    }
+    lines
+        .into_iter()
+        .flat_map(|line| [line, "\n".to_owned()])
+        .collect()
+}
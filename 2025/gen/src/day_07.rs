@@ -0,0 +1,31 @@
+use common::{check::Rng, grid::Grid};
+use glam::IVec2;
+
+use crate::{render, vec2, Generator};
+
+/// A tachyon manifold with the start in the middle of the top row, and splitters on every other
+/// row within the triangle a beam can spread out to
+pub const GENERATOR: Generator = Generator {
+    day: 7,
+    size: "width (odd)",
+    sizes: 3..=1001,
+    default_size: 141,
+    generate,
+};
+
+fn generate(rng: &mut Rng, width: usize) -> String {
+    let width = width | 1;
+    let mut grid = Grid::from_value(b'.', vec2(width, width + 1));
+    let start = grid.size().x / 2;
+    grid[IVec2::new(start, 0)] = b'S';
+    for row in 1..=start {
+        // A beam can be up to `row - 1` either side of the start by this row, on every other
+        // tile
+        for x in (start - row + 1..start + row).step_by(2) {
+            if rng.chance(3, 5) {
+                grid[IVec2::new(x, row * 2)] = b'^';
+            }
+        }
+    }
+    render(&grid)
+}
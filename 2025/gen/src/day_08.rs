@@ -0,0 +1,28 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use common::check::Rng;
+
+use crate::Generator;
+
+/// Junction boxes at different positions in a cube 100,000 across. There are enough of them for
+/// the 1000 closest pairs which part 1 connects.
+pub const GENERATOR: Generator = Generator {
+    day: 8,
+    size: "junction boxes",
+    sizes: 46..=5000,
+    default_size: 1000,
+    generate,
+};
+
+fn generate(rng: &mut Rng, boxes: usize) -> String {
+    let mut seen = BTreeSet::new();
+    let mut text = String::new();
+    while seen.len() < boxes {
+        let position = [(); 3].map(|()| rng.range(0..=99_999));
+        if seen.insert(position) {
+            let [x, y, z] = position;
+            let _ = writeln!(text, "{x},{y},{z}");
+        }
+    }
+    text
+}
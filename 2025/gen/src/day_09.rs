@@ -0,0 +1,61 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use common::check::Rng;
+use glam::IVec2;
+
+use crate::Generator;
+
+/// The red tiles at the corners of a loop of red and green tiles
+///
+/// The loop is made of columns side by side, each reaching from a bottom to a top which overlap
+/// those of the columns next to it, so it never crosses itself.
+pub const GENERATOR: Generator = Generator {
+    day: 9,
+    size: "columns",
+    sizes: 1..=10_000,
+    default_size: 124,
+    generate,
+};
+
+const MAX: i32 = 99_999;
+
+fn generate(rng: &mut Rng, columns: usize) -> String {
+    let mut edges = BTreeSet::new();
+    while edges.len() < columns + 1 {
+        edges.insert(rng.range(1..=MAX));
+    }
+    let edges = edges.into_iter().collect::<Vec<_>>();
+    let mut spans = vec![(rng.range(1..=MAX / 2), rng.range(MAX / 2 + 1..=MAX))];
+    while spans.len() < columns {
+        let (bottom, top) = spans[spans.len() - 1];
+        let next_bottom = rng.range(1..=MAX - 1);
+        let next_top = rng.range(next_bottom + 1..=MAX);
+        // Neither end lines up with the last column's, so each corner turns, and the columns
+        // share some of their sides
+        if next_bottom != bottom && next_top != top && next_bottom < top && next_top > bottom {
+            spans.push((next_bottom, next_top));
+        }
+    }
+    let tops = spans
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &(_, top))| [IVec2::new(edges[i], top), IVec2::new(edges[i + 1], top)]);
+    let bottoms = spans
+        .iter()
+        .enumerate()
+        .rev()
+        .flat_map(|(i, &(bottom, _))| {
+            [
+                IVec2::new(edges[i + 1], bottom),
+                IVec2::new(edges[i], bottom),
+            ]
+        });
+    let mut tiles = tops.chain(bottoms).collect::<Vec<_>>();
+    let start = rng.range(0..=tiles.len() - 1);
+    tiles.rotate_left(start);
+    let mut text = String::new();
+    for tile in tiles {
+        let _ = writeln!(text, "{},{}", tile.x, tile.y);
+    }
+    text
+}
@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Machines with up to 10 lights and 13 buttons
+///
+/// The lights are those toggled by pressing some of the buttons once each, and the joltages those
+/// counted up by pressing each button a few times, so that both parts have a solution.
+pub const GENERATOR: Generator = Generator {
+    day: 10,
+    size: "machines",
+    sizes: 1..=10_000,
+    default_size: 180,
+    generate,
+};
+
+fn generate(rng: &mut Rng, machines: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..machines {
+        let lights = rng.range(3..=10);
+        let buttons = (0..rng.range(3..=13))
+            .map(|_| {
+                let mut wired = (0..lights).collect::<Vec<_>>();
+                rng.shuffle(&mut wired);
+                wired.truncate(rng.range(1..=lights - 1));
+                wired.sort_unstable();
+                wired
+            })
+            .collect::<Vec<_>>();
+        let goal = loop {
+            let mut goal = vec![false; lights];
+            for button in buttons.iter().filter(|_| rng.chance(1, 2)) {
+                for &light in button {
+                    goal[light] = !goal[light];
+                }
+            }
+            if goal.contains(&true) {
+                break goal;
+            }
+        };
+        let mut joltages = vec![0u16; lights];
+        for button in &buttons {
+            let presses = rng.range(0..=20);
+            for &light in button {
+                joltages[light] += presses;
+            }
+        }
+        let _ = writeln!(
+            text,
+            "[{}] {} {{{}}}",
+            goal.iter().map(|&on| if on { '#' } else { '.' }).join(""),
+            buttons
+                .iter()
+                .map(|button| format!("({})", button.iter().join(",")))
+                .join(" "),
+            joltages.iter().join(","),
+        );
+    }
+    text
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeSet;
+
+use common::check::Rng;
+use itertools::Itertools;
+
+use crate::Generator;
+
+/// Devices in layers from `svr` down to `out`, with `fft` a few layers before `dac`
+///
+/// Each device has outputs to one to three devices in the next layer and an input from at least
+/// one in the last, so every device is between `svr` and `out`.
+pub const GENERATOR: Generator = Generator {
+    day: 11,
+    size: "devices",
+    sizes: 5..=10_000,
+    default_size: 600,
+    generate,
+};
+
+/// The names the puzzle gives its own meaning to
+const NAMED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+fn generate(rng: &mut Rng, devices: usize) -> String {
+    // Few enough layers that the number of paths fits in a u64
+    let depth = (devices / 20).clamp(2, 30).min(devices - 2);
+    let mut layers = vec![Vec::new(); depth];
+    for (i, name) in names(rng, devices - 2).into_iter().enumerate() {
+        let layer = if i < depth {
+            i
+        } else {
+            rng.range(0..=depth - 1)
+        };
+        layers[layer].push(name);
+    }
+    "fft".clone_into(&mut layers[depth / 3][0]);
+    "dac".clone_into(&mut layers[(depth * 2 / 3).max(depth / 3 + 1)][0]);
+    loop {
+        let layer = rng.range(0..=depth - 1);
+        if let Some(slot) = layers[layer]
+            .iter_mut()
+            .find(|name| !NAMED.contains(&name.as_str()))
+        {
+            "you".clone_into(slot);
+            break;
+        }
+    }
+    layers.insert(0, vec!["svr".to_owned()]);
+    layers.push(vec!["out".to_owned()]);
+
+    let mut lines = Vec::new();
+    for (layer, next) in layers.iter().tuple_windows() {
+        let mut outputs = vec![BTreeSet::new(); layer.len()];
+        // Every device in the next layer has an input
+        for target in 0..next.len() {
+            outputs[rng.range(0..=layer.len() - 1)].insert(target);
+        }
+        for outputs in &mut outputs {
+            let wanted = rng.range(1..=3).min(next.len());
+            while outputs.len() < wanted {
+                outputs.insert(rng.range(0..=next.len() - 1));
+            }
+        }
+        for (device, outputs) in layer.iter().zip(outputs) {
+            let mut outputs = outputs.into_iter().map(|i| &next[i]).collect::<Vec<_>>();
+            rng.shuffle(&mut outputs);
+            lines.push(format!("{device}: {}\n", outputs.iter().join(" ")));
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+/// `count` different three letter names, none of which are `NAMED`
+fn names(rng: &mut Rng, count: usize) -> Vec<String> {
+    let mut names = BTreeSet::new();
+    let mut unique = Vec::new();
+    while unique.len() < count {
+        let name = (0..3)
+            .map(|_| char::from(rng.range(b'a'..=b'z')))
+            .collect::<String>();
+        if !NAMED.contains(&name.as_str()) && names.insert(name.clone()) {
+            unique.push(name);
+        }
+    }
+    unique
+}
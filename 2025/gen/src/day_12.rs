@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use common::{check::Rng, grid::Grid};
+use glam::IVec2;
+use itertools::Itertools;
+
+use crate::{render, Generator};
+
+/// Six presents of five to seven tiles in a 3x3 box, then regions which either have room for
+/// every present in a box of its own, or have fewer tiles than the presents cover between them
+pub const GENERATOR: Generator = Generator {
+    day: 12,
+    size: "regions",
+    sizes: 1..=10_000,
+    default_size: 1000,
+    generate,
+};
+
+fn generate(rng: &mut Rng, regions: usize) -> String {
+    let mut text = String::new();
+    let mut areas = [0; 6];
+    for (i, area) in areas.iter_mut().enumerate() {
+        *area = rng.range(5..=7);
+        let mut tiles = (0..9)
+            .map(|tile| if tile < *area { b'#' } else { b'.' })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut tiles);
+        let present = Grid::from_row_major_ordered(tiles, IVec2::splat(3));
+        let _ = write!(text, "{i}:\n{}\n", render(&present));
+    }
+    for _ in 0..regions {
+        let (width, height) = (rng.range(35..=50), rng.range(35..=50));
+        let mut quantities = [0u8; 6];
+        if rng.chance(1, 2) {
+            for _ in 0..rng.range(0..=(width / 3) * (height / 3)) {
+                quantities[rng.range(0..=5)] += 1;
+            }
+        } else {
+            let mut covered = 0;
+            while covered <= width * height {
+                let present = rng.range(0..=5);
+                quantities[present] += 1;
+                covered += areas[present];
+            }
+        }
+        let _ = writeln!(text, "{width}x{height}: {}", quantities.iter().join(" "));
+    }
+    text
+}
@@ -0,0 +1,121 @@
+pub use common::check::Generator;
+use common::grid::Grid;
+use glam::IVec2;
+use tap::TryConv;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+
+/// Every day's generator, in order
+pub static DAYS: [Generator; 12] = [
+    day_01::GENERATOR,
+    day_02::GENERATOR,
+    day_03::GENERATOR,
+    day_04::GENERATOR,
+    day_05::GENERATOR,
+    day_06::GENERATOR,
+    day_07::GENERATOR,
+    day_08::GENERATOR,
+    day_09::GENERATOR,
+    day_10::GENERATOR,
+    day_11::GENERATOR,
+    day_12::GENERATOR,
+];
+
+/// The generator for `day`
+#[must_use]
+pub fn day(day: u8) -> Option<&'static Generator> {
+    DAYS.iter().find(|generator| generator.day == day)
+}
+
+/// A size as a vector
+pub(crate) fn vec2(x: usize, y: usize) -> IVec2 {
+    IVec2::new(
+        x.try_conv().expect("grid to fit in an IVec2"),
+        y.try_conv().expect("grid to fit in an IVec2"),
+    )
+}
+
+/// A grid as lines of text
+pub(crate) fn render(grid: &Grid<u8>) -> String {
+    format!("{grid}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        runner::{Day, Registered, Solver},
+        solution::Error,
+        Solution,
+    };
+
+    use super::*;
+
+    /// Parses `text` as both parts of `D` would
+    fn parses<D: Day>(text: &str) -> Result<(), Error> {
+        D::Part1::prepare(text, &Default::default())?;
+        D::Part2::prepare(text, &Default::default())?;
+        Ok(())
+    }
+
+    /// How to parse a day's input, and how to solve it
+    type Check = (fn(&str) -> Result<(), Error>, &'static dyn Solver);
+
+    macro_rules! days {
+        ($($day:ident),* $(,)?) => {
+            [$((
+                parses::<::$day::Day> as fn(&str) -> Result<(), Error>,
+                &Registered::<::$day::Day>::new() as &dyn Solver,
+            )),*]
+        };
+    }
+
+    /// Each generator's day, to check it against
+    static CHECKS: [Check; 12] = days![
+        day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11,
+        day_12,
+    ];
+
+    #[test]
+    fn round_trips() {
+        for (generator, (parses, _)) in DAYS.iter().zip(&CHECKS) {
+            for size in [*generator.sizes.start(), generator.default_size] {
+                for seed in 0..3 {
+                    let text = generator.generate(seed, size);
+                    assert_eq!(
+                        generator.generate(seed, size),
+                        text,
+                        "day {}",
+                        generator.day
+                    );
+                    if let Err(err) = parses(&text) {
+                        panic!("day {} size {size} seed {seed}: {err}", generator.day);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solves_small_inputs() {
+        for (generator, (_, solver)) in DAYS.iter().zip(&CHECKS) {
+            assert_eq!(solver.day(), generator.day);
+            let text = generator.generate(0, *generator.sizes.start());
+            for part in solver.parts() {
+                if let Err(err) = solver.run(*part, &text) {
+                    panic!("day {} part {part}: {err}\n{text}", generator.day);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use color_eyre::eyre::{bail, eyre, OptionExt};
+
+const USAGE: &str = "usage: gen <day> [--size <n>] [--seed <n>]";
+
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let mut args = std::env::args().skip(1);
+    let day = args.next().ok_or_eyre(USAGE)?;
+    let generator = gen::day(day.parse()?).ok_or_else(|| eyre!("no generator for day {day}"))?;
+    let (mut size, mut seed) = (generator.default_size, 0);
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| eyre!("{flag} needs a value"))?;
+        match flag.as_str() {
+            "--size" => size = value.parse()?,
+            "--seed" => seed = value.parse()?,
+            _ => bail!("unexpected {flag:?}\n{USAGE}"),
+        }
+    }
+    if !generator.sizes.contains(&size) {
+        bail!(
+            "the {} must be within {:?} for day {day}",
+            generator.size,
+            generator.sizes
+        );
+    }
+    print!("{}", generator.generate(seed, size));
+    Ok(())
+}
@@ -0,0 +1,614 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use common::{
+    answers::{self, Answers, Verdict},
+    bench::{Budget, Report, Row},
+    fetch::Fetcher,
+    input,
+    runner::{Part, RunError, Solver},
+};
+use scaffold::Scaffold;
+
+mod registry;
+mod scaffold;
+
+const USAGE: &str = "\
+usage: aoc run <year> <day> [part] [--input <path>]
+       aoc run <year>
+       aoc run --all
+       aoc verify [year [day [part]]]
+       aoc fetch <year> [day] | --all
+       aoc new <year> <day>
+       aoc bench [year [day [part]]] [--csv] [--save <path>] [--baseline <path>] [--threshold <percent>]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    /// Solve each part with its default input, or the one given
+    Run,
+    /// Solve each part with every input which has an expected answer, and compare them
+    Verify,
+    /// Download each day's real input into the cache, using the session in `AOC_SESSION`
+    Fetch,
+    /// Time every variant of each part, and report how they compare
+    Bench,
+    /// Start a new day from the year's template
+    New,
+}
+
+/// Which days and parts to run, and with what input
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Selection {
+    command: Command,
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    bench: BenchOptions,
+}
+
+/// How `bench` reports its results
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BenchOptions {
+    /// Print CSV rather than a Markdown table
+    csv: bool,
+    /// Where to save the results as CSV, to use as a later baseline
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    /// How much slower than the baseline, in percent, counts as a regression
+    threshold: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, displaydoc::Display, thiserror::Error)]
+enum ArgsError {
+    /// expected a command
+    MissingCommand,
+    /// unknown command {0:?}
+    UnknownCommand(String),
+    /// invalid {what} {found:?}
+    Invalid { what: &'static str, found: String },
+    /// unexpected argument {0:?}
+    Unexpected(String),
+    /// {0} needs a value
+    MissingValue(&'static str),
+    /// --input needs a single day
+    InputWithoutDay,
+    /// --input only works with run
+    InputWithoutRun,
+    /// {0} only works with bench
+    BenchOnly(&'static str),
+    /// expected a year, or --all
+    MissingYear,
+    /// expected a year and a day
+    MissingDay,
+}
+
+impl Selection {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self, ArgsError> {
+        let command = match args.next().as_deref() {
+            Some("run") => Command::Run,
+            Some("verify") => Command::Verify,
+            Some("fetch") => Command::Fetch,
+            Some("bench") => Command::Bench,
+            Some("new") => Command::New,
+            Some(command) => return Err(ArgsError::UnknownCommand(command.to_owned())),
+            None => return Err(ArgsError::MissingCommand),
+        };
+        let mut all = false;
+        let mut input = None;
+        let mut bench = BenchOptions::default();
+        let mut bench_flag = None;
+        let mut positional = Vec::new();
+        while let Some(arg) = args.next() {
+            let mut value = |flag| args.next().ok_or(ArgsError::MissingValue(flag));
+            match arg.as_str() {
+                "--all" => all = true,
+                "--input" => input = Some(value("--input")?.into()),
+                "--csv" => {
+                    bench.csv = true;
+                    bench_flag = Some("--csv");
+                }
+                "--save" => {
+                    bench.save = Some(value("--save")?.into());
+                    bench_flag = Some("--save");
+                }
+                "--baseline" => {
+                    bench.baseline = Some(value("--baseline")?.into());
+                    bench_flag = Some("--baseline");
+                }
+                "--threshold" => {
+                    bench.threshold = parse_arg("threshold", &value("--threshold")?)?;
+                    bench_flag = Some("--threshold");
+                }
+                _ if arg.starts_with("--") => return Err(ArgsError::Unexpected(arg)),
+                _ => positional.push(arg),
+            }
+        }
+        let mut positional = positional.into_iter();
+        let selection = Self {
+            command,
+            year: positional
+                .next()
+                .map(|s| parse_arg("year", &s))
+                .transpose()?,
+            day: positional
+                .next()
+                .map(|s| parse_arg("day", &s))
+                .transpose()?,
+            part: positional
+                .next()
+                .map(|s| match s.as_str() {
+                    "1" => Ok(Part::One),
+                    "2" => Ok(Part::Two),
+                    _ => Err(ArgsError::Invalid {
+                        what: "part",
+                        found: s,
+                    }),
+                })
+                .transpose()?,
+            input,
+            bench,
+        };
+        if let Some(arg) = positional.next() {
+            return Err(ArgsError::Unexpected(arg));
+        }
+        if let (true, Some(year)) = (all, selection.year) {
+            return Err(ArgsError::Unexpected(format!("--all with {year}")));
+        }
+        // Verify checks everything by default, since that is what it is mostly for
+        if matches!(command, Command::Run | Command::Fetch) && !all && selection.year.is_none() {
+            return Err(ArgsError::MissingYear);
+        }
+        if command != Command::Run && selection.input.is_some() {
+            return Err(ArgsError::InputWithoutRun);
+        }
+        if let (Command::Fetch | Command::New, Some(part)) = (command, selection.part) {
+            return Err(ArgsError::Unexpected(part.to_string()));
+        }
+        if command == Command::New && (all || selection.day.is_none()) {
+            return Err(ArgsError::MissingDay);
+        }
+        if let (false, Some(flag)) = (command == Command::Bench, bench_flag) {
+            return Err(ArgsError::BenchOnly(flag));
+        }
+        if selection.input.is_some() && selection.day.is_none() {
+            return Err(ArgsError::InputWithoutDay);
+        }
+        Ok(selection)
+    }
+
+    fn matches(&self, solver: &dyn Solver) -> bool {
+        self.year.is_none_or(|year| year == solver.year())
+            && self.day.is_none_or(|day| day == solver.day())
+    }
+
+    fn parts(&self, solver: &dyn Solver) -> Vec<Part> {
+        self.part
+            .map_or_else(|| solver.parts().to_vec(), |part| vec![part])
+    }
+
+    /// Whether a whole year (or more) was asked for, in which case missing inputs aren't an error
+    const fn is_batch(&self) -> bool {
+        self.day.is_none()
+    }
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            csv: false,
+            save: None,
+            baseline: None,
+            threshold: 10,
+        }
+    }
+}
+
+fn parse_arg<T: std::str::FromStr>(what: &'static str, s: &str) -> Result<T, ArgsError> {
+    s.parse().map_err(|_| ArgsError::Invalid {
+        what,
+        found: s.to_owned(),
+    })
+}
+
+/// Runs each selected part, returning whether any failed
+fn run(selection: &Selection, solvers: &[&dyn Solver]) -> bool {
+    let mut failed = false;
+    for solver in solvers {
+        for part in selection.parts(*solver) {
+            let label = format!("{} day {:02} part {part}", solver.year(), solver.day());
+            let resolver = solver.resolver().with_cli(selection.input.clone());
+            match solver.run_file(part, &resolver, solver.input_file(part)) {
+                Ok(run) => println!(
+                    "{label}: {}  (prepare {:.2?}, solve {:.2?})",
+                    run.answer, run.prepare_time, run.solve_time
+                ),
+                Err(RunError::Input(input::Error::NotFound { tried, .. }))
+                    if selection.is_batch() =>
+                {
+                    println!("{label}: skipped, no input at {tried}");
+                }
+                Err(err) => {
+                    eprintln!("{label}: {err}");
+                    failed = true;
+                }
+            }
+        }
+    }
+    failed
+}
+
+/// How many inputs `verify` gave each verdict
+#[derive(Debug, Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+    skipped: usize,
+}
+
+/// Solves each selected part with its default input and every input it has an expected answer
+/// for, comparing the answers. Returns whether any were wrong or failed to solve.
+fn verify(selection: &Selection, solvers: &[&dyn Solver]) -> bool {
+    let mut years = BTreeMap::new();
+    let mut tally = Tally::default();
+    for solver in solvers {
+        let path = answers::path_for(solver.dir());
+        let answers = match years.get(&path) {
+            Some(answers) => answers,
+            None => match Answers::load(&path) {
+                Ok(answers) => years.entry(path).or_insert(answers),
+                Err(err) => {
+                    eprintln!("{}: {err}", path.display());
+                    return true;
+                }
+            },
+        };
+        for part in selection.parts(*solver) {
+            let inputs = answers
+                .inputs(solver.day(), part)
+                .map(|(input, _)| input)
+                .chain([solver.input_file(part)])
+                .collect::<BTreeSet<_>>();
+            for input in inputs {
+                let label = format!(
+                    "{} day {:02} part {part} ({input})",
+                    solver.year(),
+                    solver.day()
+                );
+                match solver.run_file(part, &solver.resolver(), input) {
+                    Ok(run) => match answers.check(solver.day(), part, input, &run.answer) {
+                        Verdict::Pass => {
+                            tally.passed += 1;
+                            println!("{label}: pass  ({:.2?})", run.solve_time);
+                        }
+                        Verdict::Fail { expected } => {
+                            tally.failed += 1;
+                            println!("{label}: FAIL, got {} but expected {expected}", run.answer);
+                        }
+                        Verdict::Unknown => {
+                            tally.unknown += 1;
+                            println!("{label}: unknown, got {}", run.answer);
+                        }
+                    },
+                    Err(RunError::Input(input::Error::NotFound { .. })) => {
+                        tally.skipped += 1;
+                        println!("{label}: skipped, no input");
+                    }
+                    Err(err) => {
+                        tally.failed += 1;
+                        println!("{label}: FAIL, {err}");
+                    }
+                }
+            }
+        }
+    }
+    let Tally {
+        passed,
+        failed,
+        unknown,
+        skipped,
+    } = tally;
+    println!("\n{passed} passed, {failed} failed, {unknown} unknown, {skipped} skipped");
+    failed > 0
+}
+
+/// Downloads the real input and first example for each selected day into the cache, returning
+/// whether any failed
+fn fetch(solvers: &[&dyn Solver]) -> bool {
+    let fetcher = match Fetcher::from_env() {
+        Ok(fetcher) => fetcher,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return true;
+        }
+    };
+    let mut failed = false;
+    for solver in solvers {
+        let label = format!("{} day {:02}", solver.year(), solver.day());
+        match fetcher.input(solver.year(), solver.day()) {
+            Ok((_, path)) => println!("{label}: {}", path.display()),
+            Err(err) => {
+                eprintln!("{label}: {err}");
+                failed = true;
+            }
+        }
+        match fetcher.example(solver.year(), solver.day()) {
+            Ok(Some((_, path))) => println!("{label}: {}", path.display()),
+            Ok(None) => eprintln!("{label}: no example on the puzzle page"),
+            Err(err) => {
+                eprintln!("{label}: {err}");
+                failed = true;
+            }
+        }
+    }
+    failed
+}
+
+/// Times every variant of each selected part, then prints a report comparing them (and the
+/// baseline, if given). Returns whether any failed, disagreed with the `Solution`, or regressed.
+fn bench(selection: &Selection, solvers: &[&dyn Solver]) -> bool {
+    let options = &selection.bench;
+    let baseline = match &options.baseline {
+        Some(path) => match std::fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|csv| Report::from_csv(&csv).map_err(|err| err.to_string()))
+        {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("{}: {err}", path.display());
+                return true;
+            }
+        },
+        None => None,
+    };
+
+    let budget = Budget::default();
+    let mut report = Report::default();
+    let mut failed = false;
+    for solver in solvers {
+        let variants = solver.variants();
+        for part in selection.parts(*solver) {
+            let label = format!("{} day {:02} part {part}", solver.year(), solver.day());
+            let text = match solver.resolver().read(solver.input_file(part)) {
+                Ok(text) => text,
+                Err(input::Error::NotFound { .. }) => {
+                    eprintln!("{label}: skipped, no input");
+                    continue;
+                }
+                Err(err) => {
+                    eprintln!("{label}: {err}");
+                    failed = true;
+                    continue;
+                }
+            };
+            let mut expected = None;
+            for variant in variants.iter().filter(|variant| variant.part == part) {
+                let label = format!("{label} {}", variant.name);
+                let sampled = match (variant.bench)(&text, &budget) {
+                    Ok(sampled) => sampled,
+                    Err(err) => {
+                        eprintln!("{label}: {err}");
+                        failed = true;
+                        continue;
+                    }
+                };
+                eprintln!("{label}: {:.2?}", sampled.median());
+                // Every variant should agree with the first, which is the part's `Solution`
+                match &expected {
+                    None => expected = Some(sampled.answer.clone()),
+                    Some(expected) if *expected != sampled.answer => {
+                        eprintln!(
+                            "{label}: answered {} rather than {expected}",
+                            sampled.answer
+                        );
+                        failed = true;
+                    }
+                    Some(_) => {}
+                }
+                report.0.push(Row {
+                    year: solver.year(),
+                    day: solver.day(),
+                    part,
+                    variant: variant.name.to_owned(),
+                    median: sampled.median(),
+                    samples: sampled.times.len(),
+                });
+            }
+        }
+    }
+
+    let threshold = f64::from(options.threshold) / 100.0;
+    if options.csv {
+        print!("{}", report.to_csv());
+    } else {
+        print!(
+            "{}",
+            report.to_markdown(baseline.as_ref().map(|baseline| (baseline, threshold)))
+        );
+    }
+    if let Some(path) = &options.save {
+        if let Err(err) = std::fs::write(path, report.to_csv()) {
+            eprintln!("failed to save {}: {err}", path.display());
+            failed = true;
+        }
+    }
+    if let Some(baseline) = &baseline {
+        for regression in report.regressions(baseline, threshold) {
+            let row = regression.row;
+            eprintln!(
+                "regression: {} day {:02} part {} {} took {:.2?}, up {:.1}% from {:.2?}",
+                row.year,
+                row.day,
+                row.part,
+                row.variant,
+                row.median,
+                regression.change * 100.0,
+                regression.baseline
+            );
+            failed = true;
+        }
+    }
+    failed
+}
+
+/// Renders the year's template into a new day crate and registers it everywhere days are listed,
+/// returning whether that failed. A missing example is only warned about, since it can be added
+/// by hand.
+fn new(year: u16, day: u8) -> bool {
+    let scaffold = Scaffold::in_repository(year, day);
+    let registered = scaffold.generate().and_then(|()| scaffold.register());
+    match registered {
+        Ok(changed) => {
+            println!("created {}", scaffold.dir().display());
+            for path in changed {
+                println!("updated {}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            return true;
+        }
+    }
+    match Fetcher::from_env().and_then(|fetcher| scaffold.fetch_example(&fetcher)) {
+        Ok(Some(path)) => println!("saved the example as {}", path.display()),
+        Ok(None) => eprintln!("warning: no example on the puzzle page"),
+        Err(err) => eprintln!("warning: couldn't fetch the example: {err}"),
+    }
+    false
+}
+
+fn main() -> ExitCode {
+    let selection = match Selection::from_args(std::env::args().skip(1)) {
+        Ok(selection) => selection,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    if let (Command::New, Some(year), Some(day)) =
+        (selection.command, selection.year, selection.day)
+    {
+        return if new(year, day) {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+    let solvers = registry::DAYS
+        .iter()
+        .copied()
+        .filter(|solver| selection.matches(*solver))
+        .collect::<Vec<_>>();
+    if solvers.is_empty() {
+        eprintln!("error: no matching days");
+        return ExitCode::from(2);
+    }
+
+    let failed = match selection.command {
+        Command::Run => run(&selection, &solvers),
+        Command::Verify => verify(&selection, &solvers),
+        Command::Fetch => fetch(&solvers),
+        Command::Bench => bench(&selection, &solvers),
+        Command::New => unreachable!("new days are started before the registry is searched"),
+    };
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Selection, ArgsError> {
+        Selection::from_args(args.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(
+            parse("run 2024 16 2 --input in.txt"),
+            Ok(Selection {
+                command: Command::Run,
+                year: Some(2024),
+                day: Some(16),
+                part: Some(Part::Two),
+                input: Some("in.txt".into()),
+                bench: BenchOptions::default(),
+            })
+        );
+        assert_eq!(parse("run --all").map(|s| s.is_batch()), Ok(true));
+        assert_eq!(parse("run 2025").map(|s| s.is_batch()), Ok(true));
+        assert_eq!(parse("run"), Err(ArgsError::MissingYear));
+        assert_eq!(parse("run 2024 --input x"), Err(ArgsError::InputWithoutDay));
+        assert!(matches!(
+            parse("run 2024 1 3"),
+            Err(ArgsError::Invalid { what: "part", .. })
+        ));
+        assert_eq!(
+            parse("verify").map(|s| (s.command, s.year)),
+            Ok((Command::Verify, None))
+        );
+        assert_eq!(
+            parse("verify 2024 --input x"),
+            Err(ArgsError::InputWithoutRun)
+        );
+        assert_eq!(parse("fetch"), Err(ArgsError::MissingYear));
+        assert_eq!(
+            parse("bench 2024 18 --baseline base.csv --threshold 25").map(|s| s.bench),
+            Ok(BenchOptions {
+                baseline: Some("base.csv".into()),
+                threshold: 25,
+                ..BenchOptions::default()
+            })
+        );
+        assert_eq!(parse("run 2024 --csv"), Err(ArgsError::BenchOnly("--csv")));
+        assert_eq!(
+            parse("bench --save"),
+            Err(ArgsError::MissingValue("--save"))
+        );
+        assert_eq!(
+            parse("fetch 2024 1 2"),
+            Err(ArgsError::Unexpected("2".to_owned()))
+        );
+        assert!(matches!(
+            parse("run --all 2024"),
+            Err(ArgsError::Unexpected(_))
+        ));
+        assert_eq!(
+            parse("new 2025 13").map(|s| (s.command, s.year, s.day)),
+            Ok((Command::New, Some(2025), Some(13)))
+        );
+        assert_eq!(parse("new 2025"), Err(ArgsError::MissingDay));
+        assert_eq!(
+            parse("new 2025 13 1"),
+            Err(ArgsError::Unexpected("1".to_owned()))
+        );
+        assert!(matches!(
+            parse("go 2024"),
+            Err(ArgsError::UnknownCommand(_))
+        ));
+    }
+
+    /// Every day `aoc` depends on is registered, once and in order, as `aoc new` leaves them
+    #[test]
+    fn registry_is_complete() {
+        let dependencies = include_str!("../Cargo.toml")
+            .lines()
+            .filter_map(|line| scaffold::day_key(line.strip_prefix('y')?, "-day-"))
+            .collect::<Vec<_>>();
+        let registered = registry::DAYS
+            .iter()
+            .map(|solver| (solver.year(), solver.day()))
+            .collect::<Vec<_>>();
+        assert_eq!(registered, dependencies);
+        assert!(registered.is_sorted() && registered.windows(2).all(|w| w[0] != w[1]));
+        assert!(registered.iter().all(|(_, day)| (1..=25).contains(day)));
+    }
+}
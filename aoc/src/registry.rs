@@ -0,0 +1,42 @@
+use common::runner::{Registered, Solver};
+
+/// Every day which can be run, in chronological order
+pub static DAYS: &[&dyn Solver] = &[
+    &Registered::<y2024_day_01::Day>::new(),
+    &Registered::<y2024_day_02::Day>::new(),
+    &Registered::<y2024_day_03::Day>::new(),
+    &Registered::<y2024_day_04::Day>::new(),
+    &Registered::<y2024_day_05::Day>::new(),
+    &Registered::<y2024_day_06::Day>::new(),
+    &Registered::<y2024_day_07::Day>::new(),
+    &Registered::<y2024_day_08::Day>::new(),
+    &Registered::<y2024_day_09::Day>::new(),
+    &Registered::<y2024_day_10::Day>::new(),
+    &Registered::<y2024_day_11::Day>::new(),
+    &Registered::<y2024_day_12::Day>::new(),
+    &Registered::<y2024_day_13::Day>::new(),
+    &Registered::<y2024_day_14::Day>::new(),
+    &Registered::<y2024_day_15::Day>::new(),
+    &Registered::<y2024_day_16::Day>::new(),
+    &Registered::<y2024_day_17::Day>::new(),
+    &Registered::<y2024_day_18::Day>::new(),
+    &Registered::<y2024_day_19::Day>::new(),
+    &Registered::<y2024_day_20::Day>::new(),
+    &Registered::<y2024_day_21::Day>::new(),
+    &Registered::<y2024_day_22::Day>::new(),
+    &Registered::<y2024_day_23::Day>::new(),
+    &Registered::<y2024_day_24::Day>::new(),
+    &Registered::<y2024_day_25::Day>::new(),
+    &Registered::<y2025_day_01::Day>::new(),
+    &Registered::<y2025_day_02::Day>::new(),
+    &Registered::<y2025_day_03::Day>::new(),
+    &Registered::<y2025_day_04::Day>::new(),
+    &Registered::<y2025_day_05::Day>::new(),
+    &Registered::<y2025_day_06::Day>::new(),
+    &Registered::<y2025_day_07::Day>::new(),
+    &Registered::<y2025_day_08::Day>::new(),
+    &Registered::<y2025_day_09::Day>::new(),
+    &Registered::<y2025_day_10::Day>::new(),
+    &Registered::<y2025_day_11::Day>::new(),
+    &Registered::<y2025_day_12::Day>::new(),
+];
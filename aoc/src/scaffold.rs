@@ -0,0 +1,367 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus},
+};
+
+use common::{answers, fetch::Fetcher};
+
+/// The longest a row of the workspace's `members` is allowed to grow before a new one is started
+const MEMBERS_WIDTH: usize = 80;
+
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// {0:?} already exists
+    Exists(PathBuf),
+    /// no template at {0:?}
+    NoTemplate(PathBuf),
+    /// failed to run `cargo generate` (is cargo-generate installed?): {0}
+    Spawn(std::io::Error),
+    /// `cargo generate` failed: {0}
+    Generate(ExitStatus),
+    /// failed to read {path:?}: {source}
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// failed to write {path:?}: {source}
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// found nowhere to add {0} in {1:?}
+    NoPlace(String, PathBuf),
+}
+
+/// The change to make to a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit {
+    Changed(String),
+    /// It already has what would have been added
+    Unchanged,
+    /// There was nothing like it to add it beside
+    NoPlace,
+}
+
+/// The files which list every day
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    /// The year's workspace
+    Members,
+    /// `aoc`'s manifest
+    Dependencies,
+    Registry,
+    Answers,
+}
+
+/// A new day crate, rendered from its year's `daily_template` and wired into everything which
+/// lists the days
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffold {
+    /// The repository, which each year's workspace is within
+    root: PathBuf,
+    year: u16,
+    day: u8,
+}
+
+impl Scaffold {
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>, year: u16, day: u8) -> Self {
+        Self {
+            root: root.into(),
+            year,
+            day,
+        }
+    }
+
+    /// The repository `aoc` was built in
+    #[must_use]
+    pub fn in_repository(year: u16, day: u8) -> Self {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        Self::new(manifest.parent().unwrap_or(manifest), year, day)
+    }
+
+    /// The crate's package name, such as `day-07`
+    #[must_use]
+    pub fn name(&self) -> String {
+        format!("day-{:02}", self.day)
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> PathBuf {
+        self.year_dir().join(self.name())
+    }
+
+    fn year_dir(&self) -> PathBuf {
+        self.root.join(self.year.to_string())
+    }
+
+    /// Renders the year's template into the new crate with `cargo generate`
+    pub fn generate(&self) -> Result<(), Error> {
+        let template = self.year_dir().join("daily_template");
+        if !template.is_dir() {
+            return Err(Error::NoTemplate(template));
+        }
+        if self.dir().exists() {
+            return Err(Error::Exists(self.dir()));
+        }
+        let status = Command::new("cargo")
+            .arg("generate")
+            .arg("--path")
+            .arg(&template)
+            .arg("--destination")
+            .arg(self.year_dir())
+            .args(["--name", &self.name(), "--vcs", "none", "--silent"])
+            .status()
+            .map_err(Error::Spawn)?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::Generate(status))
+        }
+    }
+
+    /// Adds the crate to its workspace's members, to `aoc`'s dependencies and registry, and to
+    /// the year's answers, returning the files which changed. Anything already there is left be.
+    pub fn register(&self) -> Result<Vec<PathBuf>, Error> {
+        let (year, day) = (self.year, self.day);
+        let mut changed = Vec::new();
+        for (path, edit) in [
+            (self.year_dir().join("Cargo.toml"), Target::Members),
+            (self.root.join("aoc/Cargo.toml"), Target::Dependencies),
+            (self.root.join("aoc/src/registry.rs"), Target::Registry),
+            (self.year_dir().join(answers::FILE), Target::Answers),
+        ] {
+            let text = std::fs::read_to_string(&path).map_err(|source| Error::Read {
+                path: path.clone(),
+                source,
+            })?;
+            let edited = match edit {
+                Target::Members => add_member(&text, day),
+                Target::Dependencies => add_dependency(&text, year, day),
+                Target::Registry => add_registration(&text, year, day),
+                Target::Answers => add_answers(&text, day),
+            };
+            match edited {
+                Edit::Changed(text) => {
+                    std::fs::write(&path, text).map_err(|source| Error::Write {
+                        path: path.clone(),
+                        source,
+                    })?;
+                    changed.push(path);
+                }
+                Edit::Unchanged => {}
+                Edit::NoPlace => return Err(Error::NoPlace(self.name(), path)),
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Saves the first example block on the puzzle page as `inputs/example.txt`, unless there
+    /// already is one, returning where it is
+    pub fn fetch_example<T: common::fetch::Transport>(
+        &self,
+        fetcher: &Fetcher<T>,
+    ) -> Result<Option<PathBuf>, common::fetch::Error> {
+        let path = self.dir().join("inputs/example.txt");
+        if path.exists() {
+            return Ok(Some(path));
+        }
+        let Some((example, _)) = fetcher.example(self.year, self.day)? else {
+            return Ok(None);
+        };
+        let write_error = |source| common::fetch::Error::Write {
+            path: path.clone(),
+            source,
+        };
+        std::fs::create_dir_all(self.dir().join("inputs")).map_err(write_error)?;
+        std::fs::write(&path, example).map_err(write_error)?;
+        Ok(Some(path))
+    }
+}
+
+/// Adds `day-NN` to the end of the workspace's `members`, on the last row of days if it fits
+#[must_use]
+pub fn add_member(manifest: &str, day: u8) -> Edit {
+    let member = format!("\"day-{day:02}\"");
+    let mut lines = manifest.lines().map(str::to_owned).collect::<Vec<_>>();
+    let Some(start) = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members"))
+    else {
+        return Edit::NoPlace;
+    };
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.contains(']'))
+        .map_or(lines.len(), |end| start + end);
+    let members = &lines[start..=end.min(lines.len() - 1)];
+    if members.iter().any(|line| line.contains(&member)) {
+        return Edit::Unchanged;
+    }
+    let Some(last) = members.iter().rposition(|line| line.contains("\"day-")) else {
+        return Edit::NoPlace;
+    };
+    let last = start + last;
+    let row = &mut lines[last];
+    if row.len() + member.len() + 2 <= MEMBERS_WIDTH && row.ends_with(',') {
+        let _ = write!(row, " {member},");
+    } else {
+        let indent = &row[..row.len() - row.trim_start().len()];
+        let new_row = format!("{indent}{member},");
+        lines.insert(last + 1, new_row);
+    }
+    Edit::Changed(joined(&lines, manifest))
+}
+
+/// Adds `yYYYY-day-NN` to `aoc`'s dependencies, beside the other days
+#[must_use]
+pub fn add_dependency(manifest: &str, year: u16, day: u8) -> Edit {
+    insert_in_order(
+        manifest,
+        &format!(
+            "y{year}-{name} = {{ package = \"{name}\", path = \"../{year}/{name}\" }}",
+            name = format_args!("day-{day:02}")
+        ),
+        (year, day),
+        |line| day_key(line.strip_prefix('y')?, "-day-"),
+    )
+}
+
+/// Adds the day to `aoc`'s registry, in chronological order
+#[must_use]
+pub fn add_registration(registry: &str, year: u16, day: u8) -> Edit {
+    insert_in_order(
+        registry,
+        &format!("    &Registered::<y{year}_day_{day:02}::Day>::new(),"),
+        (year, day),
+        |line| day_key(line.trim_start().strip_prefix("&Registered::<y")?, "_day_"),
+    )
+}
+
+/// Adds empty tables for the day's answers to the year's `answers.toml`, to be filled in as
+/// they're found
+#[must_use]
+pub fn add_answers(answers: &str, day: u8) -> Edit {
+    if answers.contains(&format!("[day-{day:02}.")) {
+        return Edit::Unchanged;
+    }
+    let mut answers = answers.to_owned();
+    if !answers.is_empty() && !answers.ends_with("\n\n") {
+        answers.push('\n');
+    }
+    let _ = write!(answers, "[day-{day:02}.part1]\n\n[day-{day:02}.part2]\n");
+    Edit::Changed(answers)
+}
+
+/// Inserts `new` after the last line whose key comes before `key`, or before the first line
+/// with a key if none do
+fn insert_in_order(
+    text: &str,
+    new: &str,
+    key: (u16, u8),
+    key_of: impl Fn(&str) -> Option<(u16, u8)>,
+) -> Edit {
+    let mut lines = text.lines().map(str::to_owned).collect::<Vec<_>>();
+    let keys = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key_of(line)?)))
+        .collect::<Vec<_>>();
+    if keys.iter().any(|&(_, existing)| existing == key) {
+        return Edit::Unchanged;
+    }
+    let at = match keys.iter().rev().find(|&&(_, existing)| existing < key) {
+        Some(&(i, _)) => i + 1,
+        None => match keys.first() {
+            Some(&(i, _)) => i,
+            None => return Edit::NoPlace,
+        },
+    };
+    lines.insert(at, new.to_owned());
+    Edit::Changed(joined(&lines, text))
+}
+
+/// The year and day at the start of `line`, separated by `separator`, such as `2024-day-07`
+pub fn day_key(line: &str, separator: &str) -> Option<(u16, u8)> {
+    let (year, rest) = line.split_at_checked(4)?;
+    let day = rest.strip_prefix(separator)?.get(..2)?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// `lines` joined back together, ending in a newline if `original` did
+fn joined(lines: &[String], original: &str) -> String {
+    let mut text = lines.join("\n");
+    if original.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_members() {
+        let manifest = "[workspace]\n\nmembers = [\n  \"day-01\", \"day-02\",\n  \"gen\"\n]\n";
+        assert_eq!(
+            add_member(manifest, 3),
+            Edit::Changed(
+                "[workspace]\n\nmembers = [\n  \"day-01\", \"day-02\", \"day-03\",\n  \"gen\"\n]\n"
+                    .to_owned()
+            )
+        );
+        assert_eq!(add_member(manifest, 2), Edit::Unchanged);
+        assert_eq!(add_member("[workspace]\n", 2), Edit::NoPlace);
+
+        let full = format!(
+            "members = [\n  {}\n]\n",
+            "\"day-01\", ".repeat(7).trim_end()
+        );
+        let Edit::Changed(wrapped) = add_member(&full, 8) else {
+            panic!("expected day-08 to be added");
+        };
+        assert!(wrapped.ends_with("\"day-01\",\n  \"day-08\",\n]\n"));
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let registry = "pub static DAYS: &[&dyn Solver] = &[\n    \
+            &Registered::<y2024_day_01::Day>::new(),\n    \
+            &Registered::<y2025_day_01::Day>::new(),\n];\n";
+        let Edit::Changed(registry) = add_registration(registry, 2024, 2) else {
+            panic!("expected 2024 day 2 to be added");
+        };
+        assert_eq!(
+            registry
+                .lines()
+                .filter_map(|line| day_key(
+                    line.trim_start().strip_prefix("&Registered::<y")?,
+                    "_day_"
+                ))
+                .collect::<Vec<_>>(),
+            [(2024, 1), (2024, 2), (2025, 1)]
+        );
+        assert_eq!(add_registration(&registry, 2024, 2), Edit::Unchanged);
+
+        let manifest = "[dependencies]\ncommon = { path = \"../common\" }\n\n\
+            y2024-day-01 = { package = \"day-01\", path = \"../2024/day-01\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 2025, 1),
+            Edit::Changed(format!(
+                "{manifest}y2025-day-01 = {{ package = \"day-01\", path = \"../2025/day-01\" }}\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn adds_answers() {
+        let answers = "[day-01.part1]\n\"example.txt\" = 3\n";
+        assert_eq!(
+            add_answers(answers, 2),
+            Edit::Changed(format!("{answers}\n[day-02.part1]\n\n[day-02.part2]\n"))
+        );
+        assert_eq!(add_answers(answers, 1), Edit::Unchanged);
+    }
+}
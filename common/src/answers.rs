@@ -0,0 +1,239 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use tap::Pipe;
+
+use crate::runner::Part;
+
+/// The file each year keeps its expected answers in, beside its day crates
+pub const FILE: &str = "answers.toml";
+
+/// The expected answers for a year, by day, part and input file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, Part), BTreeMap<String, String>>);
+
+/// Whether an answer matches the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There is no expected answer for the input yet
+    Unknown,
+}
+
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// failed to read {path:?}: {source}
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// invalid TOML: {0}
+    Toml(#[from] toml::de::Error),
+    /// invalid entry {key:?}: {reason}
+    Malformed { key: String, reason: &'static str },
+    /// {0:?} isn't a day crate
+    NotADay(String),
+    /// no expected answer for day {day} part {part} with {input:?}
+    Missing { day: u8, part: Part, input: String },
+}
+
+impl Answers {
+    /// Loads the answers at `path`, which are empty if there is no such file
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => text.parse(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(Error::Read {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    /// Loads the answers for the year the day crate in `dir` belongs to
+    pub fn for_day(dir: &Path) -> Result<Self, Error> {
+        Self::load(&path_for(dir))
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.0.get(&(day, part))?.get(input).map(String::as_str)
+    }
+
+    /// Every input with an expected answer for `part` of `day`, along with that answer
+    pub fn inputs(&self, day: u8, part: Part) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .get(&(day, part))
+            .into_iter()
+            .flatten()
+            .map(|(input, answer)| (input.as_str(), answer.as_str()))
+    }
+
+    #[must_use]
+    pub fn check(&self, day: u8, part: Part, input: &str, answer: &str) -> Verdict {
+        match self.get(day, part, input) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in s.parse::<toml::Table>()? {
+            let day = parse_day(&day_key).ok_or_else(|| Error::Malformed {
+                key: day_key.clone(),
+                reason: "expected a day, such as day-01",
+            })?;
+            let toml::Value::Table(parts) = parts else {
+                return Err(Error::Malformed {
+                    key: day_key,
+                    reason: "expected a table of parts",
+                });
+            };
+            for (part_key, inputs) in parts {
+                let key = format!("{day_key}.{part_key}");
+                let part = match part_key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => {
+                        return Err(Error::Malformed {
+                            key,
+                            reason: "expected part1 or part2",
+                        })
+                    }
+                };
+                let toml::Value::Table(inputs) = inputs else {
+                    return Err(Error::Malformed {
+                        key,
+                        reason: "expected a table of inputs",
+                    });
+                };
+                let inputs = inputs
+                    .into_iter()
+                    .map(|(input, answer)| match answer {
+                        toml::Value::String(answer) => Ok((input, answer)),
+                        toml::Value::Integer(answer) => Ok((input, answer.to_string())),
+                        _ => Err(Error::Malformed {
+                            key: format!("{key}.{input}"),
+                            reason: "expected a string or an integer",
+                        }),
+                    })
+                    .collect::<Result<_, _>>()?;
+                answers.insert((day, part), inputs);
+            }
+        }
+        answers.pipe(Self).pipe(Ok)
+    }
+}
+
+/// Where the answers for the year the day crate in `dir` belongs to are kept
+#[must_use]
+pub fn path_for(dir: &Path) -> PathBuf {
+    dir.parent().unwrap_or(dir).join(FILE)
+}
+
+/// The day a crate is for, from its package name (such as `day-17`)
+#[must_use]
+pub fn parse_day(package: &str) -> Option<u8> {
+    package.strip_prefix("day-")?.parse().ok()
+}
+
+/// The expected answer for `part` of the day crate `package` in `dir` with `input`, which
+/// tests reach through `expected!`
+pub fn expected(dir: &str, package: &str, part: Part, input: &str) -> Result<String, Error> {
+    let day = parse_day(package).ok_or_else(|| Error::NotADay(package.to_owned()))?;
+    Answers::for_day(Path::new(dir))?
+        .get(day, part, input)
+        .map(str::to_owned)
+        .ok_or_else(|| Error::Missing {
+            day,
+            part,
+            input: input.to_owned(),
+        })
+}
+
+/// The expected answer for a part of the current day crate with the given input file, read
+/// from its year's `answers.toml`
+#[macro_export]
+macro_rules! expected {
+    ($part:expr, $filename:expr) => {
+        $crate::answers::expected(
+            env!("CARGO_MANIFEST_DIR"),
+            env!("CARGO_PKG_NAME"),
+            $part,
+            $filename,
+        )?
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() -> Result<(), Error> {
+        let answers: Answers = r#"
+            [day-17.part1]
+            "example.txt" = "4,6,3,5,6,3,5,2,1,0"
+
+            [day-17.part2]
+            "part2.txt" = 37_221_274_271_220
+        "#
+        .parse()?;
+        assert_eq!(
+            answers.get(17, Part::Two, "part2.txt"),
+            Some("37221274271220")
+        );
+        assert_eq!(answers.get(17, Part::Two, "example.txt"), None);
+        assert_eq!(answers.inputs(17, Part::One).count(), 1);
+        assert_eq!(answers.inputs(1, Part::One).count(), 0);
+        assert_eq!(
+            answers.check(17, Part::One, "example.txt", "4,6,3,5,6,3,5,2,1,0"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(17, Part::Two, "part2.txt", "1"),
+            Verdict::Fail {
+                expected: "37221274271220".to_owned()
+            }
+        );
+        assert_eq!(
+            answers.check(17, Part::Two, "part1.txt", "1"),
+            Verdict::Unknown
+        );
+        assert!(matches!(
+            "[day-1.part3]\n\"a\" = 1".parse::<Answers>(),
+            Err(Error::Malformed { .. })
+        ));
+        assert!(matches!(
+            "[seventeen.part1]".parse::<Answers>(),
+            Err(Error::Malformed { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn both_years_parse() -> Result<(), Error> {
+        for year in ["2024", "2025"] {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(year)
+                .join(FILE);
+            let answers = Answers::load(&path)?;
+            assert!(answers.inputs(1, Part::One).count() > 0, "{year}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    runner::Part,
+    solution::{Error, Solution},
+};
+
+/// One way of solving a part, timed against the others by `aoc bench`. Days list theirs in
+/// `runner::Day::VARIANTS` with `variants!`, alongside each part's `Solution`.
+#[derive(Clone, Copy)]
+pub struct Variant {
+    pub part: Part,
+    pub name: &'static str,
+    /// Times solving the puzzle text within the budget
+    pub bench: fn(&str, &Budget) -> Result<Sampled, Error>,
+}
+
+/// How long to spend timing each variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Budget {
+    pub min_samples: usize,
+    pub max_samples: usize,
+    /// Sampling stops once this has passed, as long as there are `min_samples`
+    pub time: Duration,
+}
+
+/// The times taken to solve a part, along with the answer given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sampled {
+    pub answer: String,
+    pub times: Vec<Duration>,
+}
+
+/// The median time taken by each variant
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report(pub Vec<Row>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub variant: String,
+    pub median: Duration,
+    pub samples: usize,
+}
+
+/// A variant which has become slower than it was in a baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression<'a> {
+    pub row: &'a Row,
+    pub baseline: Duration,
+    /// The fraction it has slowed down by, such as 0.25 for a quarter
+    pub change: f64,
+}
+
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum ReportError {
+    /// line {line}: expected `year,day,part,variant,median_ns,samples`
+    Malformed { line: usize },
+}
+
+/// The variants for a day, as `Part name: prepare => solve;`. `prepare` turns the puzzle text
+/// into whatever `solve` takes, and only `solve` is timed.
+///
+/// ```ignore
+/// const VARIANTS: &'static [Variant] = common::variants![
+///     Two "astar": str::parse::<Puzzle> => part2::process_astar;
+///     Two "uf": str::parse::<Puzzle> => part2::process_uf;
+/// ];
+/// ```
+#[macro_export]
+macro_rules! variants {
+    ($($part:ident $name:literal: $prepare:expr => $solve:expr;)*) => {
+        &[$($crate::bench::Variant {
+            part: $crate::runner::Part::$part,
+            name: $name,
+            bench: |text, budget| $crate::bench::sample(text, budget, $prepare, $solve),
+        },)*]
+    };
+}
+
+/// Times `solve` on the result of `prepare`, which is run again (untimed) before each sample
+pub fn sample<I, A: Display, E: Into<Error>>(
+    text: &str,
+    budget: &Budget,
+    prepare: impl Fn(&str) -> Result<I, E>,
+    solve: impl Fn(I) -> A,
+) -> Result<Sampled, Error> {
+    sample_fallible(
+        text,
+        budget,
+        |text| prepare(text).map_err(Into::into),
+        |input| Ok(solve(input)),
+    )
+}
+
+/// Times the part solved by `S`, with the real puzzle's parameters
+pub fn sample_solution<S: Solution>(text: &str, budget: &Budget) -> Result<Sampled, Error> {
+    let params = S::Params::default();
+    sample_fallible(
+        text,
+        budget,
+        |text| S::prepare(text, &params),
+        |input| S::solve(input, &params),
+    )
+}
+
+fn sample_fallible<I, A: Display>(
+    text: &str,
+    budget: &Budget,
+    prepare: impl Fn(&str) -> Result<I, Error>,
+    solve: impl Fn(I) -> Result<A, Error>,
+) -> Result<Sampled, Error> {
+    let start = Instant::now();
+    let mut times = Vec::new();
+    let mut answer = None;
+    while times.len() < budget.min_samples
+        || (times.len() < budget.max_samples && start.elapsed() < budget.time)
+    {
+        let input = prepare(text)?;
+        let solving = Instant::now();
+        let solved = std::hint::black_box(solve(std::hint::black_box(input))?);
+        times.push(solving.elapsed());
+        answer = Some(solved);
+    }
+    Ok(Sampled {
+        answer: answer.map(|answer| answer.to_string()).unwrap_or_default(),
+        times,
+    })
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            min_samples: 3,
+            max_samples: 1000,
+            time: Duration::from_millis(500),
+        }
+    }
+}
+
+impl Sampled {
+    #[must_use]
+    pub fn median(&self) -> Duration {
+        let mut times = self.times.clone();
+        times.sort_unstable();
+        times.get(times.len() / 2).copied().unwrap_or_default()
+    }
+}
+
+impl Report {
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("year,day,part,variant,median_ns,samples\n");
+        for row in &self.0 {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                row.year,
+                row.day,
+                row.part,
+                row.variant,
+                row.median.as_nanos(),
+                row.samples
+            );
+        }
+        csv
+    }
+
+    /// Reads a report written by `to_csv`, such as a saved baseline
+    pub fn from_csv(csv: &str) -> Result<Self, ReportError> {
+        csv.lines()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| Row::from_csv(line).ok_or(ReportError::Malformed { line: i + 1 }))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    /// Every variant which is more than `threshold` (as a fraction) slower than in `baseline`
+    #[must_use]
+    pub fn regressions<'a>(&'a self, baseline: &Self, threshold: f64) -> Vec<Regression<'a>> {
+        let baseline = baseline.medians();
+        self.0
+            .iter()
+            .filter_map(|row| {
+                let before = *baseline.get(&row.key())?;
+                let change = change(before, row.median);
+                (change > threshold).then_some(Regression {
+                    row,
+                    baseline: before,
+                    change,
+                })
+            })
+            .collect()
+    }
+
+    /// A table of every variant, with how much slower each is than the fastest for its part
+    /// and, given a baseline, how it has changed since
+    #[must_use]
+    pub fn to_markdown(&self, baseline: Option<(&Self, f64)>) -> String {
+        let fastest = self.0.iter().fold(BTreeMap::new(), |mut fastest, row| {
+            let entry = fastest
+                .entry((row.year, row.day, row.part))
+                .or_insert(row.median);
+            *entry = row.median.min(*entry);
+            fastest
+        });
+        let before = baseline.map(|(baseline, _)| baseline.medians());
+
+        let mut table =
+            String::from("| Year | Day | Part | Variant | Median | Samples | vs fastest |");
+        if before.is_some() {
+            table.push_str(" Baseline | Change |");
+        }
+        table.push_str("\n|---:|---:|---:|---|---:|---:|---:|");
+        if before.is_some() {
+            table.push_str("---:|---|");
+        }
+        table.push('\n');
+        for row in &self.0 {
+            let relative = fastest
+                .get(&(row.year, row.day, row.part))
+                .map_or(1.0, |fastest| ratio(row.median, *fastest));
+            let _ = write!(
+                table,
+                "| {} | {} | {} | {} | {:.2?} | {} | {relative:.2}× |",
+                row.year, row.day, row.part, row.variant, row.median, row.samples
+            );
+            if let (Some(before), Some((_, threshold))) = (&before, baseline) {
+                match before.get(&row.key()) {
+                    Some(before) => {
+                        let change = change(*before, row.median);
+                        let flag = if change > threshold {
+                            " **regressed**"
+                        } else {
+                            ""
+                        };
+                        let _ = write!(table, " {before:.2?} | {:+.1}%{flag} |", change * 100.0);
+                    }
+                    None => table.push_str(" | new |"),
+                }
+            }
+            table.push('\n');
+        }
+        table
+    }
+
+    fn medians(&self) -> BTreeMap<(u16, u8, Part, &str), Duration> {
+        self.0.iter().map(|row| (row.key(), row.median)).collect()
+    }
+}
+
+impl Row {
+    fn from_csv(line: &str) -> Option<Self> {
+        let [year, day, part, variant, median, samples] = line.split(',').collect::<Vec<_>>()[..]
+        else {
+            return None;
+        };
+        Some(Self {
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            part: match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return None,
+            },
+            variant: variant.to_owned(),
+            median: Duration::from_nanos(median.parse().ok()?),
+            samples: samples.parse().ok()?,
+        })
+    }
+
+    fn key(&self) -> (u16, u8, Part, &str) {
+        (self.year, self.day, self.part, &self.variant)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn ratio(a: Duration, b: Duration) -> f64 {
+    a.as_nanos() as f64 / b.as_nanos().max(1) as f64
+}
+
+fn change(before: Duration, after: Duration) -> f64 {
+    ratio(after, before) - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u8, variant: &str, millis: u64) -> Row {
+        Row {
+            year: 2024,
+            day,
+            part: Part::Two,
+            variant: variant.to_owned(),
+            median: Duration::from_millis(millis),
+            samples: 10,
+        }
+    }
+
+    #[test]
+    fn samples_within_budget() -> Result<(), Error> {
+        const VARIANTS: &[Variant] = variants![
+            One "sum": |text: &str| text.split(' ').map(str::parse::<u32>).collect::<Result<Vec<_>, _>>()
+                => |numbers: Vec<u32>| numbers.into_iter().sum::<u32>();
+        ];
+        let budget = Budget {
+            min_samples: 2,
+            max_samples: 5,
+            time: Duration::from_secs(10),
+        };
+        let sampled = (VARIANTS[0].bench)("1 2 3", &budget)?;
+        assert_eq!(sampled.answer, "6");
+        assert_eq!(sampled.times.len(), 5);
+        assert!((VARIANTS[0].bench)("1 x", &budget).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reports() -> Result<(), ReportError> {
+        let baseline = Report(vec![row(18, "astar", 10), row(18, "uf", 10)]);
+        let report = Report(vec![
+            row(18, "astar", 40),
+            row(18, "uf", 10),
+            row(19, "trie", 5),
+        ]);
+        assert_eq!(Report::from_csv(&report.to_csv())?, report);
+        assert!(Report::from_csv("header\n2024,1,3,x,1,1").is_err());
+
+        let regressions = report.regressions(&baseline, 0.1);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].row.variant, "astar");
+        assert!((regressions[0].change - 3.0).abs() < 1e-9);
+
+        let table = report.to_markdown(Some((&baseline, 0.1)));
+        assert!(table.contains(
+            "| 2024 | 18 | 2 | astar | 40.00ms | 10 | 4.00× | 10.00ms | +300.0% **regressed** |"
+        ));
+        assert!(table.contains("| uf | 10.00ms | 10 | 1.00× | 10.00ms | +0.0% |"));
+        assert!(table.contains("| trie | 5.00ms | 10 | 1.00× | | new |"));
+        Ok(())
+    }
+}
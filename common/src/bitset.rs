@@ -0,0 +1,705 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+use glam::IVec2;
+use tap::prelude::*;
+
+use crate::grid::{row_major_index, row_major_position, Grid, Positions};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of `usize`s below `64 * W`, stored inline as `W` words.
+///
+/// The word count is the const parameter (rather than the bit count) so that the storage
+/// size can be named on stable Rust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet<const W: usize>([u64; W]);
+
+/// A set of `usize`s which grows to fit the largest item inserted
+#[derive(Debug, Clone, Default)]
+pub struct BitVec(Vec<u64>);
+
+/// A set of positions within a rectangle, stored as one bit per position.
+/// Mirrors `Grid<bool>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    bits: BitVec,
+    size: IVec2,
+}
+
+/// Iterator over the set bits of a slice of words, in ascending order
+#[derive(Debug, Clone)]
+pub struct Ones<'a> {
+    words: std::iter::Enumerate<std::slice::Iter<'a, u64>>,
+    base: usize,
+    current: u64,
+}
+
+impl<'a> Ones<'a> {
+    fn new(words: &'a [u64]) -> Self {
+        Self {
+            words: words.iter().enumerate(),
+            base: 0,
+            current: 0,
+        }
+    }
+}
+
+impl Iterator for Ones<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            let (idx, word) = self.words.next()?;
+            (self.base, self.current) = (idx * BITS, *word);
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.base + bit)
+    }
+}
+
+#[inline]
+const fn split(idx: usize) -> (usize, u64) {
+    (idx / BITS, 1 << (idx % BITS))
+}
+
+#[inline]
+fn count(words: &[u64]) -> usize {
+    words.iter().map(|w| w.count_ones() as usize).sum()
+}
+
+impl<const W: usize> BitSet<W> {
+    pub const CAPACITY: usize = W * BITS;
+
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self([0; W])
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_words(words: [u64; W]) -> Self {
+        Self(words)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn words(&self) -> &[u64; W] {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn words_mut(&mut self) -> &mut [u64; W] {
+        &mut self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, idx: usize) -> bool {
+        let (word, mask) = split(idx);
+        self.0.get(word).is_some_and(|w| w & mask != 0)
+    }
+
+    /// Adds `idx` to the set, returning whether it was newly inserted.
+    ///
+    /// Panics if `idx` is not below the capacity.
+    #[inline]
+    pub fn insert(&mut self, idx: usize) -> bool {
+        assert!(
+            idx < Self::CAPACITY,
+            "{idx} is out of range for BitSet<{W}>"
+        );
+        let (word, mask) = split(idx);
+        let new = self.0[word] & mask == 0;
+        self.0[word] |= mask;
+        new
+    }
+
+    /// Removes `idx` from the set, returning whether it was present
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> bool {
+        let (word, mask) = split(idx);
+        let Some(w) = self.0.get_mut(word) else {
+            return false;
+        };
+        let present = *w & mask != 0;
+        *w &= !mask;
+        present
+    }
+
+    /// Flips whether `idx` is in the set, returning whether it is now present
+    #[inline]
+    pub fn toggle(&mut self, idx: usize) -> bool {
+        assert!(
+            idx < Self::CAPACITY,
+            "{idx} is out of range for BitSet<{W}>"
+        );
+        let (word, mask) = split(idx);
+        self.0[word] ^= mask;
+        self.0[word] & mask != 0
+    }
+
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.0 = [0; W];
+    }
+
+    /// The number of items in the set (the popcount)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        count(&self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&w| w == 0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> Ones<'_> {
+        Ones::new(&self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn union(mut self, other: &Self) -> Self {
+        self |= other;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersection(mut self, other: &Self) -> Self {
+        self &= other;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn difference(mut self, other: &Self) -> Self {
+        self -= other;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn symmetric_difference(mut self, other: &Self) -> Self {
+        self ^= other;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.iter().zip(&other.0).all(|(a, b)| a & !b == 0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0.iter().zip(&other.0).all(|(a, b)| a & b == 0)
+    }
+}
+
+impl<const W: usize> Default for BitSet<W> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BitVec {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Creates an empty set which can hold items below `bits` without reallocating
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self(vec![0; bits.div_ceil(BITS)])
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_words(words: Vec<u64>) -> Self {
+        Self(words)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn words(&self) -> &[u64] {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn words_mut(&mut self) -> &mut [u64] {
+        &mut self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_words(self) -> Vec<u64> {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, idx: usize) -> bool {
+        let (word, mask) = split(idx);
+        self.0.get(word).is_some_and(|w| w & mask != 0)
+    }
+
+    /// Adds `idx` to the set, growing if needed, and returns whether it was newly inserted
+    #[inline]
+    pub fn insert(&mut self, idx: usize) -> bool {
+        let (word, mask) = split(idx);
+        if word >= self.0.len() {
+            self.0.resize(word + 1, 0);
+        }
+        let new = self.0[word] & mask == 0;
+        self.0[word] |= mask;
+        new
+    }
+
+    /// Removes `idx` from the set, returning whether it was present
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> bool {
+        let (word, mask) = split(idx);
+        let Some(w) = self.0.get_mut(word) else {
+            return false;
+        };
+        let present = *w & mask != 0;
+        *w &= !mask;
+        present
+    }
+
+    /// Flips whether `idx` is in the set, returning whether it is now present
+    #[inline]
+    pub fn toggle(&mut self, idx: usize) -> bool {
+        if self.remove(idx) {
+            false
+        } else {
+            self.insert(idx)
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.fill(0);
+    }
+
+    /// The number of items in the set (the popcount)
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        count(&self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&w| w == 0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> Ones<'_> {
+        Ones::new(&self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn union(mut self, other: &Self) -> Self {
+        self |= other;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersection(mut self, other: &Self) -> Self {
+        self &= other;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn difference(mut self, other: &Self) -> Self {
+        self -= other;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn symmetric_difference(mut self, other: &Self) -> Self {
+        self ^= other;
+        self
+    }
+
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .enumerate()
+            .all(|(idx, a)| a & !other.0.get(idx).copied().unwrap_or(0) == 0)
+    }
+
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0.iter().zip(&other.0).all(|(a, b)| a & b == 0)
+    }
+
+    fn grow_to(&mut self, other: &Self) {
+        if self.0.len() < other.0.len() {
+            self.0.resize(other.0.len(), 0);
+        }
+    }
+}
+
+/// Sets compare equal if they contain the same items, regardless of how many words are allocated
+impl PartialEq for BitVec {
+    fn eq(&self, other: &Self) -> bool {
+        let (short, long) = if self.0.len() <= other.0.len() {
+            (&self.0, &other.0)
+        } else {
+            (&other.0, &self.0)
+        };
+        short.iter().zip(long.iter()).all(|(a, b)| a == b)
+            && long[short.len()..].iter().all(|&w| w == 0)
+    }
+}
+
+impl Eq for BitVec {}
+
+/// Implements the set operators, calling `$grow` first to make room for `rhs`'s words in the
+/// operators which can set bits past the end of `self`
+macro_rules! set_ops {
+    ($set:ident $(<const $w:ident: usize>)? $(; $grow:ident)?) => {
+        impl$(<const $w: usize>)? BitOrAssign<&Self> for $set$(<$w>)? {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: &Self) {
+                $(self.$grow(rhs);)?
+                self.0.iter_mut().zip(rhs.0.iter()).for_each(|(a, b)| *a |= b);
+            }
+        }
+
+        impl$(<const $w: usize>)? BitAndAssign<&Self> for $set$(<$w>)? {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: &Self) {
+                let len = rhs.0.len().min(self.0.len());
+                self.0[len..].iter_mut().for_each(|a| *a = 0);
+                self.0.iter_mut().zip(rhs.0.iter()).for_each(|(a, b)| *a &= b);
+            }
+        }
+
+        impl$(<const $w: usize>)? BitXorAssign<&Self> for $set$(<$w>)? {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: &Self) {
+                $(self.$grow(rhs);)?
+                self.0.iter_mut().zip(rhs.0.iter()).for_each(|(a, b)| *a ^= b);
+            }
+        }
+
+        impl$(<const $w: usize>)? SubAssign<&Self> for $set$(<$w>)? {
+            #[inline]
+            fn sub_assign(&mut self, rhs: &Self) {
+                self.0.iter_mut().zip(rhs.0.iter()).for_each(|(a, b)| *a &= !b);
+            }
+        }
+
+        set_ops!(@by_value $set $(<const $w: usize>)?; BitOr bitor BitOrAssign bitor_assign);
+        set_ops!(@by_value $set $(<const $w: usize>)?; BitAnd bitand BitAndAssign bitand_assign);
+        set_ops!(@by_value $set $(<const $w: usize>)?; BitXor bitxor BitXorAssign bitxor_assign);
+        set_ops!(@by_value $set $(<const $w: usize>)?; Sub sub SubAssign sub_assign);
+
+        impl$(<const $w: usize>)? FromIterator<usize> for $set$(<$w>)? {
+            fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+                let mut set = Self::new();
+                set.extend(iter);
+                set
+            }
+        }
+
+        impl$(<const $w: usize>)? Extend<usize> for $set$(<$w>)? {
+            fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+                for idx in iter {
+                    self.insert(idx);
+                }
+            }
+        }
+
+        impl<'a $(, const $w: usize)?> IntoIterator for &'a $set$(<$w>)? {
+            type Item = usize;
+            type IntoIter = Ones<'a>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+    };
+    (@by_value $set:ident $(<const $w:ident: usize>)?; $op:ident $method:ident $assign:ident $assign_method:ident) => {
+        impl$(<const $w: usize>)? $op<&Self> for $set$(<$w>)? {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, rhs: &Self) -> Self::Output {
+                $assign::<&Self>::$assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl$(<const $w: usize>)? $op for $set$(<$w>)? {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+set_ops!(BitSet<const W: usize>);
+set_ops!(BitVec; grow_to);
+
+impl BitGrid {
+    #[must_use]
+    pub fn new(size: IVec2) -> Self {
+        assert!(size.min_element() >= 0, "Height and width must be positive");
+        Self {
+            #[allow(clippy::cast_sign_loss)]
+            bits: BitVec::with_capacity((size.x as usize) * (size.y as usize)),
+            size,
+        }
+    }
+
+    pub fn from_positions(mut op: impl FnMut(IVec2) -> bool, size: IVec2) -> Self {
+        let mut grid = Self::new(size);
+        for (idx, pos) in grid.positions().enumerate() {
+            if op(pos) {
+                grid.bits.insert(idx);
+            }
+        }
+        grid
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    #[must_use]
+    pub const fn bits(&self) -> &BitVec {
+        &self.bits
+    }
+
+    /// Whether `pos` is set, or `None` if it's outside the grid
+    #[must_use]
+    pub fn get(&self, pos: IVec2) -> Option<bool> {
+        self.index(pos).map(|idx| self.bits.contains(idx))
+    }
+
+    #[must_use]
+    pub fn contains(&self, pos: IVec2) -> bool {
+        self.get(pos).unwrap_or(false)
+    }
+
+    /// Sets `pos` to `value`, returning the previous value, or `None` if it's outside the grid
+    pub fn set(&mut self, pos: IVec2, value: bool) -> Option<bool> {
+        let idx = self.index(pos)?;
+        if value {
+            !self.bits.insert(idx)
+        } else {
+            self.bits.remove(idx)
+        }
+        .pipe(Some)
+    }
+
+    /// Sets `pos`, returning whether it was newly set. Positions outside the grid are never set.
+    pub fn insert(&mut self, pos: IVec2) -> bool {
+        self.set(pos, true) == Some(false)
+    }
+
+    pub fn remove(&mut self, pos: IVec2) -> bool {
+        self.set(pos, false) == Some(true)
+    }
+
+    /// The number of set positions
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    #[must_use]
+    pub const fn positions(&self) -> Positions {
+        Positions::new(self.size)
+    }
+
+    /// The set positions, in row major order
+    pub fn iter(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.bits
+            .iter()
+            .map(|idx| row_major_position(self.size, idx))
+    }
+
+    #[must_use]
+    pub fn union(mut self, other: &Self) -> Self {
+        assert_eq!(self.size, other.size, "Grids must be the same size");
+        self.bits |= &other.bits;
+        self
+    }
+
+    #[must_use]
+    pub fn intersection(mut self, other: &Self) -> Self {
+        assert_eq!(self.size, other.size, "Grids must be the same size");
+        self.bits &= &other.bits;
+        self
+    }
+
+    #[must_use]
+    pub fn difference(mut self, other: &Self) -> Self {
+        assert_eq!(self.size, other.size, "Grids must be the same size");
+        self.bits -= &other.bits;
+        self
+    }
+
+    #[must_use]
+    pub fn symmetric_difference(mut self, other: &Self) -> Self {
+        assert_eq!(self.size, other.size, "Grids must be the same size");
+        self.bits ^= &other.bits;
+        self
+    }
+
+    const fn index(&self, pos: IVec2) -> Option<usize> {
+        row_major_index(self.size, pos)
+    }
+}
+
+macro_rules! grid_assign_ops {
+    ($($trait:ident $method:ident),*) => {$(
+        impl $trait<&Self> for BitGrid {
+            fn $method(&mut self, rhs: &Self) {
+                assert_eq!(self.size, rhs.size, "Grids must be the same size");
+                self.bits.$method(&rhs.bits);
+            }
+        }
+    )*};
+}
+
+grid_assign_ops!(
+    BitOrAssign bitor_assign,
+    BitAndAssign bitand_assign,
+    BitXorAssign bitxor_assign,
+    SubAssign sub_assign
+);
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        Self::from_positions(|pos| grid[pos], grid.size())
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(grid: &BitGrid) -> Self {
+        Self::from_positions(|pos| grid.contains(pos), grid.size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn bitset_basics() {
+        let mut set = BitSet::<2>::new();
+        assert!(set.is_empty());
+        assert!(set.insert(3));
+        assert!(set.insert(64));
+        assert!(set.insert(127));
+        assert!(!set.insert(3));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(64));
+        assert!(!set.contains(65));
+        assert!(!set.contains(1_000));
+        assert!(set.remove(64));
+        assert!(!set.toggle(3));
+        assert_eq!(set.iter().collect_vec(), [127]);
+        assert_eq!(set.words(), &[0, 1 << 63]);
+    }
+
+    #[test]
+    fn bitset_algebra() {
+        let a: BitSet<1> = [1, 2, 3, 5].into_iter().collect();
+        let b: BitSet<1> = [2, 3, 4].into_iter().collect();
+        assert_eq!((a | b).iter().collect_vec(), [1, 2, 3, 4, 5]);
+        assert_eq!((a & b).iter().collect_vec(), [2, 3]);
+        assert_eq!((a - b).iter().collect_vec(), [1, 5]);
+        assert_eq!((a ^ b).iter().collect_vec(), [1, 4, 5]);
+        assert_eq!(a.union(&b), a | b);
+        assert!((a & b).is_subset(&a));
+        assert!((a - b).is_disjoint(&b));
+    }
+
+    #[test]
+    fn bitvec_grows() {
+        let mut a = BitVec::new();
+        assert!(a.insert(200));
+        assert!(a.insert(1));
+        let b: BitVec = [1, 2].into_iter().collect();
+        assert_eq!((a.clone() | &b).iter().collect_vec(), [1, 2, 200]);
+        assert_eq!((a.clone() & &b).iter().collect_vec(), [1]);
+        assert_eq!((a.clone() ^ &b).iter().collect_vec(), [2, 200]);
+        assert_eq!((a.clone() - &b).iter().collect_vec(), [200]);
+        assert!(a.remove(200));
+        assert_eq!(a, std::iter::once(1).collect());
+        assert!(a.is_subset(&b));
+    }
+
+    #[test]
+    fn bitgrid_mirrors_grid() -> Result<(), crate::grid::Error> {
+        let grid = "#..\n.#.\n..#\n#..".parse::<Grid<u8>>()?.map(|b| b == b'#');
+        let mut bits = BitGrid::from(&grid);
+        assert_eq!(bits.len(), 4);
+        assert_eq!(Grid::from(&bits), grid);
+        assert_eq!(
+            bits.iter().collect_vec(),
+            [
+                IVec2::new(0, 0),
+                IVec2::new(1, 1),
+                IVec2::new(2, 2),
+                IVec2::new(0, 3)
+            ]
+        );
+        assert_eq!(bits.get(IVec2::new(3, 0)), None);
+        assert!(!bits.insert(IVec2::new(-1, 0)));
+        assert!(bits.insert(IVec2::new(2, 0)));
+        assert!(bits.remove(IVec2::ZERO));
+        let diag = BitGrid::from_positions(|p| p.x == p.y, grid.size());
+        assert_eq!(bits.clone().intersection(&diag).len(), 2);
+        assert_eq!(bits.difference(&diag).len(), 2);
+        Ok(())
+    }
+}
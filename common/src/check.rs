@@ -0,0 +1,602 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::RangeInclusive,
+    panic::AssertUnwindSafe,
+};
+
+use glam::IVec2;
+use tap::Pipe;
+
+/// The environment variable to replay a failing check with, set to the seed it reported
+pub const SEED_ENV: &str = "AOC_CHECK_SEED";
+
+/// The environment variable overriding how many random inputs each check tries
+pub const CASES_ENV: &str = "AOC_CHECK_CASES";
+
+/// A small seedable pseudo-random number generator (`SplitMix64`), so failures can be replayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng(u64);
+
+/// Random values for a check, and simpler values to try in place of one the variants disagree on
+pub trait Gen {
+    type Value: Clone;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Simpler values than `value`, most promising first. Each must still be a valid input.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/// How thoroughly to check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub cases: usize,
+    /// The seed for the first case, each of the others using the next
+    pub seed: u64,
+    /// How many times to shrink a disagreement before reporting it
+    pub max_shrinks: usize,
+}
+
+/// One way of solving a puzzle, by name
+pub type Variant<V, A> = (&'static str, fn(&V) -> A);
+
+/// What a variant gave for an input: its answer, or what it panicked with
+pub type Outcome<A> = Result<A, String>;
+
+/// The simplest input found which the variants didn't all give the same answer for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement<V, A> {
+    /// The seed the input was generated from, before shrinking
+    pub seed: u64,
+    pub input: V,
+    pub outcomes: Vec<(&'static str, Outcome<A>)>,
+    /// How many times the generated input was shrunk to reach `input`
+    pub shrinks: usize,
+}
+
+/// Writes random puzzle inputs in one day's format, for each year's `gen` crate
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub day: u8,
+    /// What the size controls, such as "side" for a square grid
+    pub size: &'static str,
+    pub sizes: RangeInclusive<usize>,
+    /// About the size of the real inputs
+    pub default_size: usize,
+    /// Writes an input of the given size, which must be within `sizes`
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Rng {
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub const fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number within `range`, which must not be empty
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryInto<i128> + TryFrom<i128>,
+    {
+        let (start, end) = (wide(*range.start()), wide(*range.end()));
+        assert!(start <= end, "empty range");
+        let span = u128::try_from(end - start + 1).expect("span to be positive");
+        let offset = i128::try_from(u128::from(self.next_u64()) % span)
+            .expect("offset to be within the range");
+        narrow(start + offset)
+    }
+
+    /// Whether an event with a chance of `numerator` in `denominator` happened
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(1..=denominator) <= numerator
+    }
+
+    /// One of `items`, which must not be empty
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    /// Shuffles `items` in place
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+impl Config {
+    /// `cases` inputs from seed 0, either of which `$AOC_CHECK_CASES` and `$AOC_CHECK_SEED`
+    /// override
+    #[must_use]
+    pub fn new(cases: usize) -> Self {
+        let env = |name| std::env::var(name).ok()?.parse().ok();
+        Self {
+            cases: env(CASES_ENV).map_or(cases, |cases: u64| {
+                usize::try_from(cases).unwrap_or(usize::MAX)
+            }),
+            seed: env(SEED_ENV).unwrap_or(0),
+            max_shrinks: 1000,
+        }
+    }
+
+    /// Runs every variant on random inputs from `gen`, checking that none of them panic and
+    /// that they all give the same answer. The first input they don't agree on is shrunk as far
+    /// as it will go while they still disagree.
+    pub fn agree<G: Gen, A: PartialEq>(
+        &self,
+        gen: &G,
+        variants: &[Variant<G::Value, A>],
+    ) -> Result<(), Disagreement<G::Value, A>> {
+        for case in 0..self.cases as u64 {
+            let seed = self.seed.wrapping_add(case);
+            let input = gen.generate(&mut Rng::new(seed));
+            let outcomes = run(variants, &input);
+            if !agreed(&outcomes) {
+                return Err(self.shrink(gen, variants, seed, input, outcomes));
+            }
+        }
+        Ok(())
+    }
+
+    fn shrink<G: Gen, A: PartialEq>(
+        &self,
+        gen: &G,
+        variants: &[Variant<G::Value, A>],
+        seed: u64,
+        mut input: G::Value,
+        mut outcomes: Vec<(&'static str, Outcome<A>)>,
+    ) -> Disagreement<G::Value, A> {
+        let mut shrinks = 0;
+        'shrinking: while shrinks < self.max_shrinks {
+            for candidate in gen.shrink(&input) {
+                let candidate_outcomes = run(variants, &candidate);
+                if !agreed(&candidate_outcomes) {
+                    input = candidate;
+                    outcomes = candidate_outcomes;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+        Disagreement {
+            seed,
+            input,
+            outcomes,
+            shrinks,
+        }
+    }
+}
+
+impl Generator {
+    /// An input of `size` from `seed`
+    #[must_use]
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+/// `Config::agree` with the default config
+pub fn agree<G: Gen, A: PartialEq>(
+    gen: &G,
+    variants: &[Variant<G::Value, A>],
+) -> Result<(), Disagreement<G::Value, A>> {
+    Config::default().agree(gen, variants)
+}
+
+fn run<V, A>(variants: &[Variant<V, A>], input: &V) -> Vec<(&'static str, Outcome<A>)> {
+    variants
+        .iter()
+        .map(|&(name, variant)| {
+            let outcome =
+                std::panic::catch_unwind(AssertUnwindSafe(|| variant(input))).map_err(|payload| {
+                    payload
+                        .downcast_ref::<&str>()
+                        .map(|message| (*message).to_owned())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_default()
+                });
+            (name, outcome)
+        })
+        .collect()
+}
+
+fn agreed<A: PartialEq>(outcomes: &[(&'static str, Outcome<A>)]) -> bool {
+    match outcomes.first() {
+        Some((_, Ok(first))) => outcomes
+            .iter()
+            .all(|(_, outcome)| outcome.as_ref() == Ok(first)),
+        Some((_, Err(_))) => false,
+        None => true,
+    }
+}
+
+impl<V: Display, A: Debug> Display for Disagreement<V, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "variants disagree on this input (seed {}, shrunk {} times):",
+            self.seed, self.shrinks
+        )?;
+        writeln!(f, "{}", self.input)?;
+        for (name, outcome) in &self.outcomes {
+            match outcome {
+                Ok(answer) => writeln!(f, "  {name}: {answer:?}")?,
+                Err(message) => writeln!(f, "  {name}: panicked: {message}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<V: Debug + Display, A: Debug> std::error::Error for Disagreement<V, A> {}
+
+/// Simpler versions of a list of at least `min_len` items: without large chunks of it, then
+/// without each item, then with each item replaced by its own shrinks
+pub fn shrink_vec<T: Clone>(
+    items: &[T],
+    min_len: usize,
+    shrink_item: impl Fn(&T) -> Vec<T>,
+) -> Vec<Vec<T>> {
+    let mut shrunk = Vec::new();
+    let mut chunk = items.len() / 2;
+    while chunk > 0 {
+        if items.len() - chunk >= min_len {
+            for start in (0..=items.len() - chunk).step_by(chunk) {
+                shrunk.push([&items[..start], &items[start + chunk..]].concat());
+            }
+        }
+        chunk /= 2;
+    }
+    if items.len() > min_len && items.len() > 1 {
+        for i in 0..items.len() {
+            shrunk.push([&items[..i], &items[i + 1..]].concat());
+        }
+    }
+    for (i, item) in items.iter().enumerate() {
+        for replacement in shrink_item(item) {
+            let mut items = items.to_vec();
+            items[i] = replacement;
+            shrunk.push(items);
+        }
+    }
+    shrunk
+}
+
+/// The numbers between `target` and `value` (excluding `value`), closest to `target` first
+pub fn shrink_int<T>(value: T, target: T) -> Vec<T>
+where
+    T: Copy + TryInto<i128> + TryFrom<i128>,
+{
+    let (value, target) = (wide(value), wide(target));
+    let mut shrunk = Vec::new();
+    let mut diff = value - target;
+    while diff != 0 {
+        shrunk.push(narrow(value - diff));
+        diff /= 2;
+    }
+    shrunk
+}
+
+fn wide<T: TryInto<i128>>(n: T) -> i128 {
+    n.try_into()
+        .unwrap_or_else(|_| unreachable!("every integer type to fit in an i128"))
+}
+
+fn narrow<T: TryFrom<i128>>(n: i128) -> T {
+    T::try_from(n).unwrap_or_else(|_| unreachable!("{n} to be between two values of the type"))
+}
+
+/// A disk map like those in 2024 day 9: each file's length (1 to 9 blocks) alternating with the
+/// gap after it (0 to 9 blocks)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap(pub Vec<(u8, u8)>);
+
+/// Disk maps with a number of files in the range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMaps(pub RangeInclusive<usize>);
+
+impl Gen for DiskMaps {
+    type Value = DiskMap;
+
+    fn generate(&self, rng: &mut Rng) -> DiskMap {
+        (0..rng.range(self.0.clone()))
+            .map(|_| (rng.range(1..=9), rng.range(0..=9)))
+            .collect::<Vec<_>>()
+            .pipe(DiskMap)
+    }
+
+    fn shrink(&self, map: &DiskMap) -> Vec<DiskMap> {
+        shrink_vec(&map.0, *self.0.start(), |&(file, gap)| {
+            let files = shrink_int(file, 1).into_iter().map(|file| (file, gap));
+            let gaps = shrink_int(gap, 0).into_iter().map(|gap| (file, gap));
+            files.chain(gaps).collect()
+        })
+        .into_iter()
+        .map(DiskMap)
+        .collect()
+    }
+}
+
+impl Display for DiskMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, &(file, gap)) in self.0.iter().enumerate() {
+            write!(f, "{file}")?;
+            if i + 1 < self.0.len() {
+                write!(f, "{gap}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Robots patrolling an area like those in 2024 day 14, which at `time` are all on different
+/// tiles, drawing a picture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sky {
+    pub size: IVec2,
+    pub time: i32,
+    /// Each robot's position in the picture, and its velocity
+    pub picture: Vec<(IVec2, IVec2)>,
+}
+
+/// Skies of the given size, with a number of robots in the range whose picture fits in a
+/// `picture`-sized box, drawn some time after the start and before the robots are all back where
+/// they started
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Skies {
+    pub size: IVec2,
+    pub robots: RangeInclusive<usize>,
+    pub picture: IVec2,
+}
+
+impl Sky {
+    /// Each robot's starting position and velocity
+    pub fn robots(&self) -> impl Iterator<Item = (IVec2, IVec2)> + '_ {
+        self.picture.iter().map(|&(position, velocity)| {
+            (
+                (position - velocity * self.time).rem_euclid(self.size),
+                velocity,
+            )
+        })
+    }
+}
+
+impl Gen for Skies {
+    type Value = Sky;
+
+    fn generate(&self, rng: &mut Rng) -> Sky {
+        let corner = IVec2::new(
+            rng.range(0..=self.size.x - self.picture.x),
+            rng.range(0..=self.size.y - self.picture.y),
+        );
+        let mut tiles = (0..self.picture.x)
+            .flat_map(|x| (0..self.picture.y).map(move |y| corner + IVec2::new(x, y)))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut tiles);
+        tiles.truncate(rng.range(self.robots.clone()));
+        Sky {
+            size: self.size,
+            time: rng.range(1..=self.size.x * self.size.y - 1),
+            picture: tiles
+                .into_iter()
+                .map(|tile| {
+                    let velocity = IVec2::new(
+                        rng.range(-self.size.x..=self.size.x),
+                        rng.range(-self.size.y..=self.size.y),
+                    );
+                    (tile, velocity)
+                })
+                .collect(),
+        }
+    }
+
+    fn shrink(&self, sky: &Sky) -> Vec<Sky> {
+        let with_picture = |picture| Sky {
+            picture,
+            ..sky.clone()
+        };
+        shrink_vec(&sky.picture, 1, |&(tile, velocity)| {
+            let xs = shrink_int(velocity.x, 0)
+                .into_iter()
+                .map(|x| IVec2 { x, ..velocity });
+            let ys = shrink_int(velocity.y, 0)
+                .into_iter()
+                .map(|y| IVec2 { y, ..velocity });
+            xs.chain(ys).map(|velocity| (tile, velocity)).collect()
+        })
+        .into_iter()
+        .map(with_picture)
+        .chain(shrink_int(sky.time, 1).into_iter().map(|time| Sky {
+            time,
+            ..sky.clone()
+        }))
+        .collect()
+    }
+}
+
+impl Display for Sky {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (position, velocity) in self.robots() {
+            writeln!(
+                f,
+                "p={},{} v={},{}",
+                position.x, position.y, velocity.x, velocity.y
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The codes for the doors in 2024 day 21: three digits followed by `A`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Codes(pub Vec<[u8; 3]>);
+
+/// Lists of codes, with a number of codes in the range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeypadCodes(pub RangeInclusive<usize>);
+
+impl Gen for KeypadCodes {
+    type Value = Codes;
+
+    fn generate(&self, rng: &mut Rng) -> Codes {
+        (0..rng.range(self.0.clone()))
+            .map(|_| [rng.range(0..=9), rng.range(0..=9), rng.range(0..=9)])
+            .collect::<Vec<_>>()
+            .pipe(Codes)
+    }
+
+    fn shrink(&self, codes: &Codes) -> Vec<Codes> {
+        shrink_vec(&codes.0, *self.0.start(), |code| {
+            (0..3)
+                .flat_map(|i| {
+                    shrink_int(code[i], 0).into_iter().map(move |digit| {
+                        let mut code = *code;
+                        code[i] = digit;
+                        code
+                    })
+                })
+                .collect()
+        })
+        .into_iter()
+        .map(Codes)
+        .collect()
+    }
+}
+
+impl Display for Codes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for [a, b, c] in &self.0 {
+            writeln!(f, "{a}{b}{c}A")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Lists of digits
+    struct Digits;
+
+    impl Gen for Digits {
+        type Value = Vec<u8>;
+
+        fn generate(&self, rng: &mut Rng) -> Vec<u8> {
+            (0..rng.range(0..=20)).map(|_| rng.range(0..=9)).collect()
+        }
+
+        fn shrink(&self, digits: &Vec<u8>) -> Vec<Vec<u8>> {
+            shrink_vec(digits, 0, |&digit| shrink_int(digit, 0))
+        }
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+            assert_eq!(rng.range(5u8..=5), 5);
+        }
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+        assert!(!rng.chance(0, 3) && rng.chance(3, 3));
+        assert_eq!(rng.choose(&["only"]), &"only");
+        assert_eq!(shrink_int(10, 0), [0, 5, 8, 9]);
+        assert_eq!(shrink_int(-3, 1), [1, -1, -2]);
+        assert!(shrink_int(4, 4).is_empty());
+    }
+
+    #[test]
+    fn shrinks_disagreements() {
+        let config = Config {
+            cases: 100,
+            seed: 0,
+            max_shrinks: 1000,
+        };
+        let sum = |digits: &Vec<u8>| digits.iter().map(|&d| u32::from(d)).sum::<u32>();
+        assert_eq!(
+            config.agree(&Digits, &[("sum", sum), ("sum again", sum)]),
+            Ok(())
+        );
+
+        // wrong whenever there's a digit over 6, so the simplest counter-example is `[7]`
+        let Err(disagreement) = config.agree(
+            &Digits,
+            &[
+                ("sum", sum),
+                ("capped", |digits| {
+                    digits.iter().map(|&d| u32::from(d.min(6))).sum()
+                }),
+            ],
+        ) else {
+            panic!("expected a disagreement");
+        };
+        assert_eq!(disagreement.input, [7]);
+        assert_eq!(disagreement.outcomes, [("sum", Ok(7)), ("capped", Ok(6))]);
+
+        let Err(disagreement) = config.agree(
+            &Digits,
+            &[
+                ("sum", sum),
+                ("panics", |digits| {
+                    assert!(digits.len() < 3, "too long");
+                    digits.iter().map(|&d| u32::from(d)).sum()
+                }),
+            ],
+        ) else {
+            panic!("expected a disagreement");
+        };
+        assert_eq!(disagreement.input, [0, 0, 0]);
+        assert_eq!(
+            disagreement.outcomes[1],
+            ("panics", Err("too long".to_owned()))
+        );
+    }
+
+    #[test]
+    fn generators() {
+        let mut rng = Rng::new(1);
+        let map = DiskMaps(1..=5).generate(&mut rng);
+        assert!((1..=9).contains(&map.to_string().len()));
+        assert!(DiskMaps(1..=5)
+            .shrink(&map)
+            .iter()
+            .all(|map| !map.0.is_empty() && map.0.iter().all(|&(file, _)| file > 0)));
+
+        let skies = Skies {
+            size: IVec2::new(11, 7),
+            robots: 5..=9,
+            picture: IVec2::new(3, 3),
+        };
+        for sky in std::iter::once(skies.generate(&mut rng)).flat_map(|sky| skies.shrink(&sky)) {
+            let drawn = sky
+                .robots()
+                .map(|(start, velocity)| (start + velocity * sky.time).rem_euclid(sky.size))
+                .collect::<HashSet<_>>();
+            assert_eq!(drawn.len(), sky.picture.len());
+            assert!((1..77).contains(&sky.time));
+        }
+
+        assert_eq!(
+            Codes(vec![[0, 2, 9], [9, 8, 0]]).to_string(),
+            "029A\n980A\n"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use glam::IVec2;
+use itertools::Itertools;
+
+use crate::grid::{row_major_index, row_major_position};
+
+/// A disjoint set union (union-find) over the ids `0..len`, with path compression and union by size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dsu {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+/// A disjoint set union over the positions of a grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridDsu {
+    inner: Dsu,
+    size: IVec2,
+}
+
+impl Dsu {
+    /// Creates `len` singleton sets
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// The number of ids (not components)
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of disjoint sets
+    #[inline]
+    #[must_use]
+    pub const fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// The representative of the set containing `x`, compressing the path to it along the way
+    pub fn find(&mut self, mut x: usize) -> Option<usize> {
+        loop {
+            let parent = *self.parents.get(x)?;
+            if parent == x {
+                break Some(parent);
+            }
+            // path halving: point every other node on the path to its grandparent
+            self.parents[x] = self.parents[parent];
+            x = self.parents[x];
+        }
+    }
+
+    /// The representative of the set containing `x`, without modifying the structure
+    #[must_use]
+    pub fn root(&self, mut x: usize) -> Option<usize> {
+        loop {
+            let parent = *self.parents.get(x)?;
+            if parent == x {
+                break Some(parent);
+            }
+            x = parent;
+        }
+    }
+
+    /// Merges the sets containing `u` and `v`, returning the size of the merged set
+    pub fn unite(&mut self, u: usize, v: usize) -> Option<usize> {
+        let (pu, pv) = (self.find(u)?, self.find(v)?);
+        if pu == pv {
+            return Some(self.sizes[pu]);
+        }
+        let (larger, smaller) = if self.sizes[pu] < self.sizes[pv] {
+            (pv, pu)
+        } else {
+            (pu, pv)
+        };
+        self.parents[smaller] = larger;
+        self.sizes[larger] += self.sizes[smaller];
+        self.components -= 1;
+        Some(self.sizes[larger])
+    }
+
+    /// Whether `u` and `v` are in the same set
+    pub fn same(&mut self, u: usize, v: usize) -> Option<bool> {
+        Some(self.find(u)? == self.find(v)?)
+    }
+
+    /// The size of the set containing `x`
+    pub fn size(&mut self, x: usize) -> Option<usize> {
+        self.find(x).map(|root| self.sizes[root])
+    }
+
+    /// The size of every set, in no particular order
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|&(idx, &parent)| idx == parent)
+            .map(|(idx, _)| self.sizes[idx])
+    }
+
+    /// The members of every set, each in ascending order
+    pub fn components(&self) -> impl Iterator<Item = Vec<usize>> {
+        (0..self.len())
+            .map(|x| (self.root(x).expect("ids below len to be valid"), x))
+            .into_group_map()
+            .into_values()
+    }
+}
+
+impl GridDsu {
+    /// Creates a singleton set for every position within `size`
+    #[must_use]
+    pub fn new(size: IVec2) -> Self {
+        assert!(size.min_element() >= 0, "Height and width must be positive");
+        Self {
+            #[allow(clippy::cast_sign_loss)]
+            inner: Dsu::new((size.x as usize) * (size.y as usize)),
+            size,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn component_count(&self) -> usize {
+        self.inner.component_count()
+    }
+
+    /// The representative of the set containing `pos`, or `None` if it is outside the grid
+    pub fn find(&mut self, pos: IVec2) -> Option<IVec2> {
+        let root = self.inner.find(row_major_index(self.size, pos)?)?;
+        Some(row_major_position(self.size, root))
+    }
+
+    /// The representative of the set containing `pos`, without modifying the structure
+    #[must_use]
+    pub fn root(&self, pos: IVec2) -> Option<IVec2> {
+        let root = self.inner.root(row_major_index(self.size, pos)?)?;
+        Some(row_major_position(self.size, root))
+    }
+
+    /// Merges the sets containing `a` and `b`, returning the size of the merged set
+    pub fn unite(&mut self, a: IVec2, b: IVec2) -> Option<usize> {
+        self.inner.unite(
+            row_major_index(self.size, a)?,
+            row_major_index(self.size, b)?,
+        )
+    }
+
+    pub fn same(&mut self, a: IVec2, b: IVec2) -> Option<bool> {
+        self.inner.same(
+            row_major_index(self.size, a)?,
+            row_major_index(self.size, b)?,
+        )
+    }
+
+    /// The number of positions in the set containing `pos`
+    pub fn component_size(&mut self, pos: IVec2) -> Option<usize> {
+        self.inner.size(row_major_index(self.size, pos)?)
+    }
+
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.inner.component_sizes()
+    }
+
+    /// The positions in every set, each in row major order
+    pub fn components(&self) -> impl Iterator<Item = Vec<IVec2>> + '_ {
+        self.inner.components().map(|component| {
+            component
+                .into_iter()
+                .map(|idx| row_major_position(self.size, idx))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dense_ids() {
+        let mut dsu = Dsu::new(6);
+        assert_eq!(dsu.component_count(), 6);
+        assert_eq!(dsu.unite(0, 1), Some(2));
+        assert_eq!(dsu.unite(2, 3), Some(2));
+        assert_eq!(dsu.unite(1, 3), Some(4));
+        assert_eq!(dsu.unite(0, 2), Some(4));
+        assert_eq!(dsu.unite(0, 6), None);
+        assert_eq!(dsu.component_count(), 3);
+        assert_eq!(dsu.same(3, 0), Some(true));
+        assert_eq!(dsu.same(4, 5), Some(false));
+        assert_eq!(dsu.size(2), Some(4));
+        let mut sizes = dsu.component_sizes().collect::<Vec<_>>();
+        sizes.sort_unstable();
+        assert_eq!(sizes, [1, 1, 4]);
+        let mut components = dsu.components().collect::<Vec<_>>();
+        components.sort_unstable();
+        assert_eq!(components, [vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+
+    #[test]
+    fn find_halves_the_path() {
+        let mut dsu = Dsu {
+            parents: vec![0, 0, 1, 2, 3, 4, 5, 6],
+            sizes: vec![8, 7, 6, 5, 4, 3, 2, 1],
+            components: 1,
+        };
+        assert_eq!(dsu.find(7), Some(0));
+        assert_eq!(dsu.parents, [0, 0, 1, 1, 3, 3, 5, 5]);
+        assert_eq!(dsu.find(7), Some(0));
+        assert_eq!(dsu.parents, [0, 0, 1, 0, 3, 3, 5, 3]);
+    }
+
+    #[test]
+    fn grid_positions() {
+        let mut dsu = GridDsu::new(IVec2::new(3, 2));
+        assert_eq!(dsu.unite(IVec2::new(0, 0), IVec2::new(1, 0)), Some(2));
+        assert_eq!(dsu.unite(IVec2::new(1, 0), IVec2::new(1, 1)), Some(3));
+        assert_eq!(dsu.unite(IVec2::new(3, 0), IVec2::new(1, 1)), None);
+        assert_eq!(dsu.same(IVec2::new(0, 0), IVec2::new(1, 1)), Some(true));
+        assert_eq!(dsu.same(IVec2::new(0, 0), IVec2::new(2, 1)), Some(false));
+        assert_eq!(dsu.component_size(IVec2::new(1, 1)), Some(3));
+        assert_eq!(dsu.component_count(), 4);
+        assert_eq!(dsu.find(IVec2::new(0, 0)), dsu.find(IVec2::new(1, 1)));
+        assert_eq!(dsu.root(IVec2::new(1, 0)), dsu.find(IVec2::new(1, 1)));
+        let largest = dsu
+            .components()
+            .max_by_key(Vec::len)
+            .expect("there to be components");
+        assert_eq!(
+            largest,
+            [IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(1, 1)]
+        );
+    }
+}
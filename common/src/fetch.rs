@@ -0,0 +1,436 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "fetch")]
+use tap::Pipe;
+
+use crate::input;
+
+/// The environment variable holding the session token to fetch with
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Where puzzles and inputs are fetched from
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The least time left between two requests, to keep load on the site down
+pub const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+#[cfg(feature = "fetch")]
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (common::fetch)"
+);
+
+/// The cached copy of a day's puzzle page, which its examples are read from. Delete it to fetch
+/// the page again once the second part is unlocked.
+const PUZZLE_PAGE: &str = "puzzle.html";
+
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// no session token, set `AOC_SESSION`
+    NoSession,
+    /// no cache directory, set `XDG_CACHE_HOME` or `HOME`
+    NoCacheDir,
+    /// {url} returned {status}
+    Status { url: String, status: u16 },
+    /// request to {url} failed: {message}
+    Transport { url: String, message: String },
+    /// failed to read {path:?}: {source}
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// failed to write {path:?}: {source}
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+/// Makes requests for a `Fetcher`, which tests replace with one that never reaches the real site
+pub trait Transport {
+    /// The body of `url`, requested with the `session` cookie
+    fn get(&self, url: &str, session: &str) -> Result<String, Error>;
+}
+
+/// Downloads puzzle inputs and pages, caching everything it fetches so each is only ever
+/// requested once, and waiting at least `MIN_INTERVAL` between requests
+pub struct Fetcher<T> {
+    transport: T,
+    session: String,
+    base_url: String,
+    cache: PathBuf,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl<T: Transport> Fetcher<T> {
+    /// Caches into `cache`, laid out by year and day like `input::cache_dir`
+    #[must_use]
+    pub fn new(transport: T, session: impl Into<String>, cache: impl Into<PathBuf>) -> Self {
+        Self {
+            transport,
+            session: session.into(),
+            base_url: BASE_URL.to_owned(),
+            cache: cache.into(),
+            min_interval: MIN_INTERVAL,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    #[must_use]
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_min_interval(self, min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            ..self
+        }
+    }
+
+    /// Where `file` for `day` of `year` is cached
+    #[must_use]
+    pub fn path(&self, year: u16, day: u8, file: &str) -> PathBuf {
+        self.cache
+            .join(year.to_string())
+            .join(format!("day-{day:02}"))
+            .join(file)
+    }
+
+    /// The real input for `day` of `year`, and where it's cached
+    pub fn input(&self, year: u16, day: u8) -> Result<(String, PathBuf), Error> {
+        let path = self.path(year, day, input::CACHED_INPUT);
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.cached(&path, &url).map(|text| (text, path))
+    }
+
+    /// The example blocks on the puzzle page for `day` of `year`, in order. Not every block is
+    /// an example, so tests pick out the ones they want.
+    pub fn examples(&self, year: u16, day: u8) -> Result<Vec<String>, Error> {
+        let path = self.path(year, day, PUZZLE_PAGE);
+        let url = format!("{}/{year}/day/{day}", self.base_url);
+        self.cached(&path, &url).map(|html| examples_in(&html))
+    }
+
+    /// The first example block on the puzzle page for `day` of `year`, which is the one most
+    /// days' `example.txt` holds, and where it's cached. `None` if the page has no examples.
+    pub fn example(&self, year: u16, day: u8) -> Result<Option<(String, PathBuf)>, Error> {
+        let path = self.path(year, day, input::CACHED_EXAMPLE);
+        if let Some(text) = read(&path)? {
+            return Ok(Some((text, path)));
+        }
+        let Some(example) = self.examples(year, day)?.into_iter().next() else {
+            return Ok(None);
+        };
+        write(&path, &example)?;
+        Ok(Some((example, path)))
+    }
+
+    /// Waits until `min_interval` has passed since the last request, then counts this as one
+    fn throttle(&self) {
+        let mut last_request = self
+            .last_request
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(wait) =
+            last_request.and_then(|last| self.min_interval.checked_sub(last.elapsed()))
+        {
+            std::thread::sleep(wait);
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    fn cached(&self, path: &Path, url: &str) -> Result<String, Error> {
+        if let Some(text) = read(path)? {
+            return Ok(text);
+        }
+        self.throttle();
+        let text = self.transport.get(url, &self.session)?;
+        write(path, &text)?;
+        Ok(text)
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl Fetcher<Ureq> {
+    /// Fetches from the real site with the session in `AOC_SESSION`, caching into
+    /// `input::cache_dir` where `read_input!` will find it
+    pub fn from_env() -> Result<Self, Error> {
+        let session = std::env::var(SESSION_ENV)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(Error::NoSession)?;
+        let cache = input::cache_dir().ok_or(Error::NoCacheDir)?;
+        Ok(Self::new(Ureq::default(), session.trim(), cache))
+    }
+}
+
+/// The contents of `path`, or `None` if nothing has been cached there yet
+fn read(path: &Path) -> Result<Option<String>, Error> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(Error::Read {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+/// Writes `text` to `path` through a temporary file, so that an interrupted write never leaves
+/// a truncated input in the cache
+fn write(path: &Path, text: &str) -> Result<(), Error> {
+    let error = |source| Error::Write {
+        path: path.to_owned(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(error)?;
+    }
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, text).map_err(error)?;
+    std::fs::rename(&partial, path).map_err(error)
+}
+
+/// The text of every `<pre><code>` block in a puzzle page
+#[must_use]
+pub fn examples_in(html: &str) -> Vec<String> {
+    html.split("<pre><code>")
+        .skip(1)
+        .filter_map(|block| block.split_once("</code></pre>"))
+        .map(|(block, _)| unescape(&strip_tags(block)))
+        .collect()
+}
+
+/// Removes the emphasis and other tags puzzle pages put inside examples
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some((before, after)) = rest.split_once('<') {
+        text.push_str(before);
+        rest = after.split_once('>').map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    text
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Fetches over HTTPS with `ureq`
+#[cfg(feature = "fetch")]
+pub struct Ureq(ureq::Agent);
+
+#[cfg(feature = "fetch")]
+impl Default for Ureq {
+    fn default() -> Self {
+        ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .pipe(ureq::Agent::from)
+            .pipe(Self)
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl Transport for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, Error> {
+        let transport = |err: ureq::Error| Error::Transport {
+            url: url.to_owned(),
+            message: err.to_string(),
+        };
+        let mut response = self
+            .0
+            .get(url)
+            .header("Cookie", format!("session={session}"))
+            .call()
+            .map_err(transport)?;
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(Error::Status {
+                url: url.to_owned(),
+                status,
+            });
+        }
+        response.body_mut().read_to_string().map_err(transport)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::atomic::AtomicUsize, sync::atomic::Ordering};
+
+    use super::*;
+
+    /// A directory of its own for each test, removed when dropped
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("common-fetch-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Serves canned bodies by URL, counting the requests made
+    struct Canned(HashMap<String, String>, AtomicUsize);
+
+    impl Transport for Canned {
+        fn get(&self, url: &str, session: &str) -> Result<String, Error> {
+            assert_eq!(session, "token");
+            self.1.fetch_add(1, Ordering::Relaxed);
+            self.0.get(url).cloned().ok_or_else(|| Error::Status {
+                url: url.to_owned(),
+                status: 404,
+            })
+        }
+    }
+
+    const PAGE: &str = "<p>For example:</p>\n<pre><code>3   4\n<em>4</em>   3\n</code></pre>\n\
+                        <p>Then <code>a &lt; b</code></p><pre><code>x -&gt; y\n</code></pre>";
+
+    #[test]
+    fn examples() {
+        assert_eq!(examples_in(PAGE), ["3   4\n4   3\n", "x -> y\n"]);
+        assert!(examples_in("<p>none</p>").is_empty());
+    }
+
+    #[test]
+    fn caches_each_request() -> Result<(), Error> {
+        let dir = TempDir::new("canned");
+        let transport = Canned(
+            [
+                ("http://aoc/2024/day/1/input", "1 2\n"),
+                ("http://aoc/2024/day/1", PAGE),
+            ]
+            .into_iter()
+            .map(|(url, body)| (url.to_owned(), body.to_owned()))
+            .collect(),
+            AtomicUsize::new(0),
+        );
+        let fetcher = Fetcher::new(transport, "token", &dir.0)
+            .with_base_url("http://aoc")
+            .with_min_interval(Duration::ZERO);
+
+        let (input, path) = fetcher.input(2024, 1)?;
+        assert_eq!(input, "1 2\n");
+        assert!(path.ends_with("2024/day-01/input.txt"));
+        assert_eq!(fetcher.input(2024, 1)?.0, "1 2\n");
+        assert_eq!(fetcher.examples(2024, 1)?.len(), 2);
+        assert_eq!(fetcher.examples(2024, 1)?.len(), 2);
+        assert_eq!(fetcher.transport.1.load(Ordering::Relaxed), 2);
+
+        let (example, path) = fetcher
+            .example(2024, 1)?
+            .expect("the page to have examples");
+        assert_eq!(example, "3   4\n4   3\n");
+        assert!(path.ends_with("2024/day-01/example.txt"));
+        assert_eq!(std::fs::read_to_string(&path).ok(), Some(example));
+        assert_eq!(fetcher.transport.1.load(Ordering::Relaxed), 2);
+
+        assert!(matches!(
+            fetcher.input(2024, 2),
+            Err(Error::Status { status: 404, .. })
+        ));
+        assert!(!fetcher.path(2024, 2, input::CACHED_INPUT).exists());
+        Ok(())
+    }
+
+    /// Runs `ureq` against a local server rather than the real site
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn ureq_against_mock_server() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        let server = std::thread::spawn(move || -> std::io::Result<Vec<(String, String)>> {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (stream, _) = listener.accept()?;
+                let mut reader = BufReader::new(stream.try_clone()?);
+                let mut line = String::new();
+                reader.read_line(&mut line)?;
+                let path = line
+                    .split_whitespace()
+                    .nth(1)
+                    .unwrap_or_default()
+                    .to_owned();
+                let mut cookie = String::new();
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line)?;
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("cookie") {
+                            cookie = value.trim().to_owned();
+                        }
+                    }
+                }
+                let (status, body) = if path == "/2024/day/1/input" {
+                    ("200 OK", "1 2\n")
+                } else {
+                    ("404 Not Found", "")
+                };
+                write!(
+                    &stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )?;
+                requests.push((path, cookie));
+            }
+            Ok(requests)
+        });
+
+        let dir = TempDir::new("ureq");
+        let fetcher = Fetcher::new(Ureq::default(), "token", &dir.0)
+            .with_base_url(base_url)
+            .with_min_interval(Duration::ZERO);
+        assert_eq!(fetcher.input(2024, 1)?.0, "1 2\n");
+        assert_eq!(fetcher.input(2024, 1)?.0, "1 2\n");
+        assert!(matches!(
+            fetcher.input(2024, 2),
+            Err(Error::Status { status: 404, .. })
+        ));
+
+        let requests = server.join().map_err(|_| "server panicked")??;
+        assert_eq!(
+            requests,
+            [
+                ("/2024/day/1/input".to_owned(), "session=token".to_owned()),
+                ("/2024/day/2/input".to_owned(), "session=token".to_owned()),
+            ]
+        );
+        Ok(())
+    }
+}
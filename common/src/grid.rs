@@ -0,0 +1,1070 @@
+use std::{
+    fmt::{Display, Write},
+    num::TryFromIntError,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use glam::{IVec2, Vec2Swizzles};
+#[cfg(feature = "image")]
+use image::ImageBuffer;
+use itertools::Itertools;
+use tap::prelude::*;
+
+use crate::bitset::BitGrid;
+
+/// The four orthogonal directions, clockwise starting from up
+pub const DIRECTIONS4: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+
+/// All eight directions, clockwise starting from up
+pub const DIRECTIONS8: [IVec2; 8] = [
+    IVec2::NEG_Y,
+    IVec2::new(1, -1),
+    IVec2::X,
+    IVec2::ONE,
+    IVec2::Y,
+    IVec2::new(-1, 1),
+    IVec2::NEG_X,
+    IVec2::NEG_ONE,
+];
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    items: Vec<T>,
+    size: IVec2,
+}
+
+impl<T> Grid<T> {
+    pub fn from_positions(op: impl FnMut(IVec2) -> T, size: IVec2) -> Self {
+        Self {
+            items: Positions {
+                current: IVec2::ZERO,
+                size,
+            }
+            .map(op)
+            .collect_vec(),
+            size,
+        }
+    }
+    pub fn from_value(value: T, size: IVec2) -> Self
+    where
+        T: Clone,
+    {
+        assert!(size.min_element() >= 0, "Height and width must be positive");
+        Self {
+            #[allow(clippy::cast_sign_loss)]
+            items: std::iter::repeat_n(value, (size.x as usize) * (size.y as usize)).collect_vec(),
+            size,
+        }
+    }
+
+    pub fn from_row_major_ordered(values: impl IntoIterator<Item = T>, size: IVec2) -> Self {
+        let items = values.into_iter().collect_vec();
+        assert_eq!(
+            items.len(),
+            (size.x * size.y).try_conv::<usize>().expect("")
+        );
+        Self { items, size }
+    }
+
+    #[cfg(feature = "image")]
+    #[must_use]
+    pub fn pixels<P>(&self, make_pixel: impl Fn(&T) -> P) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: image::Pixel,
+    {
+        ImageBuffer::from_fn(
+            self.size.x.try_into().expect(""),
+            self.size.y.try_into().expect(""),
+            |x, y| {
+                self.get(IVec2 {
+                    x: x.try_into().expect(""),
+                    y: y.try_into().expect(""),
+                })
+                .expect("Not to query outside of image")
+                .pipe_ref(&make_pixel)
+            },
+        )
+    }
+
+    #[cfg(feature = "image")]
+    #[must_use]
+    pub fn pixel_positions<P>(
+        &self,
+        make_pixel: impl Fn(IVec2, &T) -> P,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: image::Pixel,
+    {
+        ImageBuffer::from_fn(
+            self.size.x.try_into().expect(""),
+            self.size.y.try_into().expect(""),
+            |x, y| {
+                let pos = IVec2 {
+                    x: x.try_into().expect(""),
+                    y: y.try_into().expect(""),
+                };
+                make_pixel(pos, self.get(pos).expect("Not to query outside of image"))
+            },
+        )
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> IVec2 {
+        self.size
+    }
+    #[must_use]
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.index(pos).and_then(|idx| self.items.get(idx))
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.index(pos).and_then(|idx| self.items.get_mut(idx))
+    }
+
+    pub fn map<U>(self, op: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            size: self.size,
+            items: self.items.into_iter().map(op).collect_vec(),
+        }
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    #[must_use]
+    pub const fn positions(&self) -> Positions {
+        Positions {
+            current: IVec2::ZERO,
+            size: self.size,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(&self, pos: IVec2) -> bool {
+        row_major_index(self.size, pos).is_some()
+    }
+
+    /// The orthogonal neighbours of `pos` which are within the grid
+    pub fn neighbors4(&self, pos: IVec2) -> impl Iterator<Item = IVec2> {
+        let size = self.size;
+        DIRECTIONS4
+            .into_iter()
+            .map(move |d| pos + d)
+            .filter(move |&p| row_major_index(size, p).is_some())
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` which are within the grid
+    pub fn neighbors8(&self, pos: IVec2) -> impl Iterator<Item = IVec2> {
+        let size = self.size;
+        DIRECTIONS8
+            .into_iter()
+            .map(move |d| pos + d)
+            .filter(move |&p| row_major_index(size, p).is_some())
+    }
+
+    /// The positions and values from `start` onwards in steps of `dir`, until leaving the grid
+    #[must_use]
+    pub fn ray(&self, start: IVec2, dir: IVec2) -> Ray<'_, T> {
+        assert_ne!(dir, IVec2::ZERO, "A ray must have a direction");
+        Ray {
+            grid: self,
+            pos: start,
+            dir,
+        }
+    }
+
+    /// Each row, from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.size.y).map(|y| self.ray(IVec2::new(0, y), IVec2::X))
+    }
+
+    /// Each column, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.size.x).map(|x| self.ray(IVec2::new(x, 0), IVec2::Y))
+    }
+
+    /// Each line running down and to the right, starting from the top right corner
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.size.x)
+            .rev()
+            .map(|x| IVec2::new(x, 0))
+            .chain((1..self.size.y).map(|y| IVec2::new(0, y)))
+            .map(|start| self.ray(start, IVec2::ONE))
+    }
+
+    /// Each line running down and to the left, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.size.x)
+            .map(|x| IVec2::new(x, 0))
+            .chain((1..self.size.y).map(|y| IVec2::new(self.size.x - 1, y)))
+            .map(|start| self.ray(start, IVec2::new(-1, 1)))
+    }
+
+    /// Every position reachable from `start` by orthogonal steps, where a step from `a` to `b`
+    /// is allowed if `connected(a, b)`
+    pub fn flood_fill(&self, start: IVec2, mut connected: impl FnMut(&T, &T) -> bool) -> BitGrid {
+        let mut filled = BitGrid::new(self.size);
+        if !filled.insert(start) {
+            return filled;
+        }
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            let here = &self[pos];
+            for next in self.neighbors4(pos) {
+                if !filled.contains(next) && connected(here, &self[next]) {
+                    filled.insert(next);
+                    stack.push(next);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Every position reachable from `start` through `successors`, which may step in any direction
+    /// (such as only along the way something is pushed). Positions outside the grid are skipped.
+    pub fn flood<I>(&self, start: IVec2, mut successors: impl FnMut(IVec2) -> I) -> BitGrid
+    where
+        I: IntoIterator<Item = IVec2>,
+    {
+        let mut filled = BitGrid::new(self.size);
+        if !filled.insert(start) {
+            return filled;
+        }
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            for next in successors(pos) {
+                if filled.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        filled
+    }
+
+    /// The orthogonally connected regions of equal values, in row major order of their first position
+    pub fn regions(&self) -> impl Iterator<Item = Region> + '_
+    where
+        T: PartialEq,
+    {
+        let mut seen = BitGrid::new(self.size);
+        self.positions().filter_map(move |pos| {
+            if seen.contains(pos) {
+                return None;
+            }
+            let cells = self.flood_fill(pos, PartialEq::eq);
+            seen |= &cells;
+            Some(Region::new(pos, cells))
+        })
+    }
+
+    const fn index(&self, pos: IVec2) -> Option<usize> {
+        row_major_index(self.size, pos)
+    }
+}
+
+/// A straight line through a grid, see `Grid::ray`
+#[derive(Debug)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: IVec2,
+    dir: IVec2,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (IVec2, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.pos;
+        let value = self.grid.get(pos)?;
+        self.pos += self.dir;
+        Some((pos, value))
+    }
+}
+
+impl<T> Clone for Ray<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            pos: self.pos,
+            dir: self.dir,
+        }
+    }
+}
+
+/// A connected set of positions within a grid, along with the shape of its boundary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    start: IVec2,
+    cells: BitGrid,
+    area: usize,
+    perimeter: usize,
+    corners: usize,
+}
+
+impl Region {
+    /// Measures the region made up of `cells`, which contains `start`
+    #[must_use]
+    pub fn new(start: IVec2, cells: BitGrid) -> Self {
+        let (mut area, mut perimeter, mut corners) = (0, 0, 0);
+        for pos in cells.iter() {
+            area += 1;
+            perimeter += DIRECTIONS4
+                .into_iter()
+                .filter(|&d| !cells.contains(pos + d))
+                .count();
+            corners += DIRECTIONS4
+                .into_iter()
+                .circular_tuple_windows()
+                .filter(|&(a, b)| {
+                    let (left, right, mid) = (
+                        cells.contains(pos + a),
+                        cells.contains(pos + b),
+                        cells.contains(pos + a + b),
+                    );
+                    // an outer corner, or an inner one
+                    (!left && !right) || (left && right && !mid)
+                })
+                .count();
+        }
+        Self {
+            start,
+            cells,
+            area,
+            perimeter,
+            corners,
+        }
+    }
+
+    /// The first position of the region in row major order
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> IVec2 {
+        self.start
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn cells(&self) -> &BitGrid {
+        &self.cells
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn area(&self) -> usize {
+        self.area
+    }
+
+    /// The number of edges between a cell in the region and one outside it
+    #[inline]
+    #[must_use]
+    pub const fn perimeter(&self) -> usize {
+        self.perimeter
+    }
+
+    /// The number of corners of the region's boundary, which is also the number of straight sides
+    #[inline]
+    #[must_use]
+    pub const fn corners(&self) -> usize {
+        self.corners
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps the rows and columns
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_positions(|pos| self[pos.yx()].clone(), self.size.yx())
+    }
+
+    /// Mirrors the grid left to right
+    #[must_use]
+    pub fn flip_x(&self) -> Self {
+        Self::from_positions(
+            |pos| self[IVec2::new(self.size.x - 1 - pos.x, pos.y)].clone(),
+            self.size,
+        )
+    }
+
+    /// Mirrors the grid top to bottom
+    #[must_use]
+    pub fn flip_y(&self) -> Self {
+        Self::from_positions(
+            |pos| self[IVec2::new(pos.x, self.size.y - 1 - pos.y)].clone(),
+            self.size,
+        )
+    }
+
+    /// Rotates the grid a quarter turn clockwise
+    #[must_use]
+    pub fn rotate90(&self) -> Self {
+        Self::from_positions(
+            |pos| self[IVec2::new(pos.y, self.size.y - 1 - pos.x)].clone(),
+            self.size.yx(),
+        )
+    }
+
+    #[must_use]
+    pub fn rotate180(&self) -> Self {
+        Self::from_positions(|pos| self[self.size - IVec2::ONE - pos].clone(), self.size)
+    }
+
+    /// Rotates the grid a quarter turn anticlockwise
+    #[must_use]
+    pub fn rotate270(&self) -> Self {
+        Self::from_positions(
+            |pos| self[IVec2::new(self.size.x - 1 - pos.y, pos.x)].clone(),
+            self.size.yx(),
+        )
+    }
+
+    /// The distinct grids which can be reached by rotating and flipping this one, starting with itself
+    pub fn symmetries(&self) -> impl Iterator<Item = Self>
+    where
+        T: PartialEq,
+    {
+        let flipped = self.flip_x();
+        [
+            self.rotate90(),
+            self.rotate180(),
+            self.rotate270(),
+            flipped.rotate90(),
+            flipped.rotate180(),
+            flipped.rotate270(),
+            flipped,
+        ]
+        .into_iter()
+        .fold(vec![self.clone()], |mut unique, grid| {
+            if !unique.contains(&grid) {
+                unique.push(grid);
+            }
+            unique
+        })
+        .into_iter()
+    }
+
+    /// Repeats the grid `across` times horizontally and `down` times vertically
+    #[must_use]
+    pub fn tile(&self, across: i32, down: i32) -> Self {
+        assert!(
+            across >= 0 && down >= 0,
+            "Cannot tile a negative number of times"
+        );
+        Self::from_positions(
+            |pos| self[pos % self.size].clone(),
+            self.size * IVec2::new(across, down),
+        )
+    }
+}
+
+impl<T> Grid<T> {
+    /// Renders each row as a line of text, with `render` choosing the character for each position
+    pub fn render_with(&self, mut render: impl FnMut(IVec2, &T) -> char) -> String {
+        self.rows()
+            .map(|row| {
+                row.map(|(pos, value)| render(pos, value))
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+
+    /// Like `render_with`, but colours the positions in each layer for display in a terminal.
+    ///
+    /// Where layers overlap, the first one takes priority.
+    pub fn render_overlay(
+        &self,
+        layers: &[(&BitGrid, Colour)],
+        mut render: impl FnMut(IVec2, &T) -> char,
+    ) -> String {
+        let mut out = String::new();
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                out.push('\n');
+            }
+            for (pos, value) in row {
+                let c = render(pos, value);
+                match layers.iter().find(|(layer, _)| layer.contains(pos)) {
+                    Some((_, colour)) => {
+                        write!(out, "\x1b[{}m{c}\x1b[0m", colour.code())
+                            .expect("writing to a string not to fail");
+                    }
+                    None => out.push(c),
+                }
+            }
+        }
+        out
+    }
+
+    /// A borrowed window of `size` with its top left corner at `origin`, if it fits within the grid
+    #[must_use]
+    pub fn view(&self, origin: IVec2, size: IVec2) -> Option<GridView<'_, T>> {
+        GridView::new(self, origin, size)
+    }
+
+    /// Every window of `size` which fits within the grid, in row major order of their origins
+    pub fn windows(&self, size: IVec2) -> impl Iterator<Item = GridView<'_, T>> {
+        Positions::new((self.size - size + IVec2::ONE).max(IVec2::ZERO))
+            .filter_map(move |origin| self.view(origin, size))
+    }
+}
+
+/// A rectangular window into a `Grid`, indexed relative to its own top left corner
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: IVec2,
+    size: IVec2,
+}
+
+impl<'a, T> GridView<'a, T> {
+    fn new(grid: &'a Grid<T>, origin: IVec2, size: IVec2) -> Option<Self> {
+        (size.min_element() >= 0
+            && origin.min_element() >= 0
+            && (origin + size).cmple(grid.size).all())
+        .then_some(Self { grid, origin, size })
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    /// The position of the view's top left corner within the underlying grid
+    #[must_use]
+    pub const fn origin(&self) -> IVec2 {
+        self.origin
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: IVec2) -> Option<&'a T> {
+        row_major_index(self.size, pos)?;
+        self.grid.get(self.origin + pos)
+    }
+
+    #[must_use]
+    pub const fn positions(&self) -> Positions {
+        Positions::new(self.size)
+    }
+
+    /// The values in the view, in row major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        self.positions()
+            .map(move |pos| view.get(pos).expect("positions to be within the view"))
+    }
+
+    /// A window into this view, with `origin` relative to this view
+    #[must_use]
+    pub fn view(&self, origin: IVec2, size: IVec2) -> Option<Self> {
+        (origin.min_element() >= 0 && (origin + size).cmple(self.size).all())
+            .then(|| Self::new(self.grid, self.origin + origin, size))
+            .flatten()
+    }
+
+    #[must_use]
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_positions(|pos| self[pos].clone(), self.size)
+    }
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Index<IVec2> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: IVec2) -> &Self::Output {
+        self.get(index).expect("Position out of bounds")
+    }
+}
+
+/// A terminal colour for highlighting positions in `Grid::render_overlay`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    /// The ANSI escape code for the bold foreground colour
+    const fn code(self) -> &'static str {
+        match self {
+            Self::Red => "1;31",
+            Self::Green => "1;32",
+            Self::Yellow => "1;33",
+            Self::Blue => "1;34",
+            Self::Magenta => "1;35",
+            Self::Cyan => "1;36",
+        }
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render_with(|_, b| char::from(*b)))
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render_with(|_, c| *c))
+    }
+}
+
+/// The index of `pos` within the row major storage of a grid of `size`, if it's in bounds
+#[allow(clippy::cast_sign_loss)]
+pub(crate) const fn row_major_index(size: IVec2, pos: IVec2) -> Option<usize> {
+    if pos.x < 0 || pos.y < 0 || pos.x >= size.x || pos.y >= size.y {
+        None
+    } else {
+        Some((pos.y as usize) * (size.x as usize) + (pos.x as usize))
+    }
+}
+
+/// The inverse of `row_major_index`
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+pub(crate) const fn row_major_position(size: IVec2, idx: usize) -> IVec2 {
+    let width = size.x as usize;
+    IVec2::new((idx % width) as i32, (idx / width) as i32)
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: IVec2) -> &Self::Output {
+        self.get(index).expect("Position out of bounds")
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, index: IVec2) -> &mut Self::Output {
+        self.get_mut(index).expect("Position out of bounds")
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (height, width) = (
+            s.lines().count(),
+            s.lines()
+                .map(str::trim)
+                .map(str::len)
+                .unique()
+                .at_most_one()
+                .map_err(|_| Error::InconsistentLines)?
+                .ok_or(Error::Empty)?,
+        );
+        Self {
+            items: s.chars().filter(|c| !c.is_whitespace()).collect_vec(),
+            size: IVec2 {
+                x: width.try_into()?,
+                y: height.try_into()?,
+            },
+        }
+        .pipe(Ok)
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (height, width) = (
+            s.lines().count(),
+            s.lines()
+                .map(str::trim)
+                .map(str::len)
+                .unique()
+                .at_most_one()
+                .map_err(|_| Error::InconsistentLines)?
+                .ok_or(Error::Empty)?,
+        );
+        if s.is_ascii() {
+            Self {
+                items: s
+                    .as_bytes()
+                    .iter()
+                    .copied()
+                    .filter(|b| !b.is_ascii_whitespace())
+                    .collect_vec(),
+                size: IVec2 {
+                    x: width.try_into()?,
+                    y: height.try_into()?,
+                },
+            }
+            .pipe(Ok)
+        } else {
+            Err(Error::NonAscii)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Positions {
+    current: IVec2,
+    size: IVec2,
+}
+
+impl Positions {
+    /// Every position within a grid of the given size, in row major order
+    #[must_use]
+    pub const fn new(size: IVec2) -> Self {
+        Self {
+            current: IVec2::ZERO,
+            size,
+        }
+    }
+}
+
+impl Iterator for Positions {
+    type Item = IVec2;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match (self.current, self.size) {
+                (IVec2 { y, .. }, IVec2 { y: h, .. }) if y >= h => break None,
+                (IVec2 { x, .. }, IVec2 { x: w, y: _ }) if x >= w => {
+                    self.current.y += 1;
+                    self.current.x = 0;
+                }
+                (current, _) => {
+                    self.current.x += 1;
+                    break Some(current);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for Grid<T> {
+    type Item = T;
+
+    type IntoIter = std::vec::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // one of the present shapes from 2025 day 12
+    const PRESENT: &str = "
+        ###
+        ##.
+        ##.
+    ";
+
+    // the examples from 2024 day 4 and 2025 day 4, which both years' solutions parse as grids
+    const WORD_SEARCH: &str = "
+        MMMSXXMASM
+        MSAMXMSMSA
+        AMXSXMAAMM
+        MSAMASMSMX
+        XMASAMXAMM
+        XXAMMXXAMA
+        SMSMSASXSS
+        SAXAMASAAA
+        MAMMMXMMMM
+        MXMXAXMASX
+    ";
+    const PAPER_ROLLS: &str = "
+        ..@@.@@@@.
+        @@@.@.@.@@
+        @@@@@.@.@@
+        @.@@@@..@.
+        @@.@@@@.@@
+        .@@@@@@@.@
+        .@.@.@.@@@
+        @.@@@.@@@@
+        .@@@@@@@@.
+        @.@.@@@.@.
+    ";
+
+    #[test]
+    fn both_years_examples() -> Result<(), Error> {
+        let search: Grid<u8> = WORD_SEARCH.parse()?;
+        assert_eq!(search.size(), IVec2::splat(10));
+        assert_eq!(search.to_string().parse::<Grid<u8>>()?, search);
+        let xmas = search
+            .rows()
+            .chain(search.columns())
+            .chain(search.diagonals())
+            .chain(search.anti_diagonals())
+            .map(|line| {
+                line.map(|(_, b)| *b)
+                    .tuple_windows::<(_, _, _, _)>()
+                    .filter(|&window| matches!(&window.into(), b"XMAS" | b"SAMX"))
+                    .count()
+            })
+            .sum::<usize>();
+        assert_eq!(xmas, 18);
+
+        let rolls: Grid<u8> = PAPER_ROLLS.parse()?;
+        assert_eq!(rolls.to_string().parse::<Grid<u8>>()?, rolls);
+        let accessible = rolls
+            .positions()
+            .filter(|&pos| rolls[pos] == b'@')
+            .filter(|&pos| rolls.neighbors8(pos).filter(|&n| rolls[n] == b'@').count() < 4)
+            .count();
+        assert_eq!(accessible, 13);
+        Ok(())
+    }
+
+    #[test]
+    fn rotations_and_flips() -> Result<(), Error> {
+        let grid: Grid<u8> = "
+            abc
+            def
+        "
+        .parse()?;
+        let parse = |s: &str| s.parse::<Grid<u8>>();
+        assert_eq!(grid.transpose(), parse("ad\nbe\ncf")?);
+        assert_eq!(grid.rotate90(), parse("da\neb\nfc")?);
+        assert_eq!(grid.rotate180(), parse("fed\ncba")?);
+        assert_eq!(grid.rotate270(), parse("cf\nbe\nad")?);
+        assert_eq!(grid.flip_x(), parse("cba\nfed")?);
+        assert_eq!(grid.flip_y(), parse("def\nabc")?);
+        assert_eq!(grid.rotate90().rotate90(), grid.rotate180());
+        assert_eq!(grid.rotate90().rotate270(), grid);
+        assert_eq!(grid.flip_x().rotate270(), grid.transpose());
+        Ok(())
+    }
+
+    #[test]
+    fn unique_symmetries() -> Result<(), Error> {
+        let present: Grid<u8> = PRESENT.parse()?;
+        assert_eq!(present.symmetries().count(), 8);
+        let symmetric: Grid<u8> = "
+            ###
+            .#.
+            ###
+        "
+        .parse()?;
+        assert_eq!(symmetric.symmetries().count(), 2);
+        let mirrored: Grid<u8> = "
+            ###
+            #..
+            ###
+        "
+        .parse()?;
+        assert_eq!(mirrored.symmetries().count(), 4);
+        assert_eq!(Grid::from_value(0, IVec2::ONE).symmetries().count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn views_and_tiles() -> Result<(), Error> {
+        let present: Grid<u8> = PRESENT.parse()?;
+        let tiled = present.tile(2, 3);
+        assert_eq!(tiled.size(), IVec2::new(6, 9));
+        assert_eq!(tiled[IVec2::new(5, 7)], b'.');
+        let view = tiled
+            .view(IVec2::new(3, 6), present.size())
+            .expect("in bounds");
+        assert_eq!(view.to_grid(), present);
+        assert!(tiled.view(IVec2::new(4, 0), present.size()).is_none());
+        let inner = view.view(IVec2::ONE, IVec2::new(2, 2)).expect("in bounds");
+        assert_eq!(inner.iter().copied().collect_vec(), b"#.#.");
+        assert_eq!(present.windows(IVec2::new(2, 2)).count(), 4);
+        assert_eq!(present.windows(IVec2::new(4, 1)).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn lines_on_rectangles() -> Result<(), Error> {
+        let grid: Grid<u8> = "
+            abc
+            def
+        "
+        .parse()?;
+        let text = |lines: Vec<Ray<'_, u8>>| {
+            lines
+                .into_iter()
+                .map(|line| line.map(|(_, b)| char::from(*b)).collect::<String>())
+                .collect_vec()
+        };
+        assert_eq!(text(grid.rows().collect()), ["abc", "def"]);
+        assert_eq!(text(grid.columns().collect()), ["ad", "be", "cf"]);
+        assert_eq!(text(grid.diagonals().collect()), ["c", "bf", "ae", "d"]);
+        assert_eq!(
+            text(grid.anti_diagonals().collect()),
+            ["a", "bd", "ce", "f"]
+        );
+        assert_eq!(
+            grid.ray(IVec2::new(2, 1), IVec2::NEG_X).collect_vec(),
+            [
+                (IVec2::new(2, 1), &b'f'),
+                (IVec2::new(1, 1), &b'e'),
+                (IVec2::new(0, 1), &b'd'),
+            ]
+        );
+        assert_eq!(grid.ray(IVec2::new(3, 0), IVec2::X).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn text_rendering() -> Result<(), Error> {
+        let grid: Grid<u8> = PRESENT.parse()?;
+        assert_eq!(grid.to_string(), "###\n##.\n##.");
+        assert_eq!(grid.clone().map(char::from).to_string(), "###\n##.\n##.");
+        assert_eq!(
+            grid.render_with(|pos, b| if *b == b'#' && pos.x == pos.y {
+                'o'
+            } else {
+                '.'
+            }),
+            "o..\n.o.\n..."
+        );
+        let mut path = BitGrid::new(grid.size());
+        path.insert(IVec2::new(2, 0));
+        path.insert(IVec2::new(2, 1));
+        let mut region = BitGrid::new(grid.size());
+        region.insert(IVec2::new(2, 1));
+        region.insert(IVec2::new(0, 2));
+        assert_eq!(
+            grid.render_overlay(&[(&path, Colour::Red), (&region, Colour::Green)], |_, b| {
+                char::from(*b)
+            }),
+            "##\x1b[1;31m#\x1b[0m\n##\x1b[1;31m.\x1b[0m\n\x1b[1;32m#\x1b[0m#."
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = Grid::from_value(0, IVec2::new(3, 2));
+        assert_eq!(
+            grid.neighbors4(IVec2::ZERO).collect_vec(),
+            [IVec2::X, IVec2::Y]
+        );
+        assert_eq!(grid.neighbors4(IVec2::new(1, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(IVec2::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8(IVec2::new(1, 1)).count(), 5);
+        assert!(grid.contains(IVec2::new(2, 1)));
+        assert!(!grid.contains(IVec2::new(3, 1)));
+    }
+
+    #[test]
+    fn flood_fill_follows_steps() -> Result<(), Error> {
+        let grid: Grid<u8> = "
+            0123
+            1298
+            9987
+        "
+        .parse()?;
+        let uphill = grid.flood_fill(IVec2::ZERO, |a, b| *b == a + 1);
+        assert_eq!(uphill.len(), 6);
+        assert!(uphill.contains(IVec2::new(3, 0)));
+        assert!(!uphill.contains(IVec2::new(2, 1)));
+        assert!(grid.flood_fill(IVec2::NEG_ONE, |_, _| true).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn flood_follows_successors() -> Result<(), Error> {
+        let grid: Grid<u8> = "
+            .[]..
+            [][].
+            .....
+        "
+        .parse()?;
+        // everything pushed upwards along with the box at the bottom left
+        let pushed = grid.flood(IVec2::new(1, 1), |pos| {
+            let other_half = if grid[pos] == b'[' {
+                pos + IVec2::X
+            } else {
+                pos - IVec2::X
+            };
+            [other_half, pos - IVec2::Y]
+                .into_iter()
+                .filter(|p| matches!(grid.get(*p), Some(b'[' | b']')))
+        });
+        assert_eq!(
+            pushed.iter().collect_vec(),
+            [
+                IVec2::new(1, 0),
+                IVec2::new(2, 0),
+                IVec2::new(0, 1),
+                IVec2::new(1, 1)
+            ]
+        );
+        assert!(grid.flood(IVec2::NEG_ONE, |_| []).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn region_stats() -> Result<(), Error> {
+        let grid: Grid<u8> = "
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        "
+        .parse()?;
+        let regions = grid
+            .regions()
+            .map(|r| (grid[r.start()], r.area(), r.perimeter(), r.corners()))
+            .collect_vec();
+        assert_eq!(
+            regions,
+            [
+                (b'A', 4, 10, 4),
+                (b'B', 4, 8, 4),
+                (b'C', 4, 10, 8),
+                (b'D', 1, 4, 4),
+                (b'E', 3, 8, 4),
+            ]
+        );
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// Not all provided lines were the same length
+    InconsistentLines,
+    /// The provided string had no lines
+    Empty,
+    /// Failed to convert between integer types
+    IntegerConversion(#[from] TryFromIntError),
+    /// Attempted to construct a byte grid from non-ascii text
+    NonAscii,
+}
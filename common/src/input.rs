@@ -0,0 +1,211 @@
+use std::{
+    ffi::OsString,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable naming a directory of inputs, laid out as `<year>/<day>/<file>`
+pub const ENV_DIR: &str = "AOC_INPUT_DIR";
+
+/// The name the real puzzle input is cached under, which any of `PUZZLE_FILES` resolve to
+pub const CACHED_INPUT: &str = "input.txt";
+
+/// The name `fetch` caches the first example block on a day's puzzle page under
+pub const CACHED_EXAMPLE: &str = "example.txt";
+
+/// The names days give the real puzzle input (2024 keeps a copy per part)
+pub const PUZZLE_FILES: [&str; 3] = ["input.txt", "part1.txt", "part2.txt"];
+
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// no {file:?} input found, tried {tried}
+    NotFound { file: String, tried: Tried },
+    /// failed to read {path:?}: {source}
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+/// Every path looked at for an input, in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tried(pub Vec<PathBuf>);
+
+/// Finds the inputs for the day crate in a directory. Each input is looked for, in order:
+///
+/// 1. at the path given on the command line, if there is one, in which case nothing else is
+///    tried
+/// 2. under `$AOC_INPUT_DIR`
+/// 3. under the per-user cache directory (see `cache_dir`), where `fetch` puts inputs. The
+///    real input is cached as `input.txt` whichever of `PUZZLE_FILES` a day calls it, and the
+///    first example on the puzzle page as `example.txt`. Other examples aren't fetched.
+/// 4. in the crate's own `inputs/`
+///
+/// The first two are laid out by year and day, such as `2024/day-17/part1.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolver {
+    dir: PathBuf,
+    cli: Option<PathBuf>,
+}
+
+impl Resolver {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            cli: None,
+        }
+    }
+
+    /// Uses the first command line argument (if any) as the path to the input
+    #[must_use]
+    pub fn from_args(dir: impl Into<PathBuf>) -> Self {
+        Self::new(dir).with_cli(std::env::args_os().nth(1).map(PathBuf::from))
+    }
+
+    #[must_use]
+    pub fn with_cli(self, cli: Option<PathBuf>) -> Self {
+        Self { cli, ..self }
+    }
+
+    /// Where `file` is looked for, in order
+    #[must_use]
+    pub fn candidates(&self, file: &str) -> Vec<PathBuf> {
+        if let Some(cli) = &self.cli {
+            return vec![cli.clone()];
+        }
+        let relative = self.relative(file);
+        [
+            std::env::var_os(ENV_DIR).map(|dir| PathBuf::from(dir).join(&relative)),
+            cache_dir().map(|dir| {
+                let cached = if PUZZLE_FILES.contains(&file) {
+                    CACHED_INPUT
+                } else {
+                    file
+                };
+                dir.join(relative.with_file_name(cached))
+            }),
+            Some(self.dir.join("inputs").join(file)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// The first of `candidates` which exists
+    pub fn resolve(&self, file: &str) -> Result<PathBuf, Error> {
+        let tried = self.candidates(file);
+        tried
+            .iter()
+            .find(|path| path.is_file())
+            .cloned()
+            .ok_or_else(|| Error::NotFound {
+                file: file.to_owned(),
+                tried: Tried(tried),
+            })
+    }
+
+    /// Reads and normalises `file`, wherever it's found
+    pub fn read(&self, file: &str) -> Result<String, Error> {
+        let path = self.resolve(file)?;
+        std::fs::read_to_string(&path)
+            .map(|text| normalise(&text))
+            .map_err(|source| Error::Read { path, source })
+    }
+
+    /// `file`'s path within a directory of every year's inputs, such as `2024/day-17/part1.txt`
+    fn relative(&self, file: &str) -> PathBuf {
+        let mut components = self
+            .dir
+            .components()
+            .rev()
+            .map(std::path::Component::as_os_str);
+        let day = components.next().unwrap_or_default();
+        let year = components.next().unwrap_or_default();
+        Path::new(year).join(day).join(file)
+    }
+}
+
+/// The per-user directory inputs are cached in: `advent_of_code` within `$XDG_CACHE_HOME` (or
+/// `~/.cache`), `~/Library/Caches` on macOS, or `%LOCALAPPDATA%` on Windows
+#[must_use]
+pub fn cache_dir() -> Option<PathBuf> {
+    let non_empty = |name| std::env::var_os(name).filter(|value: &OsString| !value.is_empty());
+    let base = if cfg!(windows) {
+        non_empty("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        non_empty("HOME").map(|home| PathBuf::from(home).join("Library/Caches"))
+    } else {
+        non_empty("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| non_empty("HOME").map(|home| PathBuf::from(home).join(".cache")))
+    };
+    base.map(|base| base.join("advent_of_code"))
+}
+
+/// Converts line endings to `\n` and trims any trailing blank lines, leaving a single newline at
+/// the end. Whitespace within lines is left alone, since some puzzles line their columns up with
+/// it.
+#[must_use]
+pub fn normalise(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut lines = text.lines().collect::<Vec<_>>();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    lines.into_iter().flat_map(|line| [line, "\n"]).collect()
+}
+
+impl Display for Tried {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, path) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises() {
+        assert_eq!(normalise("a\r\nb  \r\n\r\n  \n"), "a\nb  \n");
+        assert_eq!(normalise("a\rb"), "a\nb\n");
+        assert_eq!(normalise(""), "");
+    }
+
+    #[test]
+    fn resolution_order() {
+        let resolver = Resolver::new("/aoc/2024/day-17");
+        assert_eq!(
+            resolver.relative("part1.txt"),
+            Path::new("2024/day-17/part1.txt")
+        );
+        let candidates = resolver.candidates("part1.txt");
+        if let Some(cache) = cache_dir() {
+            assert!(candidates.contains(&cache.join("2024/day-17").join(CACHED_INPUT)));
+        }
+        assert_eq!(
+            candidates.last().map(PathBuf::as_path),
+            Some(Path::new("/aoc/2024/day-17/inputs/part1.txt"))
+        );
+
+        let resolver = resolver.with_cli(Some("/nowhere/in.txt".into()));
+        assert_eq!(
+            resolver.candidates("part1.txt"),
+            [PathBuf::from("/nowhere/in.txt")]
+        );
+        match resolver.read("part1.txt") {
+            Err(Error::NotFound { file, tried }) => {
+                assert_eq!(file, "part1.txt");
+                assert_eq!(tried.to_string(), "/nowhere/in.txt");
+            }
+            other => panic!("expected NotFound, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+pub mod answers;
+pub mod bench;
+pub mod bitset;
+pub mod check;
+pub mod counter;
+pub mod dsu;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod min_heap;
+#[cfg(feature = "rayon")]
+pub mod par;
+pub mod parse;
+pub mod position_map;
+pub mod runner;
+pub mod search;
+pub mod solution;
+
+pub use solution::Solution;
+
+/*
+TODO:
+- counter
+*/
+
+/// Reads and normalises the current crate's input `file`, found as described on
+/// `input::Resolver`
+#[macro_export]
+macro_rules! read_input {
+    ($filename:expr) => {
+        $crate::input::Resolver::new(env!("CARGO_MANIFEST_DIR")).read($filename)?
+    };
+}
@@ -0,0 +1,372 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// An indexed binary min-heap.
+///
+/// Each key appears at most once, which allows its priority to be looked up and decreased
+/// in place instead of pushing duplicate entries and skipping stale ones when popped.
+/// Removal is lazy: removed entries are only marked, and are discarded when they reach
+/// the top of the heap (or when enough of them pile up to warrant a rebuild).
+#[derive(Debug, Clone)]
+pub struct MinHeap<K, P> {
+    slots: Vec<Slot<K, P>>,
+    index: HashMap<K, usize>,
+    removed: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<K, P> {
+    key: K,
+    priority: P,
+    live: bool,
+}
+
+impl<K, P> MinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            index: HashMap::new(),
+            removed: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            index: HashMap::with_capacity(capacity),
+            removed: 0,
+        }
+    }
+
+    /// The number of keys in the heap, not counting removed entries
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    #[must_use]
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.index.get(key).map(|&idx| &self.slots[idx].priority)
+    }
+
+    #[must_use]
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.slots
+            .first()
+            .filter(|slot| slot.live)
+            .map(|slot| (&slot.key, &slot.priority))
+    }
+
+    /// Inserts `key`, or replaces its priority if it is already present.
+    ///
+    /// Returns the previous priority of `key`, if any.
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        match self.index.get(&key).copied() {
+            Some(idx) => {
+                let old = std::mem::replace(&mut self.slots[idx].priority, priority);
+                if self.slots[idx].priority < old {
+                    self.sift_up(idx);
+                } else {
+                    // Sifting down can bring a removed entry up to the top
+                    self.sift_down(idx);
+                    self.purge_top();
+                }
+                Some(old)
+            }
+            None => {
+                let idx = self.slots.len();
+                self.index.insert(key.clone(), idx);
+                self.slots.push(Slot {
+                    key,
+                    priority,
+                    live: true,
+                });
+                self.sift_up(idx);
+                None
+            }
+        }
+    }
+
+    /// Inserts `key`, or lowers its priority if `priority` is lower than the current one.
+    ///
+    /// Returns `true` if the heap changed, which is exactly the "relax" step of Dijkstra's algorithm.
+    pub fn decrease_key(&mut self, key: K, priority: P) -> bool {
+        match self.index.get(&key).copied() {
+            Some(idx) if priority < self.slots[idx].priority => {
+                self.slots[idx].priority = priority;
+                self.sift_up(idx);
+                self.purge_top();
+                true
+            }
+            Some(_) => false,
+            None => {
+                self.push(key, priority);
+                true
+            }
+        }
+    }
+
+    /// Removes and returns the key with the lowest priority
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        let slot = self.pop_slot()?;
+        debug_assert!(slot.live, "removed slots are purged eagerly from the top");
+        self.index.remove(&slot.key);
+        self.purge_top();
+        Some((slot.key, slot.priority))
+    }
+
+    /// Lazily removes `key` from the heap, returning its priority.
+    ///
+    /// The entry keeps its place in the heap until it reaches the top, but is no longer
+    /// visible through any other method.
+    pub fn remove(&mut self, key: &K) -> Option<P>
+    where
+        P: Clone,
+    {
+        let idx = self.index.remove(key)?;
+        self.slots[idx].live = false;
+        self.removed += 1;
+        let priority = self.slots[idx].priority.clone();
+        self.purge_top();
+        if self.removed > self.index.len() {
+            self.rebuild();
+        }
+        Some(priority)
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.index.clear();
+        self.removed = 0;
+    }
+
+    /// Iterates over the live entries in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &P)> {
+        self.slots
+            .iter()
+            .filter(|slot| slot.live)
+            .map(|slot| (&slot.key, &slot.priority))
+    }
+
+    fn pop_slot(&mut self) -> Option<Slot<K, P>> {
+        if self.slots.is_empty() {
+            return None;
+        }
+        let slot = self.slots.swap_remove(0);
+        if !self.slots.is_empty() {
+            self.reindex(0);
+            self.sift_down(0);
+        }
+        Some(slot)
+    }
+
+    fn purge_top(&mut self) {
+        while self.slots.first().is_some_and(|slot| !slot.live) {
+            self.pop_slot();
+            self.removed -= 1;
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.slots.retain(|slot| slot.live);
+        self.removed = 0;
+        for idx in 0..self.slots.len() {
+            self.reindex(idx);
+        }
+        for idx in (0..self.slots.len() / 2).rev() {
+            self.sift_down(idx);
+        }
+    }
+
+    fn sift_up(&mut self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if self.slots[idx].priority >= self.slots[parent].priority {
+                break;
+            }
+            self.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut idx: usize) {
+        loop {
+            let smallest = [2 * idx + 1, 2 * idx + 2]
+                .into_iter()
+                .filter(|&child| child < self.slots.len())
+                .fold(idx, |best, child| {
+                    if self.slots[child].priority < self.slots[best].priority {
+                        child
+                    } else {
+                        best
+                    }
+                });
+            if smallest == idx {
+                break;
+            }
+            self.swap(idx, smallest);
+            idx = smallest;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.slots.swap(a, b);
+        self.reindex(a);
+        self.reindex(b);
+    }
+
+    fn reindex(&mut self, idx: usize) {
+        let slot = &self.slots[idx];
+        if slot.live {
+            if let Some(entry) = self.index.get_mut(&slot.key) {
+                *entry = idx;
+            }
+        }
+    }
+}
+
+impl<K, P> Default for MinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, P> Extend<(K, P)> for MinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    fn extend<T: IntoIterator<Item = (K, P)>>(&mut self, iter: T) {
+        for (key, priority) in iter {
+            self.push(key, priority);
+        }
+    }
+}
+
+impl<K, P> FromIterator<(K, P)> for MinHeap<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    fn from_iter<T: IntoIterator<Item = (K, P)>>(iter: T) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+
+    use super::*;
+
+    fn drain<K: Hash + Eq + Clone, P: Ord>(mut heap: MinHeap<K, P>) -> Vec<(K, P)> {
+        std::iter::from_fn(|| heap.pop()).collect()
+    }
+
+    #[test]
+    fn pops_in_priority_order() {
+        let heap: MinHeap<usize, u32> = [5, 3, 8, 1, 9, 2, 7].into_iter().enumerate().collect();
+        assert_eq!(heap.len(), 7);
+        let priorities = drain(heap).into_iter().map(|(_, p)| p).collect::<Vec<_>>();
+        assert_eq!(priorities, [1, 2, 3, 5, 7, 8, 9]);
+    }
+
+    #[test]
+    fn decrease_key() {
+        let mut heap = MinHeap::new();
+        heap.push(IVec2::new(0, 0), 10);
+        heap.push(IVec2::new(1, 0), 5);
+        heap.push(IVec2::new(0, 1), 7);
+        assert!(heap.decrease_key(IVec2::new(0, 0), 1));
+        assert!(!heap.decrease_key(IVec2::new(1, 0), 6));
+        assert!(heap.decrease_key(IVec2::new(1, 1), 3));
+        assert_eq!(heap.len(), 4);
+        assert_eq!(heap.priority(&IVec2::new(1, 0)), Some(&5));
+        assert_eq!(
+            drain(heap),
+            [
+                (IVec2::new(0, 0), 1),
+                (IVec2::new(1, 1), 3),
+                (IVec2::new(1, 0), 5),
+                (IVec2::new(0, 1), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn push_replaces_priority() {
+        let mut heap = MinHeap::new();
+        heap.push((IVec2::ZERO, IVec2::X), 1);
+        heap.push((IVec2::ZERO, IVec2::Y), 2);
+        assert_eq!(heap.push((IVec2::ZERO, IVec2::X), 3), Some(1));
+        assert_eq!(heap.peek(), Some((&(IVec2::ZERO, IVec2::Y), &2)));
+        assert_eq!(heap.len(), 2);
+    }
+
+    #[test]
+    fn lazy_removal() {
+        let mut heap: MinHeap<u32, u32> = (0..10).map(|k| (k, k)).collect();
+        assert_eq!(heap.remove(&0), Some(0));
+        assert_eq!(heap.remove(&5), Some(5));
+        assert_eq!(heap.remove(&5), None);
+        assert!(!heap.contains(&5));
+        assert_eq!(heap.len(), 8);
+        assert_eq!(heap.peek(), Some((&1, &1)));
+        assert_eq!(
+            drain(heap).into_iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 6, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    fn raising_the_top_purges_removed_entries() {
+        let mut heap: MinHeap<&str, u32> = [("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)]
+            .into_iter()
+            .collect();
+        heap.remove(&"b");
+        heap.push("a", 10);
+        assert_eq!(heap.len(), 4);
+        assert_eq!(heap.peek(), Some((&"c", &3)));
+        assert_eq!(drain(heap), [("c", 3), ("d", 4), ("e", 5), ("a", 10)]);
+    }
+
+    #[test]
+    fn removing_most_entries_rebuilds() {
+        let mut heap: MinHeap<u32, u32> = (0..100).map(|k| (k, 100 - k)).collect();
+        for k in (0..100).filter(|k| k % 10 != 0) {
+            heap.remove(&k);
+        }
+        assert_eq!(heap.len(), 10);
+        assert_eq!(
+            drain(heap).into_iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            [90, 80, 70, 60, 50, 40, 30, 20, 10, 0]
+        );
+    }
+}
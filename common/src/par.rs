@@ -0,0 +1,270 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hash},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use rayon::prelude::*;
+
+use crate::{counter::Counter, search::Search};
+
+/// A set of independently locked values, chosen by the hash of a key
+#[derive(Debug)]
+struct Shards<T> {
+    shards: Box<[Mutex<T>]>,
+    hasher: RandomState,
+}
+
+impl<T: Default> Shards<T> {
+    fn new() -> Self {
+        // a few shards per thread keeps the odds of two threads contending for one lock low
+        let count = (rayon::current_num_threads() * 4).next_power_of_two();
+        Self {
+            shards: std::iter::repeat_with(Mutex::default).take(count).collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    fn get<K: Hash>(&self, key: &K) -> MutexGuard<'_, T> {
+        #[allow(clippy::cast_possible_truncation)]
+        let idx = self.hasher.hash_one(key) as usize & (self.shards.len() - 1);
+        // a panic on another thread doesn't leave any of our maps in an inconsistent state
+        self.shards[idx]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn into_values(self) -> impl Iterator<Item = T> {
+        self.shards
+            .into_vec()
+            .into_iter()
+            .map(|shard| shard.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+/// A memoization table which can be shared between threads
+#[derive(Debug)]
+pub struct Memo<K, V>(Shards<HashMap<K, V>>);
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Shards::new())
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.0.get(key).get(key).cloned()
+    }
+
+    /// The value for `key`, computing it with `f` if it isn't known yet.
+    ///
+    /// No lock is held while `f` runs, so `f` may recursively query the table. Two threads
+    /// may occasionally compute the same value, in which case the first one stored wins.
+    pub fn get_or_insert_with(&self, key: K, f: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = f();
+        self.0.get(&key).entry(key).or_insert(value).clone()
+    }
+
+    /// The number of memoized values
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0
+            .shards
+            .iter()
+            .map(|shard| shard.lock().unwrap_or_else(PoisonError::into_inner).len())
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn into_map(self) -> HashMap<K, V> {
+        self.0.into_values().flatten().collect()
+    }
+}
+
+impl<K, V> Default for Memo<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Maps every item in parallel, sharing one `Memo` between all of the calls to `map`, then
+/// combines the results with `reduce`.
+///
+/// `reduce` must be associative, and `identity` must produce its identity element.
+pub fn memo_map_reduce<T, K, V, R>(
+    items: impl IntoParallelIterator<Item = T>,
+    map: impl Fn(T, &Memo<K, V>) -> R + Sync + Send,
+    identity: impl Fn() -> R + Sync + Send,
+    reduce: impl Fn(R, R) -> R + Sync + Send,
+) -> R
+where
+    K: Hash + Eq + Send,
+    V: Clone + Send,
+    R: Send,
+{
+    let memo = Memo::new();
+    items
+        .into_par_iter()
+        .map(|item| map(item, &memo))
+        .reduce(identity, reduce)
+}
+
+/// A `Counter` which can be added to from many threads at once
+#[derive(Debug)]
+pub struct ShardedCounter<K: Hash + Eq>(Shards<Counter<K>>);
+
+impl<K> ShardedCounter<K>
+where
+    K: Hash + Eq,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Shards::new())
+    }
+
+    #[inline]
+    pub fn add(&self, key: K) {
+        self.add_n(key, 1);
+    }
+
+    pub fn add_n(&self, key: K, count: usize) {
+        self.0.get(&key).extend([(key, count)]);
+    }
+
+    /// Merges the shards into a single counter
+    #[must_use]
+    pub fn into_counter(self) -> Counter<K> {
+        self.0
+            .into_values()
+            .fold(Counter::new(), |mut total, shard| {
+                total += shard;
+                total
+            })
+    }
+}
+
+impl<K> Default for ShardedCounter<K>
+where
+    K: Hash + Eq,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counts the items of a parallel iterator
+pub fn count<K>(items: impl IntoParallelIterator<Item = K>) -> Counter<K>
+where
+    K: Hash + Eq + Send,
+{
+    let counter = ShardedCounter::new();
+    items.into_par_iter().for_each(|key| counter.add(key));
+    counter.into_counter()
+}
+
+/// Breadth first search which expands each frontier in parallel.
+///
+/// This produces the same distances and predecessors as `search::bfs`, so is a drop in
+/// replacement when `successors` is expensive or the frontiers are wide (as with open grids).
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl Fn(&S) -> I + Sync + Send,
+) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone + Send + Sync,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(starts, 0);
+    let mut frontier = search.distances().keys().cloned().collect::<Vec<_>>();
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        depth += 1;
+        let edges = frontier
+            .par_iter()
+            .flat_map_iter(|state| {
+                successors(state)
+                    .into_iter()
+                    .map(move |next| (state.clone(), next))
+            })
+            .collect::<Vec<_>>();
+        frontier = edges
+            .into_iter()
+            .filter_map(|(from, next)| search.relax(&from, next.clone(), depth).then_some(next))
+            .collect();
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn memoized_recursion() {
+        fn fib(n: u64, memo: &Memo<u64, u64>) -> u64 {
+            if n < 2 {
+                return n;
+            }
+            memo.get_or_insert_with(n, || fib(n - 1, memo) + fib(n - 2, memo))
+        }
+        let total = memo_map_reduce(vec![10, 20, 30, 90], fib, || 0, |a, b| a + b);
+        assert_eq!(total, 55 + 6765 + 832_040 + 2_880_067_194_370_816_120);
+    }
+
+    #[test]
+    fn sharded_counts_match_serial() {
+        let items = (0..10_000_u32).map(|n| n % 37).collect::<Vec<_>>();
+        let serial = items.iter().copied().collect::<Counter<_>>();
+        assert_eq!(count(items), serial);
+    }
+
+    #[test]
+    fn bfs_matches_serial() -> Result<(), crate::grid::Error> {
+        let grid: Grid<u8> = "
+            ..#....
+            ..#.##.
+            ....#..
+            ##.##.#
+            .......
+        "
+        .parse()?;
+        let successors = |p: &IVec2| {
+            [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
+                .into_iter()
+                .map(|d| *p + d)
+                .filter(|p| grid.get(*p).is_some_and(|t| *t == b'.'))
+                .collect::<Vec<_>>()
+        };
+        let end = IVec2::new(6, 0);
+        let serial = crate::search::bfs([IVec2::ZERO], successors);
+        let parallel = bfs([IVec2::ZERO], successors);
+        assert_eq!(serial.distances(), parallel.distances());
+        assert_eq!(
+            serial.on_shortest_paths([end]),
+            parallel.on_shortest_paths([end])
+        );
+        Ok(())
+    }
+}
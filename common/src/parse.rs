@@ -0,0 +1,378 @@
+use std::{fmt::Display, str::FromStr};
+
+use glam::{I64Vec3, IVec2};
+use itertools::Itertools;
+
+/// A slice of puzzle input which remembers where it came from, so that errors can point at
+/// the line and column that failed to parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, displaydoc::Display, thiserror::Error)]
+/// line {line}, column {column}: {kind}
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, displaydoc::Display)]
+pub enum ErrorKind {
+    /// expected {expected:?} in {found:?}
+    Missing { expected: String, found: String },
+    /// failed to parse {found:?}: {reason}
+    Invalid { found: String, reason: String },
+    /// expected {expected} numbers in {found:?}, found {count}
+    Count {
+        expected: usize,
+        count: usize,
+        found: String,
+    },
+}
+
+/// The non-empty lines of `input`, with surrounding whitespace removed
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).lines()
+}
+
+/// The blocks of `input` separated by blank lines
+pub fn blocks(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).blocks()
+}
+
+/// Splits `input` on the first blank line
+pub fn two_blocks(input: &str) -> Result<(Span<'_>, Span<'_>), Error> {
+    Span::new(input).two_blocks()
+}
+
+/// Every integer in `input`, ignoring whatever separates them
+pub fn ints<T>(input: &str) -> impl Iterator<Item = Result<T, Error>> + '_
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    Span::new(input).ints()
+}
+
+impl<'a> Span<'a> {
+    #[must_use]
+    pub const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// The (1 based) line this span starts on
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The (1 based) column this span starts at
+    #[must_use]
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    #[must_use]
+    pub const fn error(&self, kind: ErrorKind) -> Error {
+        Error {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+
+    #[must_use]
+    pub fn trim(self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    /// The non-empty lines of this span, with surrounding whitespace removed
+    pub fn lines(self) -> impl Iterator<Item = Self> {
+        self.text
+            .lines()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(move |s| self.sub(s))
+    }
+
+    /// The blocks of this span separated by blank lines, with surrounding whitespace removed
+    pub fn blocks(self) -> impl Iterator<Item = Self> {
+        let mut rest = self.text;
+        std::iter::from_fn(move || {
+            let start = rest.trim_start();
+            if start.is_empty() {
+                return None;
+            }
+            let (block, tail) = start.split_at(blank_line(start).unwrap_or(start.len()));
+            rest = tail;
+            Some(self.sub(block.trim()))
+        })
+    }
+
+    /// Splits on the first blank line
+    pub fn two_blocks(self) -> Result<(Self, Self), Error> {
+        let start = self.text.trim_start();
+        match blank_line(start).map(|idx| start.split_at(idx)) {
+            Some((first, second)) if !second.trim().is_empty() => {
+                Ok((self.sub(first.trim()), self.sub(second.trim())))
+            }
+            _ => Err(self.missing("blank line")),
+        }
+    }
+
+    /// Splits on the first occurrence of `separator`, trimming both halves
+    pub fn split_once(self, separator: &str) -> Result<(Self, Self), Error> {
+        let (l, r) = self
+            .text
+            .split_once(separator)
+            .ok_or_else(|| self.missing(separator))?;
+        Ok((self.sub(l.trim()), self.sub(r.trim())))
+    }
+
+    /// Splits on every occurrence of `separator`, trimming each piece
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Self> + 'a {
+        self.text.split(separator).map(move |s| self.sub(s.trim()))
+    }
+
+    pub fn strip_prefix(self, prefix: &str) -> Result<Self, Error> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.sub(rest))
+            .ok_or_else(|| self.missing(prefix))
+    }
+
+    pub fn strip_suffix(self, suffix: &str) -> Result<Self, Error> {
+        self.text
+            .strip_suffix(suffix)
+            .map(|rest| self.sub(rest))
+            .ok_or_else(|| self.missing(suffix))
+    }
+
+    /// The value of a labelled section such as `Register A: 729` or `p=0,4`
+    pub fn section(self, label: &str) -> Result<Self, Error> {
+        let rest = self.strip_prefix(label)?;
+        rest.text
+            .strip_prefix(':')
+            .or_else(|| rest.text.strip_prefix('='))
+            .map(|value| self.sub(value.trim()))
+            .ok_or_else(|| rest.missing(":"))
+    }
+
+    pub fn parse<T>(self) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.parse().map_err(|err: T::Err| {
+            self.error(ErrorKind::Invalid {
+                found: self.text.to_owned(),
+                reason: err.to_string(),
+            })
+        })
+    }
+
+    /// Every integer in this span, ignoring whatever separates them.
+    ///
+    /// A `-` is only treated as a sign if it doesn't directly follow a digit or letter,
+    /// so `3-5` is two positive numbers while `x=-3` is a negative one.
+    pub fn ints<T>(self) -> impl Iterator<Item = Result<T, Error>> + 'a
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut idx = 0;
+        std::iter::from_fn(move || {
+            while idx < bytes.len() {
+                let start = idx;
+                let signed = bytes[idx] == b'-'
+                    && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit)
+                    && (idx == 0 || !bytes[idx - 1].is_ascii_alphanumeric());
+                if signed || bytes[idx].is_ascii_digit() {
+                    idx += 1;
+                    while bytes.get(idx).is_some_and(u8::is_ascii_digit) {
+                        idx += 1;
+                    }
+                    return Some(self.sub(&self.text[start..idx]).parse());
+                }
+                idx += 1;
+            }
+            None
+        })
+    }
+
+    /// Exactly `N` integers, extracted as by `Span::ints`
+    pub fn int_array<T, const N: usize>(self) -> Result<[T; N], Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let values: Vec<T> = self.ints().try_collect()?;
+        let count = values.len();
+        values.try_into().map_err(|_| {
+            self.error(ErrorKind::Count {
+                expected: N,
+                count,
+                found: self.text.to_owned(),
+            })
+        })
+    }
+
+    /// A vector written as `x,y`
+    pub fn ivec2(self) -> Result<IVec2, Error> {
+        let (x, y) = self.split_once(",")?;
+        Ok(IVec2::new(x.parse()?, y.parse()?))
+    }
+
+    /// A vector written as `x,y,z`
+    pub fn i64vec3(self) -> Result<I64Vec3, Error> {
+        let (x, rest) = self.split_once(",")?;
+        let (y, z) = rest.split_once(",")?;
+        Ok(I64Vec3::new(x.parse()?, y.parse()?, z.parse()?))
+    }
+
+    fn missing(&self, expected: &str) -> Error {
+        self.error(ErrorKind::Missing {
+            expected: expected.to_owned(),
+            found: self.text.to_owned(),
+        })
+    }
+
+    /// Creates a span for `inner`, which must be a subslice of `self`
+    fn sub(&self, inner: &'a str) -> Self {
+        let offset = inner.as_ptr() as usize - self.text.as_ptr() as usize;
+        debug_assert!(offset + inner.len() <= self.text.len(), "not a subslice");
+        let before = &self.text[..offset];
+        let (line, column) = before.rfind('\n').map_or_else(
+            || (self.line, self.column + before.chars().count()),
+            |newline| {
+                (
+                    self.line + before.matches('\n').count(),
+                    before[newline + 1..].chars().count() + 1,
+                )
+            },
+        );
+        Self {
+            text: inner,
+            line,
+            column,
+        }
+    }
+}
+
+/// The byte offset of the first line in `s` which is blank, ignoring the first line
+fn blank_line(s: &str) -> Option<usize> {
+    s.split('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len() + 1;
+            Some((start, line))
+        })
+        .skip(1)
+        .find_map(|(start, line)| line.trim().is_empty().then_some(start))
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "
+        Button A: X+94, Y+34
+        Button B: X+22, Y+67
+        Prize: X=8400, Y=5400
+
+        p=0,4 v=3,-3
+        3-5
+    ";
+
+    #[test]
+    fn blocks_and_lines() -> Result<(), Error> {
+        let blocks = blocks(INPUT).collect_vec();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!((blocks[1].line(), blocks[1].column()), (6, 9));
+        let lines = blocks[0].lines().collect_vec();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2].as_str(), "Prize: X=8400, Y=5400");
+        assert_eq!((lines[2].line(), lines[2].column()), (4, 9));
+        let (first, second) = two_blocks(INPUT)?;
+        assert_eq!(first, blocks[0]);
+        assert_eq!(second.as_str(), "p=0,4 v=3,-3\n        3-5");
+        Ok(())
+    }
+
+    #[test]
+    fn integers() -> Result<(), Error> {
+        let lines = lines(INPUT).collect_vec();
+        assert_eq!(lines[0].int_array::<u32, 2>()?, [94, 34]);
+        assert_eq!(
+            lines[2].section("Prize")?.int_array::<u32, 2>()?,
+            [8400, 5400]
+        );
+        assert_eq!(lines[3].int_array::<i32, 4>()?, [0, 4, 3, -3]);
+        assert_eq!(lines[4].int_array::<u64, 2>()?, [3, 5]);
+        assert_eq!(
+            ints::<i8>("-1 x-2 - 3").try_collect::<_, Vec<_>, _>()?,
+            [-1, 2, 3]
+        );
+        let err = lines[3]
+            .int_array::<i32, 3>()
+            .expect_err("too many numbers");
+        assert_eq!((err.line, err.column), (6, 9));
+        Ok(())
+    }
+
+    #[test]
+    fn vectors_and_sections() -> Result<(), Error> {
+        let robot = lines(INPUT).nth(3).expect("a robot line");
+        let (p, v) = robot.split_once(" ")?;
+        assert_eq!(p.section("p")?.ivec2()?, IVec2::new(0, 4));
+        assert_eq!(v.section("v")?.ivec2()?, IVec2::new(3, -3));
+        assert_eq!(
+            Span::new("162,817,812").i64vec3()?,
+            I64Vec3::new(162, 817, 812)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors_point_at_failure() {
+        let err = lines("1,2\n3;4")
+            .map(Span::ivec2)
+            .nth(1)
+            .expect("a second line");
+        assert_eq!(
+            err.map_err(|err| err.to_string()),
+            Err(r#"line 2, column 1: expected "," in "3;4""#.to_owned())
+        );
+        let err = lines("1,2\n  3,x")
+            .map(Span::ivec2)
+            .nth(1)
+            .expect("a second line");
+        assert_eq!(
+            err.map_err(|err| err.to_string()),
+            Err(
+                r#"line 2, column 5: failed to parse "x": invalid digit found in string"#
+                    .to_owned()
+            )
+        );
+    }
+}
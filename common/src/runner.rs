@@ -0,0 +1,267 @@
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::{self, Variant},
+    input::{self, Resolver},
+    solution::{Error, Solution},
+};
+
+/// One of the two parts of a day's puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// Where to find a day's real puzzle and how to solve each part of it, which every day crate
+/// implements
+pub trait Day {
+    const YEAR: u16;
+    const DAY: u8;
+    /// The crate's manifest directory, which its inputs are stored relative to
+    const DIR: &'static str;
+    /// The input file (within `inputs/`) each part reads
+    const INPUTS: [&'static str; 2];
+    /// Whether the day has a second part. The last day of each year only has one, so its
+    /// `Part2` is `NoPart`
+    const PART2: bool = true;
+    /// Other ways of solving each part, to compare against `Part1` and `Part2` with `aoc bench`
+    const VARIANTS: &'static [Variant] = &[];
+
+    type Part1: Solution;
+    type Part2: Solution;
+}
+
+/// A successful run of one part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: String,
+    pub prepare_time: Duration,
+    pub solve_time: Duration,
+}
+
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum RunError {
+    /// {year} day {day} has no part {part}
+    NoSuchPart { year: u16, day: u8, part: Part },
+    /// {0}
+    Input(#[from] input::Error),
+    /// failed to parse the input: {0}
+    Parse(#[source] Error),
+    /// failed to solve the puzzle: {0}
+    Solve(#[source] Error),
+}
+
+/// A `Day` with its types erased, so that days with different answers can share a registry
+pub trait Solver: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn parts(&self) -> &'static [Part];
+    /// The day crate's directory, which holds its `inputs/`
+    fn dir(&self) -> &'static Path;
+    /// The input file `part` reads when no other is given
+    fn input_file(&self, part: Part) -> &'static str;
+    fn run(&self, part: Part, input: &str) -> Result<Run, RunError>;
+    /// Every way of solving each part, starting with the `Solution`s as `solution`
+    fn variants(&self) -> Vec<Variant>;
+
+    fn check_part(&self, part: Part) -> Result<(), RunError> {
+        if self.parts().contains(&part) {
+            Ok(())
+        } else {
+            Err(RunError::NoSuchPart {
+                year: self.year(),
+                day: self.day(),
+                part,
+            })
+        }
+    }
+
+    /// Finds the input for this day's crate
+    fn resolver(&self) -> Resolver {
+        Resolver::new(self.dir())
+    }
+
+    /// Reads the input `file` with `resolver` and solves `part` with it
+    fn run_file(&self, part: Part, resolver: &Resolver, file: &str) -> Result<Run, RunError> {
+        self.check_part(part)?;
+        self.run(part, &resolver.read(file)?)
+    }
+}
+
+/// The `Solver` for the day `D`
+pub struct Registered<D>(PhantomData<fn() -> D>);
+
+impl<D: Day> Registered<D> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<D: Day> Default for Registered<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: Day> Solver for Registered<D> {
+    fn year(&self) -> u16 {
+        D::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        D::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        if D::PART2 {
+            &[Part::One, Part::Two]
+        } else {
+            &[Part::One]
+        }
+    }
+
+    fn dir(&self) -> &'static Path {
+        Path::new(D::DIR)
+    }
+
+    fn input_file(&self, part: Part) -> &'static str {
+        match part {
+            Part::One => D::INPUTS[0],
+            Part::Two => D::INPUTS[1],
+        }
+    }
+
+    fn run(&self, part: Part, input: &str) -> Result<Run, RunError> {
+        self.check_part(part)?;
+        match part {
+            Part::One => run::<D::Part1>(input),
+            Part::Two => run::<D::Part2>(input),
+        }
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        let solutions = [
+            Variant {
+                part: Part::One,
+                name: "solution",
+                bench: bench::sample_solution::<D::Part1>,
+            },
+            Variant {
+                part: Part::Two,
+                name: "solution",
+                bench: bench::sample_solution::<D::Part2>,
+            },
+        ];
+        solutions
+            .into_iter()
+            .take(self.parts().len())
+            .chain(D::VARIANTS.iter().copied())
+            .collect()
+    }
+}
+
+/// Solves the real puzzle `text` with `S`, timing each step
+fn run<S: Solution>(text: &str) -> Result<Run, RunError> {
+    let params = S::Params::default();
+    let start = Instant::now();
+    let input = S::prepare(text, &params).map_err(RunError::Parse)?;
+    let prepare_time = start.elapsed();
+    let start = Instant::now();
+    let answer = S::solve(input, &params).map_err(RunError::Solve)?;
+    Ok(Run {
+        answer: answer.to_string(),
+        prepare_time,
+        solve_time: start.elapsed(),
+    })
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => f.write_str("1"),
+            Self::Two => f.write_str("2"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+    struct Max;
+    struct Sums;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Params = ();
+        type Answer = u32;
+
+        fn prepare(text: &str, (): &()) -> Result<Vec<u32>, Error> {
+            Ok(text
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn solve(input: Vec<u32>, (): &()) -> Result<u32, Error> {
+            Ok(input.into_iter().sum())
+        }
+    }
+
+    impl Solution for Max {
+        type Input = Vec<u32>;
+        type Params = ();
+        type Answer = u32;
+
+        fn prepare(text: &str, params: &()) -> Result<Vec<u32>, Error> {
+            Sum::prepare(text, params)
+        }
+
+        fn solve(input: Vec<u32>, (): &()) -> Result<u32, Error> {
+            input.into_iter().max().ok_or_else(|| "no numbers".into())
+        }
+    }
+
+    impl Day for Sums {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+        const INPUTS: [&'static str; 2] = ["part1.txt", "part2.txt"];
+
+        type Part1 = Sum;
+        type Part2 = Max;
+    }
+
+    #[test]
+    fn erased_runs() {
+        let solver: &dyn Solver = &Registered::<Sums>::new();
+        assert_eq!((solver.year(), solver.day()), (2000, 1));
+        assert_eq!(
+            solver.run(Part::One, "1 2 3").map(|run| run.answer).ok(),
+            Some("6".to_owned())
+        );
+        assert_eq!(
+            solver.run(Part::Two, "1 5 3").map(|run| run.answer).ok(),
+            Some("5".to_owned())
+        );
+        assert!(matches!(
+            solver.run(Part::One, "1 x"),
+            Err(RunError::Parse(_))
+        ));
+        assert!(matches!(solver.run(Part::Two, ""), Err(RunError::Solve(_))));
+        assert_eq!(solver.input_file(Part::Two), "part2.txt");
+        assert!(matches!(
+            solver.run_file(Part::One, &solver.resolver(), "missing.txt"),
+            Err(RunError::Input(input::Error::NotFound { .. }))
+        ));
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::min_heap::MinHeap;
+
+/// The outcome of a graph search: the cost of reaching every explored state, along with the
+/// predecessor DAG which records every way of reaching each state at that cost.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S, C> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy,
+{
+    #[inline]
+    #[must_use]
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// All the states which precede `state` on some shortest path to it
+    #[inline]
+    #[must_use]
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One of the shortest paths from a start state to `goal`, inclusive of both
+    #[must_use]
+    pub fn path_to(&self, goal: &S) -> Option<Vec<S>> {
+        self.distances.get(goal)?;
+        let mut path = vec![goal.clone()];
+        while let Some(prev) = path
+            .last()
+            .and_then(|state| self.predecessors(state).first())
+        {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state which lies on any shortest path to one of `goals`
+    #[must_use]
+    pub fn on_shortest_paths(&self, goals: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut stack = goals
+            .into_iter()
+            .filter(|goal| self.distances.contains_key(goal))
+            .collect::<Vec<_>>();
+        let mut seen = HashSet::new();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// The goals with the lowest cost, along with that cost
+    #[must_use]
+    pub fn cheapest(&self, goals: impl IntoIterator<Item = S>) -> Option<(Vec<S>, C)>
+    where
+        C: Ord,
+    {
+        goals
+            .into_iter()
+            .filter_map(|goal| Some((self.distance(&goal)?, goal)))
+            .fold(None, |best, (cost, goal)| match best {
+                Some((mut goals, best)) if cost == best => {
+                    goals.push(goal);
+                    Some((goals, best))
+                }
+                Some((goals, best)) if best < cost => Some((goals, best)),
+                _ => Some((vec![goal], cost)),
+            })
+    }
+
+    pub(crate) fn new(starts: impl IntoIterator<Item = S>, zero: C) -> Self {
+        Self {
+            distances: starts.into_iter().map(|s| (s, zero)).collect(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Records reaching `next` from `from` with cost `cost`, returning whether it is an improvement
+    pub(crate) fn relax(&mut self, from: &S, next: S, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.distances.get(&next).copied() {
+            Some(known) if cost > known => false,
+            Some(known) if cost == known => {
+                // Start states have no predecessors entry, and must not gain one through a
+                // zero-cost cycle, or `path_to` would never terminate
+                let Some(preds) = self.predecessors.get_mut(&next) else {
+                    return false;
+                };
+                if !preds.contains(from) {
+                    preds.push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![from.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth first search where every step costs 1
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> Search<S, usize>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(starts, 0);
+    let mut queue = search.distances.keys().cloned().collect::<VecDeque<_>>();
+    while let Some(state) = queue.pop_front() {
+        let cost = search.distances[&state] + 1;
+        for next in successors(&state) {
+            if search.relax(&state, next.clone(), cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm, exploring every state reachable from `starts`
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), |_| false)
+}
+
+/// A* search which stops once every cheapest route to a state satisfying `is_goal` has been found.
+///
+/// `heuristic` must be consistent (never overestimate the cost of a single step) for the
+/// predecessor DAG to contain every shortest path.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new(starts, C::default());
+    let mut queue = search
+        .distances
+        .keys()
+        .map(|s| (s.clone(), heuristic(s)))
+        .collect::<MinHeap<_, _>>();
+    let mut best = None;
+    while let Some((state, estimate)) = queue.pop() {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        let cost = search.distances[&state];
+        if best.is_none() && is_goal(&state) {
+            best = Some(cost);
+        }
+        for (next, step) in successors(&state) {
+            if search.relax(&state, next.clone(), cost + step) {
+                let estimate = cost + step + heuristic(&next);
+                queue.push(next, estimate);
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::IVec2;
+
+    use super::*;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "
+        #######
+        #S....#
+        #.###.#
+        #.....#
+        #.#####
+        #....E#
+        #######
+    ";
+
+    fn open(grid: &Grid<u8>, pos: IVec2) -> impl Iterator<Item = IVec2> + '_ {
+        [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
+            .into_iter()
+            .map(move |d| pos + d)
+            .filter(|p| grid.get(*p).is_some_and(|t| *t != b'#'))
+    }
+
+    #[test]
+    fn bfs_distances_and_paths() -> Result<(), crate::grid::Error> {
+        let grid: Grid<u8> = MAZE.parse()?;
+        let (start, end) = (IVec2::new(1, 1), IVec2::new(5, 5));
+        let search = bfs([start], |p| open(&grid, *p).collect::<Vec<_>>());
+        assert_eq!(search.distance(&end), Some(8));
+        let path = search.path_to(&end).expect("a path");
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&start));
+        // the loop at the top means both sides are on a shortest path
+        assert_eq!(search.on_shortest_paths([IVec2::new(5, 3)]).len(), 12);
+        Ok(())
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() -> Result<(), crate::grid::Error> {
+        let grid: Grid<u8> = MAZE.parse()?;
+        let (start, end) = (IVec2::new(1, 1), IVec2::new(5, 5));
+        let successors = |p: &IVec2| {
+            open(&grid, *p)
+                .map(|n| (n, if n.y == 3 { 5_u32 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let d = dijkstra([start], successors);
+        #[allow(clippy::cast_sign_loss)]
+        let a = astar(
+            [start],
+            successors,
+            |p| (end - *p).abs().element_sum() as u32,
+            |p| *p == end,
+        );
+        assert_eq!(d.distance(&end), Some(12));
+        assert_eq!(a.distance(&end), Some(12));
+        assert_eq!(d.on_shortest_paths([end]), a.on_shortest_paths([end]),);
+        assert!(a.distances().len() <= d.distances().len());
+        assert_eq!(d.cheapest([end, start]), Some((vec![start], 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn zero_cost_cycle_through_start() {
+        let search = dijkstra([0_u8], |n| [(1 - *n, 0_u32)]);
+        assert_eq!(search.distance(&1), Some(0));
+        assert_eq!(search.predecessors(&0), &[] as &[u8]);
+        assert_eq!(search.predecessors(&1), &[0]);
+        assert_eq!(search.path_to(&0), Some(vec![0]));
+        assert_eq!(search.path_to(&1), Some(vec![0, 1]));
+    }
+}
@@ -0,0 +1,82 @@
+use std::{convert::Infallible, fmt::Display};
+
+/// The error type shared by every solution, which any day's own errors (including `color_eyre`
+/// reports) convert into with `?`
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// One part of a day's puzzle, in a shape tests, benches and the runner can all drive
+pub trait Solution {
+    /// What `solve` works on, produced from the puzzle text by `prepare`
+    type Input;
+    /// Anything which differs between the examples and the real puzzle, such as the size of
+    /// a grid. The default is the real puzzle's.
+    type Params: Default;
+    type Answer: Display;
+
+    /// Parses the puzzle text, along with any preprocessing which shouldn't count towards
+    /// solving it (such as sorting pairs by distance ahead of connecting them)
+    fn prepare(text: &str, params: &Self::Params) -> Result<Self::Input, Error>;
+
+    fn solve(input: Self::Input, params: &Self::Params) -> Result<Self::Answer, Error>;
+
+    /// Prepares and solves `text` in one go
+    fn run(text: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        Self::solve(Self::prepare(text, params)?, params)
+    }
+}
+
+/// The second part of the last day of each year, which has no puzzle of its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPart;
+
+impl Solution for NoPart {
+    type Input = ();
+    type Params = ();
+    type Answer = Infallible;
+
+    fn prepare(_: &str, (): &()) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn solve((): (), (): &()) -> Result<Infallible, Error> {
+        Err("there is no puzzle to solve".into())
+    }
+}
+
+/// Declares a part as a unit struct implementing [`Solution`] for the usual case: the puzzle
+/// text is parsed straight into the input, and there are no params.
+///
+/// `solve` is either a function taking the input and returning the answer, or a closure-like
+/// `|input| body` where the body returns a `Result`:
+///
+/// ```ignore
+/// common::solution!(Part1: Puzzle => usize, process);
+/// common::solution!(Part2: String => u32, |puzzle| Ok(process(&puzzle)?));
+/// ```
+#[macro_export]
+macro_rules! solution {
+    ($name:ident: $input:ty => $answer:ty, |$puzzle:ident| $body:expr) => {
+        #[doc = concat!(stringify!($name), " as a `Solution`, for the runner")]
+        pub struct $name;
+
+        impl $crate::Solution for $name {
+            type Input = $input;
+            type Params = ();
+            type Answer = $answer;
+
+            fn prepare(text: &str, (): &()) -> Result<Self::Input, $crate::solution::Error> {
+                Ok(text.parse()?)
+            }
+
+            fn solve(
+                $puzzle: Self::Input,
+                (): &(),
+            ) -> Result<Self::Answer, $crate::solution::Error> {
+                $body
+            }
+        }
+    };
+    ($name:ident: $input:ty => $answer:ty, $solve:path) => {
+        $crate::solution!($name: $input => $answer, |puzzle| Ok($solve(puzzle)));
+    };
+}